@@ -8,6 +8,7 @@ use walkdir::WalkDir;
 
 use crate::behavior::blocks::AddonBlock;
 use crate::behavior::items::AddonItem;
+use crate::behavior::recipes::AddonRecipe;
 use crate::error::AddonError;
 use crate::error::AddonError::{IOError, JsonError};
 use crate::language::Languages;
@@ -17,6 +18,7 @@ use crate::Addon;
 pub mod blocks;
 pub mod items;
 pub mod menu_category;
+pub mod recipes;
 
 #[derive(Debug, Clone)]
 pub struct BehaviorPack {
@@ -24,6 +26,7 @@ pub struct BehaviorPack {
     pub languages: Languages,
     pub blocks: HashMap<PathBuf, AddonBlock>,
     pub items: HashMap<PathBuf, AddonItem>,
+    pub recipes: HashMap<PathBuf, AddonRecipe>,
     pub functions: HashMap<PathBuf, String>,
     pub scripts: HashMap<PathBuf, String>,
 }
@@ -119,6 +122,43 @@ impl Addon for BehaviorPack {
             }
         }
 
+        // Recipes
+        let recipes_path = path.join("recipes");
+        let mut recipes = HashMap::new();
+
+        // If dir exists read all recipes
+        if recipes_path.is_dir() {
+            'recipes_walk: for recipes_entry in
+                WalkDir::new(&recipes_path).into_iter().filter(|v| {
+                    if let Ok(v) = v {
+                        v.file_type().is_file()
+                    } else {
+                        false
+                    }
+                })
+            {
+                if let Ok(entry) = recipes_entry {
+                    let recipe_path = entry.path();
+
+                    let recipe = fs::read_to_string(recipe_path)
+                        .map_err(|e| IOError(Arc::new(e), recipe_path.to_path_buf()))?;
+
+                    // If the file is empty skip it
+                    if recipe.trim().is_empty() {
+                        continue 'recipes_walk;
+                    }
+
+                    // Strip all c-style comments
+                    let stripper = CommentSettings::c_style().strip_comments(recipe.as_bytes());
+
+                    let recipe: AddonRecipe = serde_json::from_reader(stripper)
+                        .map_err(|e| JsonError(Arc::new(e), recipe_path.to_path_buf()))?;
+
+                    recipes.insert(recipe_path.to_path_buf(), recipe);
+                }
+            }
+        }
+
         // Functions
         let functions_path = path.join("functions");
         let mut functions = HashMap::new();
@@ -172,6 +212,7 @@ impl Addon for BehaviorPack {
             languages,
             blocks,
             items,
+            recipes,
             functions,
             scripts,
         })
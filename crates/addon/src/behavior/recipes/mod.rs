@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::identifier::AddonIdentifier;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonRecipe {
+    /// Specifies the version of the game this recipe was made in.
+    pub format_version: String,
+    #[serde(flatten)]
+    pub definition: AddonRecipeDefinition,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum AddonRecipeDefinition {
+    #[serde(rename = "minecraft:recipe_shaped")]
+    Shaped(AddonShapedRecipe),
+    #[serde(rename = "minecraft:recipe_shapeless")]
+    Shapeless(AddonShapelessRecipe),
+    #[serde(rename = "minecraft:recipe_furnace")]
+    Furnace(AddonFurnaceRecipe),
+    #[serde(rename = "minecraft:recipe_brewing_mix")]
+    BrewingMix(AddonBrewingRecipe),
+    #[serde(rename = "minecraft:recipe_brewing_container")]
+    BrewingContainer(AddonBrewingRecipe),
+    #[serde(rename = "minecraft:recipe_smithing_transform")]
+    SmithingTransform(AddonSmithingTransformRecipe),
+    #[serde(rename = "minecraft:recipe_smithing_trim")]
+    SmithingTrim(AddonSmithingTrimRecipe),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonRecipeDescription {
+    /// The identifier for this recipe, it must include a namespace.
+    pub identifier: AddonIdentifier,
+}
+
+/// An item used by a recipe, either just its identifier or an object
+/// describing the item or item tag.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AddonRecipeItem {
+    Identifier(String),
+    Item {
+        item: String,
+        data: Option<i16>,
+        count: Option<u16>,
+    },
+    Tag {
+        tag: String,
+        count: Option<u16>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AddonRecipeResult {
+    Single(AddonRecipeItem),
+    Multiple(Vec<AddonRecipeItem>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonShapedRecipe {
+    pub description: AddonRecipeDescription,
+    /// The blocks this recipe can be used in, e.g. "crafting_table".
+    pub tags: Vec<String>,
+    /// The rows of the crafting grid, each character refers to an entry of the key.
+    pub pattern: Vec<String>,
+    pub key: HashMap<char, AddonRecipeItem>,
+    pub result: AddonRecipeResult,
+    /// Recipes with a lower priority are preferred if multiple recipes match.
+    pub priority: Option<i32>,
+    /// If the recipe also matches when its pattern is mirrored.
+    pub assume_symmetry: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonShapelessRecipe {
+    pub description: AddonRecipeDescription,
+    /// The blocks this recipe can be used in, e.g. "crafting_table".
+    pub tags: Vec<String>,
+    pub ingredients: Vec<AddonRecipeItem>,
+    pub result: AddonRecipeResult,
+    /// Recipes with a lower priority are preferred if multiple recipes match.
+    pub priority: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonFurnaceRecipe {
+    pub description: AddonRecipeDescription,
+    /// The blocks this recipe can be used in, e.g. "furnace" or "smoker".
+    pub tags: Vec<String>,
+    pub input: AddonRecipeItem,
+    pub output: AddonRecipeItem,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonBrewingRecipe {
+    pub description: AddonRecipeDescription,
+    pub tags: Vec<String>,
+    pub input: String,
+    pub reagent: String,
+    pub output: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonSmithingTransformRecipe {
+    pub description: AddonRecipeDescription,
+    pub tags: Vec<String>,
+    pub template: String,
+    pub base: String,
+    pub addition: String,
+    pub result: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddonSmithingTrimRecipe {
+    pub description: AddonRecipeDescription,
+    pub tags: Vec<String>,
+    pub template: AddonRecipeItem,
+    pub base: AddonRecipeItem,
+    pub addition: AddonRecipeItem,
+}
@@ -1,3 +1,4 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
@@ -9,10 +10,24 @@ pub enum ButtonImage {
 }
 
 pub struct Button {
-    pub text: String,
+    pub text: RawText,
     pub image: Option<ButtonImage>,
 }
 
+impl Button {
+    pub fn new(text: impl Into<RawText>) -> Self {
+        Self {
+            text: text.into(),
+            image: None,
+        }
+    }
+
+    pub fn image(mut self, image: ButtonImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+}
+
 impl Element for Button {
     fn elem_serialize(&self) -> Value {
         let (button_type, image_data) = match &self.image {
@@ -25,7 +40,7 @@ impl Element for Button {
 
         json!({
             "type": button_type,
-            "text": self.text,
+            "text": self.text.to_compact_json(),
             "data": image_data,
         })
     }
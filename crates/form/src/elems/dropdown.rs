@@ -1,19 +1,30 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
 use crate::error::FormError;
 
 pub struct DropDown {
-    pub text: String,
+    pub text: RawText,
     pub options: Vec<String>,
     pub default_index: i32,
 }
 
+impl DropDown {
+    pub fn new(text: impl Into<RawText>, options: Vec<String>, default_index: i32) -> Self {
+        Self {
+            text: text.into(),
+            options,
+            default_index,
+        }
+    }
+}
+
 impl Element for DropDown {
     fn elem_serialize(&self) -> Value {
         json!({
             "type": "dropdown",
-            "text": self.text,
+            "text": self.text.to_compact_json(),
             "default": self.default_index,
             "options": self.options,
         })
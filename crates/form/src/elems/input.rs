@@ -1,19 +1,34 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
 use crate::error::FormError;
 
 pub struct Input {
-    pub text: String,
+    pub text: RawText,
     pub default: String,
     pub placeholder: String,
 }
 
+impl Input {
+    pub fn new(
+        text: impl Into<RawText>,
+        default: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            default: default.into(),
+            placeholder: placeholder.into(),
+        }
+    }
+}
+
 impl Element for Input {
     fn elem_serialize(&self) -> Value {
         json!({
             "type": "input",
-            "text": self.text,
+            "text": self.text.to_compact_json(),
             "default": self.default,
             "placeholder": self.placeholder,
         })
@@ -1,17 +1,24 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
 use crate::error::FormError;
 
 pub struct Label {
-    pub text: String,
+    pub text: RawText,
+}
+
+impl Label {
+    pub fn new(text: impl Into<RawText>) -> Self {
+        Self { text: text.into() }
+    }
 }
 
 impl Element for Label {
     fn elem_serialize(&self) -> Value {
         json!({
             "type": "label",
-            "text": self.text,
+            "text": self.text.to_compact_json(),
         })
     }
 
@@ -1,21 +1,34 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
 use crate::error::FormError;
 
 pub struct Slider {
-    pub text: String,
+    pub text: RawText,
     pub min: f64,
     pub max: f64,
     pub step_size: f64,
     pub default: f64,
 }
 
+impl Slider {
+    pub fn new(text: impl Into<RawText>, min: f64, max: f64, step_size: f64, default: f64) -> Self {
+        Self {
+            text: text.into(),
+            min,
+            max,
+            step_size,
+            default,
+        }
+    }
+}
+
 impl Element for Slider {
     fn elem_serialize(&self) -> Value {
         json!({
             "type": "slider",
-            "text": self.text,
+            "text": self.text.to_compact_json(),
             "min": self.min,
             "max": self.max,
             "step": self.step_size,
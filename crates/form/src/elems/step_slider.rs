@@ -1,3 +1,4 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
@@ -5,16 +6,26 @@ use crate::error::FormError;
 
 /// A combination of DropDown and Slider
 pub struct StepSlider {
-    pub text: String,
+    pub text: RawText,
     pub options: Vec<String>,
     pub default_index: i32,
 }
 
+impl StepSlider {
+    pub fn new(text: impl Into<RawText>, options: Vec<String>, default_index: i32) -> Self {
+        Self {
+            text: text.into(),
+            options,
+            default_index,
+        }
+    }
+}
+
 impl Element for StepSlider {
     fn elem_serialize(&self) -> Value {
         json!({
             "type": "step_slider",
-            "text": self.text,
+            "text": self.text.to_compact_json(),
             "default": self.default_index,
             "steps": self.options,
         })
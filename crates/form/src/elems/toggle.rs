@@ -1,18 +1,28 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::{json, Value};
 
 use crate::elems::Element;
 use crate::error::FormError;
 
 pub struct Toggle {
-    pub text: String,
+    pub text: RawText,
     pub default: bool,
 }
 
+impl Toggle {
+    pub fn new(text: impl Into<RawText>, default: bool) -> Self {
+        Self {
+            text: text.into(),
+            default,
+        }
+    }
+}
+
 impl Element for Toggle {
     fn elem_serialize(&self) -> Value {
         json!({
             "type": "toggle",
-            "text": self.text,
+            "text": self.text.to_compact_json(),
             "default": self.default,
         })
     }
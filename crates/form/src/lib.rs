@@ -1,3 +1,9 @@
+//! Texts of forms are [`RawText`](bedrockrs_shared::text::rawtext::RawText)s,
+//! which can be created from plain strings via `.into()` or the `new` constructors.
+//!
+//! Breaking change: the text fields used to be `String`s, struct literals need
+//! to convert them, e.g. `title: "Title".into()`.
+
 pub use window::*;
 
 pub mod elems;
@@ -1,3 +1,4 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::json;
 
 use crate::elems::Element;
@@ -5,10 +6,24 @@ use crate::error::FormError;
 use crate::window::Form;
 
 pub struct CustomForm {
-    pub title: String,
+    pub title: RawText,
     pub elements: Vec<Box<dyn Element + Send>>,
 }
 
+impl CustomForm {
+    pub fn new(title: impl Into<RawText>) -> Self {
+        Self {
+            title: title.into(),
+            elements: vec![],
+        }
+    }
+
+    pub fn element(mut self, element: impl Element + Send + 'static) -> Self {
+        self.elements.push(Box::new(element));
+        self
+    }
+}
+
 impl Form for CustomForm {
     fn form_serialize(&self) -> String {
         let mut elems_strings = vec![];
@@ -19,7 +34,7 @@ impl Form for CustomForm {
 
         json!({
             "type": "custom_form",
-            "title": self.title,
+            "title": self.title.to_compact_json(),
             "content": elems_strings,
         })
         .to_string()
@@ -1,3 +1,4 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::json;
 
 use crate::elems::button::Button;
@@ -6,18 +7,34 @@ use crate::error::FormError;
 use crate::window::Form;
 
 pub struct ModalForm {
-    pub title: String,
-    pub body: String,
+    pub title: RawText,
+    pub body: RawText,
     pub button1: Button,
     pub button2: Button,
 }
 
+impl ModalForm {
+    pub fn new(
+        title: impl Into<RawText>,
+        body: impl Into<RawText>,
+        button1: Button,
+        button2: Button,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            button1,
+            button2,
+        }
+    }
+}
+
 impl Form for ModalForm {
     fn form_serialize(&self) -> String {
         json!({
             "type": "form",
-            "title": self.title,
-            "content": self.body,
+            "title": self.title.to_compact_json(),
+            "content": self.body.to_compact_json(),
             "button1": self.button1.elem_serialize(),
             "button2": self.button2.elem_serialize(),
         })
@@ -1,3 +1,4 @@
+use bedrockrs_shared::text::rawtext::RawText;
 use serde_json::json;
 
 use crate::elems::button::Button;
@@ -6,11 +7,26 @@ use crate::error::FormError;
 use crate::window::Form;
 
 pub struct SimpleForm {
-    pub title: String,
-    pub body: String,
+    pub title: RawText,
+    pub body: RawText,
     pub buttons: Vec<Button>,
 }
 
+impl SimpleForm {
+    pub fn new(title: impl Into<RawText>, body: impl Into<RawText>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            buttons: vec![],
+        }
+    }
+
+    pub fn button(mut self, button: Button) -> Self {
+        self.buttons.push(button);
+        self
+    }
+}
+
 impl Form for SimpleForm {
     fn form_serialize(&self) -> String {
         let mut buttons_strings = vec![];
@@ -21,8 +37,8 @@ impl Form for SimpleForm {
 
         json!({
             "type": "form",
-            "title": self.title,
-            "content": self.body,
+            "title": self.title.to_compact_json(),
+            "content": self.body.to_compact_json(),
             "buttons": buttons_strings
         })
         .to_string()
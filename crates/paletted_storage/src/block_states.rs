@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::Arc;
+
+use bedrockrs_nbt::byte_order::NbtByteOrder;
+use bedrockrs_nbt::endian::little_endian::NbtLittleEndian;
+use bedrockrs_nbt::endian::little_endian_network::NbtLittleEndianNetwork;
+use bedrockrs_nbt::error::NbtError;
+use bedrockrs_nbt::NbtTag;
+
+use crate::error::BlockStateError;
+use crate::PalettedStorage;
+
+/// The network hash of `minecraft:unknown`, which doesn't follow the usual hashing.
+pub const UNKNOWN_BLOCK_NETWORK_HASH: u32 = 0xFFFFFFFE;
+
+const FNV1_32_OFFSET_BASIS: u32 = 0x811C9DC5;
+const FNV1_32_PRIME: u32 = 0x01000193;
+const FNV1_64_OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+const FNV1_64_PRIME: u64 = 0x00000100000001B3;
+
+const NBT_COMPOUND_ID: u8 = 0x0A;
+const NBT_END_ID: u8 = 0x00;
+
+/// A single block state, e.g. `minecraft:stone_slab` with `top_slot_bit: 1`.
+#[derive(Debug, Clone)]
+pub struct BlockState {
+    pub name: String,
+    pub states: HashMap<String, NbtTag>,
+}
+
+impl BlockState {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            states: HashMap::new(),
+        }
+    }
+
+    /// Reads a block state from a palette entry, any other fields
+    /// like `version` are ignored.
+    pub fn from_nbt(tag: &NbtTag) -> Result<Self, BlockStateError> {
+        let NbtTag::Compound(map) = tag else {
+            return Err(BlockStateError::InvalidState(format!(
+                "Expected a compound tag, got {tag:?}"
+            )));
+        };
+
+        let name = match map.get("name") {
+            Some(NbtTag::String(name)) => name.clone(),
+            _ => {
+                return Err(BlockStateError::InvalidState(String::from(
+                    "Missing name field",
+                )))
+            }
+        };
+
+        let states = match map.get("states") {
+            Some(NbtTag::Compound(states)) => states.clone(),
+            None => HashMap::new(),
+            Some(other) => {
+                return Err(BlockStateError::InvalidState(format!(
+                    "Expected states of {name} to be a compound tag, got {other:?}"
+                )))
+            }
+        };
+
+        Ok(Self { name, states })
+    }
+
+    /// Creates a palette entry for this state, stored with the given block version.
+    pub fn to_nbt(&self, version: Option<i32>) -> NbtTag {
+        let mut map = HashMap::new();
+
+        map.insert(String::from("name"), NbtTag::String(self.name.clone()));
+        map.insert(
+            String::from("states"),
+            NbtTag::Compound(self.states.clone()),
+        );
+
+        if let Some(version) = version {
+            map.insert(String::from("version"), NbtTag::Int32(version));
+        }
+
+        NbtTag::Compound(map)
+    }
+
+    /// The little endian nbt of this state with sorted keys,
+    /// equal states always have equal canonical bytes.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![];
+
+        write_sorted(&self.to_nbt(None), "", &mut buf)
+            // Serializing into a vec can't fail
+            .expect("Block state serialization failed");
+
+        buf
+    }
+
+    /// The FNV-1a hash of the canonical bytes, used by the client
+    /// when `use_block_network_id_hashes` is enabled.
+    pub fn network_hash(&self) -> u32 {
+        if self.name == "minecraft:unknown" {
+            return UNKNOWN_BLOCK_NETWORK_HASH;
+        }
+
+        fnv1a_32(&self.canonical_bytes())
+    }
+}
+
+/// Writes a tag as little endian nbt, with the tags of compounds sorted by name.
+fn write_sorted(tag: &NbtTag, name: &str, buf: &mut Vec<u8>) -> Result<(), NbtError> {
+    let NbtTag::Compound(map) = tag else {
+        return tag.nbt_serialize::<NbtLittleEndian>(name, buf);
+    };
+
+    NbtLittleEndian::write_u8(buf, NBT_COMPOUND_ID)?;
+    NbtLittleEndian::write_string(buf, name.to_string())?;
+
+    let mut tags: Vec<_> = map.iter().collect();
+    tags.sort_by_key(|(tag_name, _)| *tag_name);
+
+    for (tag_name, tag) in tags {
+        write_sorted(tag, tag_name, buf)?;
+    }
+
+    NbtLittleEndian::write_u8(buf, NBT_END_ID)
+}
+
+/// How the network IDs of block states are computed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BlockNetworkIdMode {
+    /// The [`BlockState::network_hash`] of each state.
+    Hashed,
+    /// Sequential runtime IDs, the states are ordered by the FNV-1 64 hash of their
+    /// block name and keep their registration order within a block.
+    Sequential,
+}
+
+/// Maps block states to network IDs and back, containing the vanilla
+/// block states and any custom block states.
+#[derive(Debug, Clone)]
+pub struct BlockStateRegistry {
+    mode: BlockNetworkIdMode,
+    states: Vec<BlockState>,
+    by_bytes: HashMap<Vec<u8>, u32>,
+    by_network_id: HashMap<u32, usize>,
+}
+
+impl BlockStateRegistry {
+    pub fn new(mode: BlockNetworkIdMode) -> Self {
+        Self {
+            mode,
+            states: vec![],
+            by_bytes: HashMap::new(),
+            by_network_id: HashMap::new(),
+        }
+    }
+
+    /// Loads the vanilla block states from a file containing
+    /// all states as consecutive little endian network nbt compounds
+    /// (`canonical_block_states.nbt`).
+    pub fn load_vanilla(
+        path: impl AsRef<Path>,
+        mode: BlockNetworkIdMode,
+    ) -> Result<Self, BlockStateError> {
+        let path = path.as_ref();
+
+        let data = fs::read(path)
+            .map_err(|e| BlockStateError::IOError(Arc::new(e), path.to_path_buf()))?;
+        let mut cursor = Cursor::new(data.as_slice());
+
+        let mut registry = Self::new(mode);
+
+        while (cursor.position() as usize) < data.len() {
+            let (_, tag) = NbtTag::nbt_deserialize::<NbtLittleEndianNetwork>(&mut cursor)?;
+            registry.states.push(BlockState::from_nbt(&tag)?);
+        }
+
+        registry.rebuild();
+
+        Ok(registry)
+    }
+
+    /// Registers all given states, with sequential IDs this reassigns
+    /// the IDs of all states, so it should be done before any player joins.
+    pub fn register(&mut self, states: impl IntoIterator<Item = BlockState>) {
+        self.states.extend(states);
+        self.rebuild();
+    }
+
+    #[inline]
+    pub fn mode(&self) -> BlockNetworkIdMode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    pub fn network_id(&self, state: &BlockState) -> Option<u32> {
+        self.by_bytes.get(&state.canonical_bytes()).copied()
+    }
+
+    pub fn state(&self, network_id: u32) -> Option<&BlockState> {
+        self.by_network_id
+            .get(&network_id)
+            .map(|i| &self.states[*i])
+    }
+
+    /// Returns the network ID of a palette entry.
+    pub fn palette_entry_to_network_id(&self, tag: &NbtTag) -> Result<u32, BlockStateError> {
+        let state = BlockState::from_nbt(tag)?;
+
+        self.network_id(&state)
+            .ok_or(BlockStateError::UnknownState(state.name))
+    }
+
+    /// Returns the palette entry for a network ID, stored with the given block version.
+    pub fn network_id_to_palette_entry(
+        &self,
+        network_id: u32,
+        version: Option<i32>,
+    ) -> Result<NbtTag, BlockStateError> {
+        self.state(network_id)
+            .map(|state| state.to_nbt(version))
+            .ok_or(BlockStateError::UnknownNetworkId(network_id))
+    }
+
+    /// Returns the network IDs of all palette entries of a storage, in palette order.
+    pub fn palette_to_network_ids(
+        &self,
+        storage: &PalettedStorage,
+    ) -> Result<Vec<u32>, BlockStateError> {
+        storage
+            .palette
+            .iter()
+            .map(|tag| self.palette_entry_to_network_id(tag))
+            .collect()
+    }
+
+    /// Builds a palette from network IDs, stored with the given block version.
+    pub fn network_ids_to_palette(
+        &self,
+        network_ids: &[u32],
+        version: Option<i32>,
+    ) -> Result<Vec<NbtTag>, BlockStateError> {
+        network_ids
+            .iter()
+            .map(|id| self.network_id_to_palette_entry(*id, version))
+            .collect()
+    }
+
+    fn rebuild(&mut self) {
+        if self.mode == BlockNetworkIdMode::Sequential {
+            // The sort is stable, so states of a block keep their order
+            self.states
+                .sort_by_cached_key(|state| fnv1_64(state.name.as_bytes()));
+        }
+
+        self.by_bytes.clear();
+        self.by_network_id.clear();
+
+        for (index, state) in self.states.iter().enumerate() {
+            let network_id = match self.mode {
+                BlockNetworkIdMode::Hashed => state.network_hash(),
+                BlockNetworkIdMode::Sequential => index as u32,
+            };
+
+            self.by_bytes.insert(state.canonical_bytes(), network_id);
+            self.by_network_id.insert(network_id, index);
+        }
+    }
+}
+
+fn fnv1a_32(data: &[u8]) -> u32 {
+    data.iter().fold(FNV1_32_OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u32).wrapping_mul(FNV1_32_PRIME)
+    })
+}
+
+fn fnv1_64(data: &[u8]) -> u64 {
+    data.iter().fold(FNV1_64_OFFSET_BASIS, |hash, byte| {
+        hash.wrapping_mul(FNV1_64_PRIME) ^ *byte as u64
+    })
+}
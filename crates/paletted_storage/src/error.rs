@@ -0,0 +1,20 @@
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use bedrockrs_nbt::NbtError;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum BlockStateError {
+    #[error("IO Error at {1:?}: {0}")]
+    IOError(Arc<io::Error>, PathBuf),
+    #[error("Nbt Error: {0}")]
+    NbtError(#[from] NbtError),
+    #[error("Invalid block state: {0}")]
+    InvalidState(String),
+    #[error("Unknown block state: {0}")]
+    UnknownState(String),
+    #[error("Unknown block network ID: {0}")]
+    UnknownNetworkId(u32),
+}
@@ -6,6 +6,9 @@ use bedrockrs_nbt::endian::little_endian_network::NbtLittleEndianNetwork;
 use bedrockrs_nbt::NbtTag;
 use byteorder::ReadBytesExt;
 
+pub mod block_states;
+pub mod error;
+
 #[derive(Debug, Clone)]
 pub struct PalettedStorage {
     pub blocks: [u32; 4096],
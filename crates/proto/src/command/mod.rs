@@ -0,0 +1,3 @@
+pub mod parser;
+pub mod soft_enum;
+pub mod tree;
@@ -0,0 +1,612 @@
+use bedrockrs_core::Vec3;
+
+use crate::command::tree::{CommandParameter, CommandParameterKind, CommandTree};
+use crate::error::CommandParseError;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CommandCoordinate {
+    Absolute(f32),
+    /// Relative to the position of the executor, written as `~`.
+    Relative(f32),
+    /// Relative to the position and rotation of the executor, written as `^`.
+    Local(f32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CommandPosition {
+    pub x: CommandCoordinate,
+    pub y: CommandCoordinate,
+    pub z: CommandCoordinate,
+}
+
+impl CommandPosition {
+    /// Resolves the position relative to `origin`,
+    /// `None` if it contains local coordinates since they depend on the rotation.
+    pub fn resolve(&self, origin: Vec3<f32>) -> Option<Vec3<f32>> {
+        fn resolve(coordinate: CommandCoordinate, origin: f32) -> Option<f32> {
+            match coordinate {
+                CommandCoordinate::Absolute(v) => Some(v),
+                CommandCoordinate::Relative(v) => Some(origin + v),
+                CommandCoordinate::Local(_) => None,
+            }
+        }
+
+        Some(Vec3 {
+            x: resolve(self.x, origin.x)?,
+            y: resolve(self.y, origin.y)?,
+            z: resolve(self.z, origin.z)?,
+        })
+    }
+}
+
+/// The value of a parsed parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandArgument {
+    Int(i32),
+    Float(f32),
+    /// A `*` given for a wildcard parameter.
+    Wildcard,
+    /// A player name or target selector.
+    Target(String),
+    String(String),
+    Position(CommandPosition),
+    /// The rest of the command, given for message, raw text, json and command parameters.
+    Text(String),
+    /// The value of an enum, literal or soft enum as it is defined.
+    Enum(String),
+    /// The int of a suffixed parameter, without the suffix.
+    Suffixed(i32),
+}
+
+/// A command request matched against a [`CommandTree`].
+#[derive(Debug, Clone)]
+pub struct ParsedCommand {
+    /// The name of the command, also if it was run via an alias.
+    pub name: String,
+    /// The index of the overload that matched.
+    pub overload: usize,
+    /// The arguments by parameter name, omitted optional parameters are missing.
+    pub arguments: Vec<(String, CommandArgument)>,
+}
+
+impl ParsedCommand {
+    pub fn get(&self, name: &str) -> Option<&CommandArgument> {
+        self.arguments
+            .iter()
+            .find(|(parameter, _)| parameter == name)
+            .map(|(_, argument)| argument)
+    }
+}
+
+/// Reads whitespace separated tokens, keeping quoted strings and
+/// the brackets of target selectors together.
+#[derive(Debug, Clone)]
+struct Reader<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn is_empty(&mut self) -> bool {
+        self.skip_whitespace();
+        self.position >= self.input.len()
+    }
+
+    /// Returns the rest of the input.
+    fn rest(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = &self.input[self.position..];
+        self.position = self.input.len();
+        rest.trim_end()
+    }
+
+    fn token(&mut self) -> Result<Option<String>, CommandParseError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let mut token = String::new();
+        let mut chars = self.input[self.position..].char_indices();
+
+        let mut depth = 0;
+        let mut quoted = false;
+        let mut end = self.input.len() - self.position;
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' if quoted => {
+                    if let Some((_, c)) = chars.next() {
+                        token.push(c);
+                    }
+                    continue;
+                }
+                '"' => {
+                    quoted = !quoted;
+                    // Quotes are kept inside of selectors
+                    if depth == 0 {
+                        continue;
+                    }
+                }
+                '[' if !quoted => depth += 1,
+                ']' if !quoted && depth > 0 => depth -= 1,
+                c if c.is_whitespace() && !quoted && depth == 0 => {
+                    end = i;
+                    break;
+                }
+                _ => {}
+            }
+
+            token.push(c);
+        }
+
+        if quoted {
+            return Err(CommandParseError::UnterminatedQuote);
+        }
+
+        self.position += end;
+
+        Ok(Some(token))
+    }
+
+    /// Returns the next coordinate of a position, relative and local coordinates
+    /// don't have to be separated by whitespace, e.g. `~~1~` or `^1^2^3`.
+    fn coordinate(&mut self) -> Option<&'a str> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let rest = &self.input[self.position..];
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_whitespace() || *c == '~' || *c == '^')
+            .map_or(rest.len(), |(i, _)| i);
+
+        self.position += end;
+
+        Some(&rest[..end])
+    }
+}
+
+fn parse_coordinate(token: &str) -> Option<CommandCoordinate> {
+    let parse_offset = |v: &str| match v {
+        "" => Some(0.0),
+        v => v.parse().ok(),
+    };
+
+    if let Some(v) = token.strip_prefix('~') {
+        return parse_offset(v).map(CommandCoordinate::Relative);
+    }
+    if let Some(v) = token.strip_prefix('^') {
+        return parse_offset(v).map(CommandCoordinate::Local);
+    }
+
+    token.parse().ok().map(CommandCoordinate::Absolute)
+}
+
+fn find_value(values: &[String], token: &str) -> Option<String> {
+    values
+        .iter()
+        .find(|v| v.eq_ignore_ascii_case(token))
+        .cloned()
+}
+
+/// Parses the argument for a single parameter, `Ok(None)` if the input is exhausted.
+fn parse_argument(
+    reader: &mut Reader,
+    parameter: &CommandParameter,
+) -> Result<Option<CommandArgument>, CommandParseError> {
+    let invalid = |value: &str| CommandParseError::InvalidArgument {
+        parameter: parameter.name.clone(),
+        value: value.to_string(),
+    };
+
+    match &parameter.kind {
+        CommandParameterKind::Message
+        | CommandParameterKind::RawText
+        | CommandParameterKind::Json
+        | CommandParameterKind::Command => {
+            return Ok(match reader.rest() {
+                "" => None,
+                rest => Some(CommandArgument::Text(rest.to_string())),
+            });
+        }
+        CommandParameterKind::Position | CommandParameterKind::BlockPosition => {
+            let mut coordinates = [CommandCoordinate::Absolute(0.0); 3];
+
+            for (i, coordinate) in coordinates.iter_mut().enumerate() {
+                *coordinate = match reader.coordinate() {
+                    // A partially given position is invalid
+                    None if i > 0 => {
+                        return Err(CommandParseError::MissingArgument(parameter.name.clone()))
+                    }
+                    None => return Ok(None),
+                    Some(token) => parse_coordinate(token).ok_or_else(|| invalid(token))?,
+                };
+            }
+
+            let [x, y, z] = coordinates;
+
+            return Ok(Some(CommandArgument::Position(CommandPosition { x, y, z })));
+        }
+        _ => {}
+    }
+
+    let token = match reader.token()? {
+        None => return Ok(None),
+        Some(v) => v,
+    };
+
+    let argument = match &parameter.kind {
+        CommandParameterKind::Int => {
+            CommandArgument::Int(token.parse().map_err(|_| invalid(&token))?)
+        }
+        CommandParameterKind::Float | CommandParameterKind::Value => {
+            CommandArgument::Float(token.parse().map_err(|_| invalid(&token))?)
+        }
+        CommandParameterKind::WildcardInt => match token.as_str() {
+            "*" => CommandArgument::Wildcard,
+            _ => CommandArgument::Int(token.parse().map_err(|_| invalid(&token))?),
+        },
+        CommandParameterKind::Target => CommandArgument::Target(token),
+        CommandParameterKind::WildcardTarget => match token.as_str() {
+            "*" => CommandArgument::Wildcard,
+            _ => CommandArgument::Target(token),
+        },
+        CommandParameterKind::String => CommandArgument::String(token),
+        CommandParameterKind::Enum { values, .. } => {
+            CommandArgument::Enum(find_value(values, &token).ok_or_else(|| invalid(&token))?)
+        }
+        CommandParameterKind::Literal(value) => CommandArgument::Enum(
+            find_value(std::slice::from_ref(value), &token).ok_or_else(|| invalid(&token))?,
+        ),
+        // The values of soft enums may have changed since the command was registered
+        CommandParameterKind::SoftEnum { values, .. } => {
+            CommandArgument::Enum(find_value(values, &token).unwrap_or(token))
+        }
+        CommandParameterKind::Suffixed(suffix) => {
+            let split = token
+                .len()
+                .checked_sub(suffix.len())
+                .ok_or_else(|| invalid(&token))?;
+
+            match token.get(split..) {
+                Some(v) if v.eq_ignore_ascii_case(suffix) => {
+                    CommandArgument::Suffixed(token[..split].parse().map_err(|_| invalid(&token))?)
+                }
+                _ => return Err(invalid(&token)),
+            }
+        }
+        _ => unreachable!("Parameters consuming the rest of the input were handled before"),
+    };
+
+    Ok(Some(argument))
+}
+
+/// Parses the arguments of one overload, on failure returns how many parameters matched.
+fn parse_overload(
+    mut reader: Reader,
+    parameters: &[CommandParameter],
+) -> Result<Vec<(String, CommandArgument)>, (usize, CommandParseError)> {
+    let mut arguments = vec![];
+
+    for (i, parameter) in parameters.iter().enumerate() {
+        match parse_argument(&mut reader, parameter).map_err(|e| (i, e))? {
+            Some(argument) => arguments.push((parameter.name.clone(), argument)),
+            None if parameter.optional => break,
+            None => {
+                return Err((
+                    i,
+                    CommandParseError::MissingArgument(parameter.name.clone()),
+                ));
+            }
+        }
+    }
+
+    if !reader.is_empty() {
+        return Err((
+            parameters.len(),
+            CommandParseError::TooManyArguments(reader.rest().to_string()),
+        ));
+    }
+
+    Ok(arguments)
+}
+
+/// Parses a command like `/give @p diamond 5`, the leading `/` is optional.
+/// Overloads are tried in order, if none matches the error of the overload
+/// that matched the most parameters is returned.
+pub fn parse_command(tree: &CommandTree, input: &str) -> Result<ParsedCommand, CommandParseError> {
+    let input = input.trim();
+    let input = input.strip_prefix('/').unwrap_or(input);
+
+    let mut reader = Reader { input, position: 0 };
+
+    let name = reader.token()?.ok_or(CommandParseError::Empty)?;
+    let command = tree
+        .command(&name)
+        .ok_or(CommandParseError::UnknownCommand(name))?;
+
+    // Commands without overloads don't take arguments
+    if command.overloads.is_empty() {
+        return match reader.is_empty() {
+            true => Ok(ParsedCommand {
+                name: command.name.clone(),
+                overload: 0,
+                arguments: vec![],
+            }),
+            false => Err(CommandParseError::TooManyArguments(
+                reader.rest().to_string(),
+            )),
+        };
+    }
+
+    let mut best_error: Option<(usize, CommandParseError)> = None;
+
+    for (overload, parameters) in command.overloads.iter().enumerate() {
+        match parse_overload(reader.clone(), parameters) {
+            Ok(arguments) => {
+                return Ok(ParsedCommand {
+                    name: command.name.clone(),
+                    overload,
+                    arguments,
+                })
+            }
+            Err((matched, error)) => match &best_error {
+                Some((best, _)) if *best >= matched => {}
+                _ => best_error = Some((matched, error)),
+            },
+        }
+    }
+
+    Err(best_error
+        .map(|(_, error)| error)
+        .unwrap_or(CommandParseError::Empty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::tree::CommandDefinition;
+
+    fn tree() -> CommandTree {
+        let mut tree = CommandTree::new();
+
+        tree.register(
+            CommandDefinition::new("tp", "Teleports entities")
+                .alias("teleport")
+                .overload([CommandParameter::new(
+                    "destination",
+                    CommandParameterKind::Position,
+                )])
+                .overload([
+                    CommandParameter::new("victim", CommandParameterKind::Target),
+                    CommandParameter::new("destination", CommandParameterKind::Position),
+                ]),
+        )
+        .unwrap();
+        tree.register(
+            CommandDefinition::new("tag", "Manages tags")
+                .overload([
+                    CommandParameter::new("targets", CommandParameterKind::WildcardTarget),
+                    CommandParameter::literal("add"),
+                    CommandParameter::new("name", CommandParameterKind::String),
+                ])
+                .overload([
+                    CommandParameter::new("targets", CommandParameterKind::WildcardTarget),
+                    CommandParameter::literal("remove"),
+                    CommandParameter::new("name", CommandParameterKind::String),
+                ]),
+        )
+        .unwrap();
+        tree.register(
+            CommandDefinition::new("gamemode", "Sets the game mode").overload([
+                CommandParameter::new(
+                    "mode",
+                    CommandParameterKind::Enum {
+                        name: "GameMode".to_string(),
+                        values: vec!["survival".to_string(), "creative".to_string()],
+                    },
+                ),
+                CommandParameter::optional("player", CommandParameterKind::Target),
+            ]),
+        )
+        .unwrap();
+        tree.register(CommandDefinition::new("xp", "Adds experience").overload([
+            CommandParameter::new("amount", CommandParameterKind::Suffixed("L".to_string())),
+            CommandParameter::optional("player", CommandParameterKind::Target),
+        ]))
+        .unwrap();
+        tree.register(CommandDefinition::new("say", "Sends a message").overload([
+            CommandParameter::new("message", CommandParameterKind::Message),
+        ]))
+        .unwrap();
+        tree.register(CommandDefinition::new("list", "Lists all players"))
+            .unwrap();
+
+        tree
+    }
+
+    fn position(input: &str) -> CommandPosition {
+        match tree().parse(input).unwrap().get("destination") {
+            Some(CommandArgument::Position(v)) => *v,
+            v => panic!("Expected a position, got {v:?}"),
+        }
+    }
+
+    #[test]
+    fn absolute_position() {
+        use CommandCoordinate::Absolute;
+
+        assert_eq!(
+            position("/tp 1 -2.5 3"),
+            CommandPosition {
+                x: Absolute(1.0),
+                y: Absolute(-2.5),
+                z: Absolute(3.0),
+            }
+        );
+    }
+
+    #[test]
+    fn relative_position_without_whitespace() {
+        use CommandCoordinate::Relative;
+
+        assert_eq!(
+            position("/tp ~~~"),
+            CommandPosition {
+                x: Relative(0.0),
+                y: Relative(0.0),
+                z: Relative(0.0),
+            }
+        );
+        assert_eq!(
+            position("/tp ~1~-2~"),
+            CommandPosition {
+                x: Relative(1.0),
+                y: Relative(-2.0),
+                z: Relative(0.0),
+            }
+        );
+    }
+
+    #[test]
+    fn local_position_without_whitespace() {
+        use CommandCoordinate::Local;
+
+        assert_eq!(
+            position("/tp ^1^2^3"),
+            CommandPosition {
+                x: Local(1.0),
+                y: Local(2.0),
+                z: Local(3.0),
+            }
+        );
+    }
+
+    #[test]
+    fn mixed_position() {
+        use CommandCoordinate::{Absolute, Relative};
+
+        assert_eq!(
+            position("/tp 5 ~ ~-1"),
+            CommandPosition {
+                x: Absolute(5.0),
+                y: Relative(0.0),
+                z: Relative(-1.0),
+            }
+        );
+    }
+
+    #[test]
+    fn partial_position() {
+        assert!(matches!(
+            tree().parse("/tp 1 2"),
+            Err(CommandParseError::MissingArgument(name)) if name == "destination"
+        ));
+    }
+
+    #[test]
+    fn overload_and_alias() {
+        let parsed = tree().parse("teleport @p ~ 64 ~").unwrap();
+
+        assert_eq!(parsed.name, "tp");
+        assert_eq!(parsed.overload, 1);
+        assert_eq!(
+            parsed.get("victim"),
+            Some(&CommandArgument::Target("@p".to_string()))
+        );
+    }
+
+    #[test]
+    fn selector_with_whitespace() {
+        let parsed = tree().parse(r#"/tp @a[name="a b", r=3] 0 0 0"#).unwrap();
+
+        assert_eq!(
+            parsed.get("victim"),
+            Some(&CommandArgument::Target(
+                r#"@a[name="a b", r=3]"#.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn literal() {
+        let parsed = tree().parse(r#"/tag * REMOVE "a \"b\"""#).unwrap();
+
+        assert_eq!(parsed.overload, 1);
+        assert_eq!(parsed.get("targets"), Some(&CommandArgument::Wildcard));
+        assert_eq!(
+            parsed.get("remove"),
+            Some(&CommandArgument::Enum("remove".to_string()))
+        );
+        assert_eq!(
+            parsed.get("name"),
+            Some(&CommandArgument::String(r#"a "b""#.to_string()))
+        );
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        assert!(matches!(
+            tree().parse(r#"/tag @s add "name"#),
+            Err(CommandParseError::UnterminatedQuote)
+        ));
+    }
+
+    #[test]
+    fn enum_and_optional() {
+        let parsed = tree().parse("/gamemode Creative").unwrap();
+
+        assert_eq!(
+            parsed.get("mode"),
+            Some(&CommandArgument::Enum("creative".to_string()))
+        );
+        assert_eq!(parsed.get("player"), None);
+
+        assert!(matches!(
+            tree().parse("/gamemode spectator"),
+            Err(CommandParseError::InvalidArgument { parameter, .. }) if parameter == "mode"
+        ));
+    }
+
+    #[test]
+    fn suffixed() {
+        let parsed = tree().parse("/xp 5l").unwrap();
+
+        assert_eq!(parsed.get("amount"), Some(&CommandArgument::Suffixed(5)));
+        assert!(tree().parse("/xp 5").is_err());
+    }
+
+    #[test]
+    fn message() {
+        let parsed = tree().parse("/say  hello  world ").unwrap();
+
+        assert_eq!(
+            parsed.get("message"),
+            Some(&CommandArgument::Text("hello  world".to_string()))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(tree().parse("/"), Err(CommandParseError::Empty)));
+        assert!(matches!(
+            tree().parse("/unknown"),
+            Err(CommandParseError::UnknownCommand(name)) if name == "unknown"
+        ));
+        assert!(matches!(
+            tree().parse("/list all"),
+            Err(CommandParseError::TooManyArguments(rest)) if rest == "all"
+        ));
+        assert!(matches!(
+            tree().parse("/gamemode survival @s extra"),
+            Err(CommandParseError::TooManyArguments(rest)) if rest == "extra"
+        ));
+    }
+}
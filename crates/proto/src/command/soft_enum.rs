@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use crate::command::tree::{CommandParameter, CommandParameterKind};
+use crate::connection::ConnectionShard;
+use crate::error::ConnectionError;
+use crate::gamepacket::GamePacket;
+use crate::packets::available_commands::AvailableCommandsPacket;
+use crate::packets::update_soft_enum::UpdateSoftEnumPacket;
+use crate::types::soft_enum_action::SoftEnumAction;
+
+/// The values a soft enum should have, e.g. the names of all warps.
+/// Changes are sent to each connection via its [`SoftEnumTracker`].
+#[derive(Debug, Clone)]
+pub struct SoftEnum {
+    name: String,
+    values: Vec<String>,
+}
+
+impl SoftEnum {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            values: vec![],
+        }
+    }
+
+    pub fn with_values(name: impl Into<String>, values: Vec<String>) -> Self {
+        let mut soft_enum = Self::new(name);
+        soft_enum.set(values);
+        soft_enum
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    pub fn contains(&self, value: &str) -> bool {
+        self.values.iter().any(|v| v == value)
+    }
+
+    /// Adds a value, returns false if it was already present.
+    pub fn add(&mut self, value: impl Into<String>) -> bool {
+        let value = value.into();
+
+        if self.contains(&value) {
+            return false;
+        }
+
+        self.values.push(value);
+        true
+    }
+
+    /// Removes a value, returns false if it wasn't present.
+    pub fn remove(&mut self, value: &str) -> bool {
+        let len = self.values.len();
+        self.values.retain(|v| v != value);
+        self.values.len() != len
+    }
+
+    pub fn set(&mut self, values: Vec<String>) {
+        self.values.clear();
+
+        for value in values {
+            self.add(value);
+        }
+    }
+
+    /// Creates a parameter accepting the values of this enum.
+    /// Commands using the same soft enum have to be registered with the same values.
+    pub fn parameter(&self, name: impl Into<String>, optional: bool) -> CommandParameter {
+        let kind = CommandParameterKind::SoftEnum {
+            name: self.name.clone(),
+            values: self.values.clone(),
+        };
+
+        match optional {
+            true => CommandParameter::optional(name, kind),
+            false => CommandParameter::new(name, kind),
+        }
+    }
+}
+
+/// Tracks the soft enum values a single connection knows about,
+/// so only the changes have to be sent.
+#[derive(Debug, Clone, Default)]
+pub struct SoftEnumTracker {
+    known: HashMap<String, Vec<String>>,
+}
+
+impl SoftEnumTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a tracker knowing the soft enums sent in the packet.
+    pub fn from_packet(packet: &AvailableCommandsPacket) -> Self {
+        Self {
+            known: packet
+                .soft_enums
+                .iter()
+                .map(|v| (v.name.clone(), v.values.clone()))
+                .collect(),
+        }
+    }
+
+    /// Returns the values the connection knows for the soft enum.
+    pub fn values(&self, name: &str) -> Option<&[String]> {
+        self.known.get(name).map(Vec::as_slice)
+    }
+
+    /// Returns the updates bringing the connection to the values of `soft_enum`.
+    /// Added and removed values are sent, unless replacing all values is smaller.
+    pub fn diff(&self, soft_enum: &SoftEnum) -> Vec<UpdateSoftEnumPacket> {
+        let packet = |values: Vec<String>, action| UpdateSoftEnumPacket {
+            enum_name: soft_enum.name.clone(),
+            values,
+            action,
+        };
+
+        let known = match self.known.get(&soft_enum.name) {
+            Some(v) => v,
+            None => return vec![packet(soft_enum.values.clone(), SoftEnumAction::Set)],
+        };
+
+        let removed: Vec<String> = known
+            .iter()
+            .filter(|v| !soft_enum.contains(v))
+            .cloned()
+            .collect();
+        let added: Vec<String> = soft_enum
+            .values
+            .iter()
+            .filter(|v| !known.contains(v))
+            .cloned()
+            .collect();
+
+        if removed.is_empty() && added.is_empty() {
+            return vec![];
+        }
+
+        if removed.len() + added.len() >= soft_enum.values.len() {
+            return vec![packet(soft_enum.values.clone(), SoftEnumAction::Set)];
+        }
+
+        let mut packets = vec![];
+        if !removed.is_empty() {
+            packets.push(packet(removed, SoftEnumAction::Remove));
+        }
+        if !added.is_empty() {
+            packets.push(packet(added, SoftEnumAction::Add));
+        }
+        packets
+    }
+
+    /// Sends the changes of `soft_enum` to the connection.
+    pub async fn sync(
+        &mut self,
+        conn: &mut ConnectionShard,
+        soft_enum: &SoftEnum,
+    ) -> Result<(), ConnectionError> {
+        let packets = self.diff(soft_enum);
+
+        if packets.is_empty() {
+            return Ok(());
+        }
+
+        for packet in packets {
+            conn.send(GamePacket::UpdateSoftEnum(packet)).await?;
+        }
+
+        self.known
+            .insert(soft_enum.name.clone(), soft_enum.values.clone());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use bedrockrs_core::int::LE;
+
+use crate::command::parser::{parse_command, ParsedCommand};
+use crate::error::{CommandParseError, CommandRegistrationError};
+use crate::packets::available_commands::AvailableCommandsPacket;
+use crate::types::command_data::{
+    CommandData, CommandEnum, CommandOverload, CommandParameterData, CommandParameterType,
+    CommandPermissionLevel, CommandSoftEnum, COMMAND_PARAMETER_FLAG_ENUM,
+    COMMAND_PARAMETER_FLAG_SOFT_ENUM, COMMAND_PARAMETER_FLAG_SUFFIXED,
+    COMMAND_PARAMETER_FLAG_VALID, COMMAND_PARAMETER_OPTION_COLLAPSE_ENUM,
+};
+
+/// What a parameter of a command accepts.
+#[derive(Debug, Clone)]
+pub enum CommandParameterKind {
+    Int,
+    Float,
+    Value,
+    /// An int or `*`.
+    WildcardInt,
+    /// A player name or target selector.
+    Target,
+    /// A player name, target selector or `*`.
+    WildcardTarget,
+    String,
+    Position,
+    BlockPosition,
+    /// The rest of the command as text.
+    Message,
+    /// The rest of the command as raw text json.
+    RawText,
+    /// The rest of the command as json.
+    Json,
+    /// The rest of the command as another command, like in `/execute run`.
+    Command,
+    /// One of the given values.
+    Enum {
+        name: String,
+        values: Vec<String>,
+    },
+    /// A fixed keyword, e.g. the `add` in `/tag add`, sent as an enum with a single value
+    /// named after the command and the keyword.
+    Literal(String),
+    /// One of the given values, which can be changed after the commands were sent.
+    SoftEnum {
+        name: String,
+        values: Vec<String>,
+    },
+    /// An int followed by a suffix, e.g. `5L` for the suffix `L`.
+    Suffixed(String),
+}
+
+impl CommandParameterKind {
+    fn parameter_type(&self) -> Option<CommandParameterType> {
+        Some(match self {
+            CommandParameterKind::Int => CommandParameterType::Int,
+            CommandParameterKind::Float => CommandParameterType::Float,
+            CommandParameterKind::Value => CommandParameterType::Value,
+            CommandParameterKind::WildcardInt => CommandParameterType::WildcardInt,
+            CommandParameterKind::Target => CommandParameterType::Target,
+            CommandParameterKind::WildcardTarget => CommandParameterType::WildcardTarget,
+            CommandParameterKind::String => CommandParameterType::String,
+            CommandParameterKind::Position => CommandParameterType::Position,
+            CommandParameterKind::BlockPosition => CommandParameterType::BlockPosition,
+            CommandParameterKind::Message => CommandParameterType::Message,
+            CommandParameterKind::RawText => CommandParameterType::RawText,
+            CommandParameterKind::Json => CommandParameterType::Json,
+            CommandParameterKind::Command => CommandParameterType::Command,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandParameter {
+    pub name: String,
+    pub kind: CommandParameterKind,
+    pub optional: bool,
+}
+
+impl CommandParameter {
+    pub fn new(name: impl Into<String>, kind: CommandParameterKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            optional: false,
+        }
+    }
+
+    pub fn optional(name: impl Into<String>, kind: CommandParameterKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            optional: true,
+        }
+    }
+
+    pub fn literal(value: impl Into<String>) -> Self {
+        let value = value.into();
+
+        Self::new(value.clone(), CommandParameterKind::Literal(value))
+    }
+}
+
+/// A command and all ways it can be used.
+#[derive(Debug, Clone)]
+pub struct CommandDefinition {
+    pub name: String,
+    pub description: String,
+    pub aliases: Vec<String>,
+    pub permission_level: CommandPermissionLevel,
+    /// A combination of the `COMMAND_FLAG_*` flags.
+    pub flags: u16,
+    /// The parameter lists the command accepts, tried in order when parsing.
+    pub overloads: Vec<Vec<CommandParameter>>,
+}
+
+impl CommandDefinition {
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into().to_lowercase(),
+            description: description.into(),
+            aliases: vec![],
+            permission_level: CommandPermissionLevel::Any,
+            flags: 0,
+            overloads: vec![],
+        }
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into().to_lowercase());
+        self
+    }
+
+    pub fn permission_level(mut self, permission_level: CommandPermissionLevel) -> Self {
+        self.permission_level = permission_level;
+        self
+    }
+
+    pub fn flags(mut self, flags: u16) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn overload(mut self, parameters: impl IntoIterator<Item = CommandParameter>) -> Self {
+        self.overloads.push(parameters.into_iter().collect());
+        self
+    }
+
+    /// If the name or one of the aliases is `name`, ignoring the case.
+    pub fn matches(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+
+        self.name == name || self.aliases.contains(&name)
+    }
+}
+
+/// All commands of a server, used to send them to clients and to parse their requests.
+#[derive(Debug, Clone, Default)]
+pub struct CommandTree {
+    commands: Vec<CommandDefinition>,
+}
+
+impl CommandTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a command, replacing a command with the same name.
+    /// Fails if the command uses an enum or soft enum with the name of an
+    /// already registered one but with different values.
+    pub fn register(&mut self, command: CommandDefinition) -> Result<(), CommandRegistrationError> {
+        let mut builder = PacketBuilder::default();
+
+        for registered in self.commands.iter().filter(|v| v.name != command.name) {
+            builder.command(registered)?;
+        }
+        builder.command(&command)?;
+
+        self.commands.retain(|v| v.name != command.name);
+        self.commands.push(command);
+
+        Ok(())
+    }
+
+    pub fn unregister(&mut self, name: &str) -> Option<CommandDefinition> {
+        let index = self.commands.iter().position(|v| v.name == name)?;
+
+        Some(self.commands.remove(index))
+    }
+
+    /// Returns the command with the name or alias `name`.
+    pub fn command(&self, name: &str) -> Option<&CommandDefinition> {
+        self.commands.iter().find(|v| v.matches(name))
+    }
+
+    pub fn commands(&self) -> &[CommandDefinition] {
+        &self.commands
+    }
+
+    /// Parses the command of a [`CommandRequestPacket`](crate::packets::command_request::CommandRequestPacket).
+    pub fn parse(&self, input: &str) -> Result<ParsedCommand, CommandParseError> {
+        parse_command(self, input)
+    }
+
+    /// Builds the packet for all commands whose permission level is at most `permission_level`.
+    pub fn available_commands_packet(
+        &self,
+        permission_level: CommandPermissionLevel,
+    ) -> AvailableCommandsPacket {
+        let mut builder = PacketBuilder::default();
+
+        let commands = self
+            .commands
+            .iter()
+            .filter(|v| v.permission_level as u8 <= permission_level as u8)
+            .map(|command| {
+                builder
+                    .command(command)
+                    .expect("Enums of registered commands are checked for clashes")
+            })
+            .collect();
+
+        AvailableCommandsPacket {
+            enum_values: builder.enum_values,
+            chained_subcommand_values: vec![],
+            suffixes: builder.suffixes,
+            enums: builder.enums,
+            chained_subcommands: vec![],
+            commands,
+            soft_enums: builder.soft_enums,
+            enum_constraints: vec![],
+        }
+    }
+}
+
+/// Deduplicates the tables of the [`AvailableCommandsPacket`] while building it.
+#[derive(Default)]
+struct PacketBuilder {
+    enum_values: Vec<String>,
+    enum_value_indices: HashMap<String, u32>,
+    suffixes: Vec<String>,
+    enums: Vec<CommandEnum>,
+    enum_indices: HashMap<String, u32>,
+    soft_enums: Vec<CommandSoftEnum>,
+    soft_enum_indices: HashMap<String, u32>,
+}
+
+impl PacketBuilder {
+    fn enum_value(&mut self, value: &str) -> u32 {
+        if let Some(index) = self.enum_value_indices.get(value) {
+            return *index;
+        }
+
+        let index = self.enum_values.len() as u32;
+        self.enum_values.push(value.to_string());
+        self.enum_value_indices.insert(value.to_string(), index);
+        index
+    }
+
+    /// Returns the index of the enum, enums are identified by their name.
+    fn command_enum(
+        &mut self,
+        name: &str,
+        values: &[String],
+    ) -> Result<u32, CommandRegistrationError> {
+        if let Some(index) = self.enum_indices.get(name) {
+            let registered = &self.enums[*index as usize].value_indices;
+
+            let equal = registered.len() == values.len()
+                && registered
+                    .iter()
+                    .zip(values)
+                    .all(|(v, value)| &self.enum_values[*v as usize] == value);
+
+            return match equal {
+                true => Ok(*index),
+                false => Err(CommandRegistrationError::EnumMismatch(name.to_string())),
+            };
+        }
+
+        let value_indices = values.iter().map(|v| self.enum_value(v)).collect();
+
+        let index = self.enums.len() as u32;
+        self.enums.push(CommandEnum {
+            name: name.to_string(),
+            value_indices,
+        });
+        self.enum_indices.insert(name.to_string(), index);
+        Ok(index)
+    }
+
+    fn soft_enum(
+        &mut self,
+        name: &str,
+        values: &[String],
+    ) -> Result<u32, CommandRegistrationError> {
+        if let Some(index) = self.soft_enum_indices.get(name) {
+            return match self.soft_enums[*index as usize].values == values {
+                true => Ok(*index),
+                false => Err(CommandRegistrationError::SoftEnumMismatch(name.to_string())),
+            };
+        }
+
+        let index = self.soft_enums.len() as u32;
+        self.soft_enums.push(CommandSoftEnum {
+            name: name.to_string(),
+            values: values.to_vec(),
+        });
+        self.soft_enum_indices.insert(name.to_string(), index);
+        Ok(index)
+    }
+
+    fn suffix(&mut self, suffix: &str) -> u32 {
+        match self.suffixes.iter().position(|v| v == suffix) {
+            Some(index) => index as u32,
+            None => {
+                self.suffixes.push(suffix.to_string());
+                (self.suffixes.len() - 1) as u32
+            }
+        }
+    }
+
+    fn parameter(
+        &mut self,
+        command: &str,
+        parameter: &CommandParameter,
+    ) -> Result<CommandParameterData, CommandRegistrationError> {
+        let mut options = 0;
+
+        let type_info = match &parameter.kind {
+            CommandParameterKind::Enum { name, values } => {
+                COMMAND_PARAMETER_FLAG_VALID
+                    | COMMAND_PARAMETER_FLAG_ENUM
+                    | self.command_enum(name, values)?
+            }
+            CommandParameterKind::Literal(value) => {
+                options |= COMMAND_PARAMETER_OPTION_COLLAPSE_ENUM;
+
+                // Namespaced so it doesn't share the enum of another command or a user enum
+                let name = format!("{command}.{value}");
+
+                COMMAND_PARAMETER_FLAG_VALID
+                    | COMMAND_PARAMETER_FLAG_ENUM
+                    | self.command_enum(&name, std::slice::from_ref(value))?
+            }
+            CommandParameterKind::SoftEnum { name, values } => {
+                COMMAND_PARAMETER_FLAG_VALID
+                    | COMMAND_PARAMETER_FLAG_SOFT_ENUM
+                    | self.soft_enum(name, values)?
+            }
+            CommandParameterKind::Suffixed(suffix) => {
+                COMMAND_PARAMETER_FLAG_SUFFIXED | self.suffix(suffix)
+            }
+            kind => {
+                // All other kinds are basic types
+                COMMAND_PARAMETER_FLAG_VALID | kind.parameter_type().map_or(0, |v| v as u32)
+            }
+        };
+
+        Ok(CommandParameterData {
+            name: parameter.name.clone(),
+            type_info: LE::new(type_info),
+            optional: parameter.optional,
+            options,
+        })
+    }
+
+    fn command(
+        &mut self,
+        command: &CommandDefinition,
+    ) -> Result<CommandData, CommandRegistrationError> {
+        let alias_enum_index = match command.aliases.is_empty() {
+            true => -1,
+            false => {
+                let mut values = vec![command.name.clone()];
+                values.extend(command.aliases.iter().cloned());
+
+                self.command_enum(&format!("{}Aliases", command.name), &values)? as i32
+            }
+        };
+
+        let mut overloads = command
+            .overloads
+            .iter()
+            .map(|parameters| {
+                Ok(CommandOverload {
+                    chaining: false,
+                    parameters: parameters
+                        .iter()
+                        .map(|v| self.parameter(&command.name, v))
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect::<Result<Vec<_>, CommandRegistrationError>>()?;
+
+        // The client requires at least one overload
+        if overloads.is_empty() {
+            overloads.push(CommandOverload {
+                chaining: false,
+                parameters: vec![],
+            });
+        }
+
+        Ok(CommandData {
+            name: command.name.clone(),
+            description: command.description.clone(),
+            flags: LE::new(command.flags),
+            permission_level: command.permission_level,
+            alias_enum_index: LE::new(alias_enum_index),
+            chained_subcommand_indices: vec![],
+            overloads,
+        })
+    }
+}
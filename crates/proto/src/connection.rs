@@ -4,6 +4,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use bedrockrs_core::int::LE;
+use bedrockrs_proto_core::version::with_protocol_version;
 use tokio::select;
 use tokio::sync::{broadcast, watch};
 use tokio::time::interval;
@@ -12,6 +13,7 @@ use crate::compression::Compression;
 use crate::encryption::Encryption;
 use crate::error::ConnectionError;
 use crate::gamepacket::GamePacket;
+use crate::send_queue::{SendQueue, SendQueueMetrics, SendQueuePush, SendQueueSettings};
 use crate::transport_layer::TransportLayerConnection;
 
 pub struct Connection {
@@ -25,6 +27,9 @@ pub struct Connection {
     /// login process, if encryption is allowed.
     pub encryption: Option<Encryption>,
     pub cache_supported: bool,
+    /// Represents the protocol version negotiated with the other side, the protocol version
+    /// gets set in the login process. All packets are en-/decoded for this version.
+    pub protocol_version: Option<i32>,
 }
 
 impl Connection {
@@ -34,6 +39,7 @@ impl Connection {
             compression: None,
             encryption: None,
             cache_supported: false,
+            protocol_version: None,
         }
     }
 
@@ -41,12 +47,16 @@ impl Connection {
         let mut pk_stream = vec![];
 
         // Batch all game packets together
-        for game_packet in gamepackets {
-            // Write a game packet
-            game_packet
-                .pk_serialize(&mut pk_stream)
-                .map_err(|e| ConnectionError::ProtoCodecError(e))?
-        }
+        with_protocol_version(self.protocol_version, || -> Result<(), ConnectionError> {
+            for game_packet in gamepackets {
+                // Write a game packet
+                game_packet
+                    .pk_serialize(&mut pk_stream)
+                    .map_err(ConnectionError::ProtoCodecError)?
+            }
+
+            Ok(())
+        })?;
 
         // Compress the data depending on compression method
         let compressed_stream = match &self.compression {
@@ -145,20 +155,24 @@ impl Connection {
 
         let mut gamepackets = vec![];
 
-        // Read gamepacket loop
-        'gamepacket_read: loop {
-            // Deserialize gamepacket
-            match GamePacket::pk_deserialize(&mut decompressed_stream) {
-                Ok(v) => gamepackets.push(v.0),
-                Err(e) => return Err(ConnectionError::ProtoCodecError(e)),
-            };
-
-            // Is at the end of batched packet data cursor
-            // TODO: Overflow checking
-            if decompressed_stream.position() == decompressed_stream.get_ref().len() as u64 {
-                break 'gamepacket_read;
+        with_protocol_version(self.protocol_version, || -> Result<(), ConnectionError> {
+            // Read gamepacket loop
+            'gamepacket_read: loop {
+                // Deserialize gamepacket
+                match GamePacket::pk_deserialize(&mut decompressed_stream) {
+                    Ok(v) => gamepackets.push(v.0),
+                    Err(e) => return Err(ConnectionError::ProtoCodecError(e)),
+                };
+
+                // Is at the end of batched packet data cursor
+                // TODO: Overflow checking
+                if decompressed_stream.position() == decompressed_stream.get_ref().len() as u64 {
+                    break 'gamepacket_read;
+                }
             }
-        }
+
+            Ok(())
+        })?;
 
         Ok(gamepackets)
     }
@@ -183,6 +197,7 @@ impl Connection {
         mut self,
         flush_interval: Duration,
         packet_buffer_size: usize,
+        send_queue_settings: SendQueueSettings,
     ) -> ConnectionShard {
         let (shard_pk_sender, mut task_pk_receiver) =
             broadcast::channel::<GamePacket>(packet_buffer_size);
@@ -215,9 +230,19 @@ impl Connection {
         let (mut task_cache_supported_sender, shard_cache_supported_receiver) =
             watch::channel(self.cache_supported.clone());
 
+        let (shard_protocol_version_sender, mut task_protocol_version_receiver) =
+            watch::channel(self.protocol_version);
+        let (shard_protocol_version_request_sender, mut task_protocol_version_request_receiver) =
+            watch::channel(());
+        let (task_protocol_version_sender, shard_protocol_version_receiver) =
+            watch::channel(self.protocol_version);
+
+        let (task_send_queue_metrics_sender, shard_send_queue_metrics_receiver) =
+            watch::channel(SendQueueMetrics::default());
+
         tokio::spawn(async move {
             let mut flush_interval = interval(flush_interval);
-            let mut send_buffer = vec![];
+            let mut send_queue = SendQueue::new(send_queue_settings);
 
             'select_loop: loop {
                 select! {
@@ -245,6 +270,13 @@ impl Connection {
 
                         self.cache_supported = task_cache_supported_receiver.borrow_and_update().to_owned();
                     }
+                    res = task_protocol_version_receiver.changed() => {
+                        if let Err(_) = res {
+                            break 'select_loop
+                        }
+
+                        self.protocol_version = *task_protocol_version_receiver.borrow_and_update();
+                    }
                     res = task_compression_request_receiver.changed() => {
                         if let Err(_) = res {
                             break 'select_loop
@@ -272,6 +304,15 @@ impl Connection {
                             break 'select_loop
                         }
                     }
+                    res = task_protocol_version_request_receiver.changed() => {
+                        if let Err(_) = res {
+                            break 'select_loop
+                        }
+
+                        if let Err(_) = task_protocol_version_sender.send(self.protocol_version) {
+                            break 'select_loop
+                        }
+                    }
                     res = self.recv() => {
                         match res {
                             Ok(pks) => {
@@ -289,39 +330,44 @@ impl Connection {
                         }
                     }
                     res = task_pk_receiver.recv() => {
-                        if let Err(_) = res {
+                        let pk = match res {
+                            Ok(pk) => pk,
+                            Err(_) => { break 'select_loop }
+                        };
+
+                        // The client isn't keeping up, close the connection
+                        // before the queue takes down the whole server
+                        if let SendQueuePush::Overflow = send_queue.push(pk) {
+                            let _ = task_pk_sender.send(Err(ConnectionError::SendQueueOverflow(send_queue.len())));
                             break 'select_loop
                         }
 
-                        match res {
-                            Ok(pk) => { send_buffer.push(pk); }
-                            Err(e) => { break 'select_loop }
-                        };
+                        task_send_queue_metrics_sender.send_replace(send_queue.metrics());
                     }
                     res = task_flush_request_receiver.changed() => {
                         if let Err(_) = res {
                             break 'select_loop
                         }
 
-                        if !send_buffer.is_empty() {
-                            if let Err(_) = self.send(send_buffer).await {
+                        if !send_queue.is_empty() {
+                            if self.send(send_queue.take_all()).await.is_err() {
                                 break 'select_loop
                             }
 
+                            task_send_queue_metrics_sender.send_replace(send_queue.metrics());
+
                             if let Err(_) = task_flush_complete_sender.send(()) {
                                 break 'select_loop
                             }
-
-                            send_buffer = vec![];
                         }
                     }
                     _ = flush_interval.tick() => {
-                        if !send_buffer.is_empty() {
-                            if let Err(_) = self.send(send_buffer).await {
+                        if !send_queue.is_empty() {
+                            if self.send(send_queue.take()).await.is_err() {
                                 break 'select_loop
                             }
 
-                            send_buffer = vec![];
+                            task_send_queue_metrics_sender.send_replace(send_queue.metrics());
                         }
                     }
                 }
@@ -350,6 +396,12 @@ impl Connection {
             cache_supported_sender: shard_cache_supported_sender,
             cache_supported_request_sender: shard_cache_supported_request_sender,
             cache_supported_receiver: shard_cache_supported_receiver,
+
+            protocol_version_sender: shard_protocol_version_sender,
+            protocol_version_request_sender: shard_protocol_version_request_sender,
+            protocol_version_receiver: shard_protocol_version_receiver,
+
+            send_queue_metrics_receiver: shard_send_queue_metrics_receiver,
         }
     }
 }
@@ -374,6 +426,12 @@ pub struct ConnectionShard {
     cache_supported_sender: watch::Sender<bool>,
     cache_supported_request_sender: watch::Sender<()>,
     cache_supported_receiver: watch::Receiver<bool>,
+
+    protocol_version_sender: watch::Sender<Option<i32>>,
+    protocol_version_request_sender: watch::Sender<()>,
+    protocol_version_receiver: watch::Receiver<Option<i32>>,
+
+    send_queue_metrics_receiver: watch::Receiver<SendQueueMetrics>,
 }
 
 impl ConnectionShard {
@@ -494,6 +552,33 @@ impl ConnectionShard {
             Err(_) => Err(ConnectionError::ConnectionClosed),
         }
     }
+
+    pub async fn set_protocol_version(
+        &mut self,
+        protocol_version: Option<i32>,
+    ) -> Result<(), ConnectionError> {
+        match self.protocol_version_sender.send(protocol_version) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ConnectionError::ConnectionClosed),
+        }
+    }
+
+    pub async fn get_protocol_version(&mut self) -> Result<Option<i32>, ConnectionError> {
+        match self.protocol_version_request_sender.send(()) {
+            Ok(_) => {}
+            Err(_) => return Err(ConnectionError::ConnectionClosed),
+        };
+
+        match self.protocol_version_receiver.changed().await {
+            Ok(_) => Ok(*self.protocol_version_receiver.borrow_and_update()),
+            Err(_) => Err(ConnectionError::ConnectionClosed),
+        }
+    }
+
+    /// Returns the latest statistics of the connections send queue.
+    pub fn send_queue_metrics(&self) -> SendQueueMetrics {
+        *self.send_queue_metrics_receiver.borrow()
+    }
 }
 
 impl Clone for ConnectionShard {
@@ -518,6 +603,12 @@ impl Clone for ConnectionShard {
             cache_supported_sender: self.cache_supported_sender.clone(),
             cache_supported_request_sender: self.cache_supported_request_sender.clone(),
             cache_supported_receiver: self.cache_supported_receiver.clone(),
+
+            protocol_version_sender: self.protocol_version_sender.clone(),
+            protocol_version_request_sender: self.protocol_version_request_sender.clone(),
+            protocol_version_receiver: self.protocol_version_receiver.clone(),
+
+            send_queue_metrics_receiver: self.send_queue_metrics_receiver.clone(),
         }
     }
 }
@@ -0,0 +1,408 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+use bedrockrs_shared::world::dimension::Dimension;
+use bedrockrs_shared::world::generator_type::GeneratorType;
+
+use crate::connection::ConnectionShard;
+use crate::error::{ConnectionError, DimensionError};
+use crate::gamepacket::GamePacket;
+use crate::packets::change_dimension::ChangeDimensionPacket;
+use crate::packets::dimension_data::DimensionDataPacket;
+use crate::packets::level_chunk::LevelChunkPacket;
+use crate::packets::network_chunk_publisher_update::NetworkChunkPublisherUpdatePacket;
+use crate::packets::play_status::PlayStatusPacket;
+use crate::packets::player_action::PlayerActionPacket;
+use crate::packets::respawn::RespawnPacket;
+use crate::packets::stop_sound::StopSoundPacket;
+use crate::types::chunk_pos::ChunkPos;
+use crate::types::dimension_definition::DimensionDefinition;
+use crate::types::network_block_pos::NetworkBlockPos;
+use crate::types::play_status::PlayStatusType;
+use crate::types::player_action_type::PlayerActionType;
+use crate::types::respawn_state::RespawnState;
+
+/// Returned once the player finished a dimension change or respawned.
+#[derive(Debug, Clone)]
+pub enum DimensionEvent {
+    /// The client loaded the new dimension, its chunks can be sent now.
+    Changed {
+        dimension: Dimension,
+        position: Vec3<f32>,
+        /// If the change happened because the player respawned in another dimension.
+        respawned: bool,
+    },
+    /// The player respawned in the same dimension.
+    Respawned { position: Vec3<f32> },
+}
+
+#[derive(Debug, Clone)]
+struct PendingChange {
+    dimension: Dimension,
+    position: Vec3<f32>,
+    respawn: bool,
+}
+
+#[derive(Debug, Clone)]
+enum RespawnStatus {
+    Alive,
+    /// Waiting for the player to press the respawn button.
+    Dead {
+        dimension: Dimension,
+        position: Vec3<f32>,
+    },
+}
+
+/// Runs dimension changes and respawns of a player.
+///
+/// The server acknowledges a dimension change itself, but it is only finished once the
+/// client acknowledged it as well with a [`PlayerActionType::DimensionChangeDone`],
+/// so [`PlayerActionPacket`]s and
+/// [`RespawnPacket`]s of the client must be passed to [`DimensionManager::on_player_action`]
+/// and [`DimensionManager::on_respawn`].
+#[derive(Debug, Clone)]
+pub struct DimensionManager {
+    runtime_id: ActorRuntimeID,
+    dimension: Dimension,
+    /// The chunk radius of the player, used for the chunk publisher.
+    chunk_radius: u32,
+    definitions: Vec<DimensionDefinition>,
+    pending: Option<PendingChange>,
+    respawn: RespawnStatus,
+}
+
+impl DimensionManager {
+    pub fn new(runtime_id: ActorRuntimeID, dimension: Dimension, chunk_radius: u32) -> Self {
+        Self {
+            runtime_id,
+            dimension,
+            chunk_radius,
+            definitions: vec![],
+            pending: None,
+            respawn: RespawnStatus::Alive,
+        }
+    }
+
+    pub fn dimension(&self) -> &Dimension {
+        &self.dimension
+    }
+
+    pub fn is_changing(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn is_dead(&self) -> bool {
+        matches!(self.respawn, RespawnStatus::Dead { .. })
+    }
+
+    pub fn set_chunk_radius(&mut self, chunk_radius: u32) {
+        self.chunk_radius = chunk_radius;
+    }
+
+    /// Returns the sub chunk count of the dimension, including custom height ranges.
+    pub fn sub_chunk_count(&self, dimension: &Dimension) -> u32 {
+        match self
+            .definitions
+            .iter()
+            .find(|definition| definition.name == dimension_name(dimension))
+        {
+            Some(definition) => definition.sub_chunk_count(),
+            None => vanilla_definition(dimension).sub_chunk_count(),
+        }
+    }
+
+    /// Changes the height range of a dimension, which only takes effect once the
+    /// dimension is entered again.
+    pub async fn set_height_range(
+        &mut self,
+        conn: &mut ConnectionShard,
+        dimension: &Dimension,
+        min_height: i32,
+        max_height: i32,
+    ) -> Result<(), DimensionError> {
+        let definition = DimensionDefinition::new(
+            dimension_name(dimension),
+            min_height,
+            max_height,
+            vanilla_definition(dimension).generator_type,
+        );
+
+        self.definitions
+            .retain(|other| other.name != definition.name);
+        self.definitions.push(definition);
+
+        conn.send(GamePacket::DimensionData(DimensionDataPacket {
+            definitions: self.definitions.clone(),
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Starts moving the player to another dimension.
+    pub async fn change_dimension(
+        &mut self,
+        conn: &mut ConnectionShard,
+        dimension: Dimension,
+        position: Vec3<f32>,
+    ) -> Result<(), DimensionError> {
+        if self.pending.is_some() {
+            return Err(DimensionError::ChangeInProgress);
+        }
+
+        // The client gets stuck in the loading screen when changing to the current dimension
+        if dimension_id(&dimension) == dimension_id(&self.dimension) {
+            return Err(DimensionError::AlreadyInDimension(dimension));
+        }
+
+        self.begin_change(conn, dimension, position, false).await
+    }
+
+    /// Handles a [`PlayerActionPacket`] of the client, finishing a pending dimension change.
+    pub async fn on_player_action(
+        &mut self,
+        conn: &mut ConnectionShard,
+        pk: &PlayerActionPacket,
+    ) -> Result<Option<DimensionEvent>, DimensionError> {
+        if pk.action != PlayerActionType::DimensionChangeDone {
+            return Ok(None);
+        }
+
+        let pending = match self.pending.take() {
+            Some(pending) => pending,
+            None => return Ok(None),
+        };
+
+        if pending.respawn {
+            self.send_respawn(conn, RespawnState::ReadyToSpawn, pending.position)
+                .await?;
+        }
+
+        Ok(Some(DimensionEvent::Changed {
+            dimension: pending.dimension,
+            position: pending.position,
+            respawned: pending.respawn,
+        }))
+    }
+
+    /// Shows the death screen, the player can respawn at the given position once the
+    /// client pressed the respawn button.
+    pub async fn on_death(
+        &mut self,
+        conn: &mut ConnectionShard,
+        dimension: Dimension,
+        position: Vec3<f32>,
+    ) -> Result<(), DimensionError> {
+        self.send_respawn(conn, RespawnState::SearchingForSpawn, position)
+            .await?;
+        self.send_respawn(conn, RespawnState::ReadyToSpawn, position)
+            .await?;
+
+        self.respawn = RespawnStatus::Dead {
+            dimension,
+            position,
+        };
+
+        Ok(())
+    }
+
+    /// Handles a [`RespawnPacket`] of the client, sent when the respawn button was pressed.
+    /// Respawning in another dimension first runs a dimension change.
+    pub async fn on_respawn(
+        &mut self,
+        conn: &mut ConnectionShard,
+        pk: &RespawnPacket,
+    ) -> Result<Option<DimensionEvent>, DimensionError> {
+        if pk.state != RespawnState::ClientReadyToSpawn || self.pending.is_some() {
+            return Ok(None);
+        }
+
+        let (dimension, position) = match std::mem::replace(&mut self.respawn, RespawnStatus::Alive)
+        {
+            RespawnStatus::Dead {
+                dimension,
+                position,
+            } => (dimension, position),
+            RespawnStatus::Alive => return Ok(None),
+        };
+
+        if dimension_id(&dimension) != dimension_id(&self.dimension) {
+            self.begin_change(conn, dimension, position, true).await?;
+            return Ok(None);
+        }
+
+        self.send_respawn(conn, RespawnState::ReadyToSpawn, position)
+            .await?;
+
+        Ok(Some(DimensionEvent::Respawned { position }))
+    }
+
+    async fn begin_change(
+        &mut self,
+        conn: &mut ConnectionShard,
+        dimension: Dimension,
+        position: Vec3<f32>,
+        respawn: bool,
+    ) -> Result<(), DimensionError> {
+        conn.send(GamePacket::ChangeDimension(ChangeDimensionPacket {
+            dimension: dimension.clone(),
+            position: position.to_le(),
+            respawn,
+        }))
+        .await?;
+
+        // The client discards chunks outside of its publisher area,
+        // so the area has to be moved before the chunks are sent
+        conn.send(GamePacket::NetworkChunkPublisherUpdate(
+            NetworkChunkPublisherUpdatePacket {
+                position: Vec3 {
+                    x: position.x as i32,
+                    y: position.y as i32,
+                    z: position.z as i32,
+                }
+                .to_var(),
+                radius: VAR::new(self.chunk_radius * 16),
+                saved_chunks: vec![],
+            },
+        ))
+        .await?;
+
+        // The client only leaves the loading screen once the chunks around it exist,
+        // so empty ones are sent until the real chunks are published after the change
+        let chunk_data = empty_chunk_data(&dimension, self.sub_chunk_count(&dimension))
+            .map_err(ConnectionError::from)?;
+        let chunk_x = (position.x.floor() as i32) >> 4;
+        let chunk_z = (position.z.floor() as i32) >> 4;
+
+        for x in -1..=1 {
+            for z in -1..=1 {
+                conn.send(GamePacket::LevelChunk(LevelChunkPacket {
+                    chunk_position: ChunkPos::new(chunk_x + x, chunk_z + z),
+                    dimension_id: VAR::new(dimension_id(&dimension)),
+                    sub_chunk_count: VAR::new(0),
+                    cache_enabled: false,
+                    serialized_chunk_data: chunk_data.clone(),
+                    client_needs_to_request_subchunks: false,
+                    client_request_subchunk_limit: VAR::new(-1),
+                }))
+                .await?;
+            }
+        }
+
+        conn.send(GamePacket::StopSound(StopSoundPacket::all()))
+            .await?;
+        conn.send(GamePacket::PlayStatus(PlayStatusPacket {
+            status: PlayStatusType::PlayerSpawn,
+        }))
+        .await?;
+        // Current clients wait for the server to acknowledge the change first,
+        // they only send their own acknowledgement afterwards
+        conn.send(GamePacket::PlayerAction(PlayerActionPacket {
+            runtime_id: self.runtime_id.clone(),
+            action: PlayerActionType::DimensionChangeDone,
+            block_position: NetworkBlockPos {
+                x: VAR::new(0),
+                y: VAR::new(0),
+                z: VAR::new(0),
+            },
+            result_position: NetworkBlockPos {
+                x: VAR::new(0),
+                y: VAR::new(0),
+                z: VAR::new(0),
+            },
+            face: VAR::new(0),
+        }))
+        .await?;
+
+        conn.flush().await?;
+
+        self.dimension = dimension.clone();
+        self.pending = Some(PendingChange {
+            dimension,
+            position,
+            respawn,
+        });
+
+        Ok(())
+    }
+
+    async fn send_respawn(
+        &mut self,
+        conn: &mut ConnectionShard,
+        state: RespawnState,
+        position: Vec3<f32>,
+    ) -> Result<(), DimensionError> {
+        conn.send(GamePacket::Respawn(RespawnPacket {
+            position: position.to_le(),
+            state,
+            runtime_id: self.runtime_id.clone(),
+        }))
+        .await?;
+
+        Ok(())
+    }
+}
+
+fn dimension_id(dimension: &Dimension) -> i32 {
+    match dimension {
+        Dimension::Overworld => 0,
+        Dimension::Nether => 1,
+        Dimension::End => 2,
+    }
+}
+
+fn dimension_name(dimension: &Dimension) -> &'static str {
+    match dimension {
+        Dimension::Overworld => "minecraft:overworld",
+        Dimension::Nether => "minecraft:nether",
+        Dimension::End => "minecraft:the_end",
+    }
+}
+
+fn vanilla_definition(dimension: &Dimension) -> DimensionDefinition {
+    match dimension {
+        Dimension::Overworld => DimensionDefinition::new(
+            dimension_name(dimension),
+            -64,
+            320,
+            GeneratorType::Overworld,
+        ),
+        Dimension::Nether => {
+            DimensionDefinition::new(dimension_name(dimension), 0, 128, GeneratorType::Nether)
+        }
+        Dimension::End => {
+            DimensionDefinition::new(dimension_name(dimension), 0, 256, GeneratorType::End)
+        }
+    }
+}
+
+/// Builds a chunk without sub chunks, made of a single biome palette per sub chunk
+/// and no border blocks.
+fn empty_chunk_data(
+    dimension: &Dimension,
+    sub_chunk_count: u32,
+) -> Result<Vec<u8>, ProtoCodecError> {
+    let biome: i32 = match dimension {
+        // plains
+        Dimension::Overworld => 1,
+        // hell
+        Dimension::Nether => 8,
+        // the_end
+        Dimension::End => 9,
+    };
+
+    let mut data = vec![];
+
+    for _ in 0..sub_chunk_count {
+        // Palette header with 0 bits per entry using runtime IDs
+        data.push(1);
+        VAR::new(biome).proto_serialize(&mut data)?;
+    }
+
+    // Border blocks
+    data.push(0);
+
+    Ok(data)
+}
@@ -1,9 +1,13 @@
 use io::Error as IOError;
 use std::error::Error;
 use std::io;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_shared::world::dimension::Dimension;
 use rak_rs::connection::queue::SendQueueError;
 use rak_rs::connection::RecvError;
 use rak_rs::error::server::ServerError;
@@ -31,6 +35,8 @@ pub enum ConnectionError {
     ProtoCodecError(#[from] ProtoCodecError),
     #[error("Connection Closed")]
     ConnectionClosed,
+    #[error("Send queue overflow, {0} packets are still queued")]
+    SendQueueOverflow(usize),
     #[error("Transport Error: {0}")]
     TransportError(TransportLayerError),
     #[error("Compression Error: {0}")]
@@ -43,6 +49,82 @@ pub enum ConnectionError {
     WrongCompressionMethod(u8),
 }
 
+#[derive(Error, Debug, Clone)]
+pub enum ContainerError {
+    #[error("Connection Error: {0}")]
+    ConnectionError(#[from] ConnectionError),
+    #[error("No container open with window ID {0}")]
+    UnknownWindow(u8),
+    #[error("Slot {slot} is outside of the container with {size} slots")]
+    InvalidSlot { slot: u8, size: u8 },
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum ScoreboardError {
+    #[error("Connection Error: {0}")]
+    ConnectionError(#[from] ConnectionError),
+    #[error("Unknown objective: {0}")]
+    UnknownObjective(String),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum HudError {
+    #[error("Connection Error: {0}")]
+    ConnectionError(#[from] ConnectionError),
+    #[error("No boss bar shown for actor {0}")]
+    UnknownBossBar(i64),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum DimensionError {
+    #[error("Connection Error: {0}")]
+    ConnectionError(#[from] ConnectionError),
+    #[error("A dimension change is already in progress")]
+    ChangeInProgress,
+    #[error("The player already is in dimension {0:?}")]
+    AlreadyInDimension(Dimension),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum SkinError {
+    #[error("Missing client data field: {0}")]
+    MissingField(String),
+    #[error("Invalid client data field {0}: {1}")]
+    InvalidField(String, String),
+    #[error("Base64 decoding Error: {0}")]
+    Base64DecodeError(#[from] base64::DecodeError),
+    #[error("Json Error: {0}")]
+    JsonError(#[from] Arc<serde_json::Error>),
+    #[error("Image of {width}x{height} pixels does not match {len} bytes of data")]
+    InvalidImageSize { width: u32, height: u32, len: usize },
+    #[error("Image Error: {0}")]
+    ImageError(#[from] Arc<image::ImageError>),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum CommandParseError {
+    #[error("Empty command")]
+    Empty,
+    #[error("Unknown command: {0}")]
+    UnknownCommand(String),
+    #[error("Invalid argument {value:?} for parameter {parameter}")]
+    InvalidArgument { parameter: String, value: String },
+    #[error("Missing argument for parameter {0}")]
+    MissingArgument(String),
+    #[error("Too many arguments: {0}")]
+    TooManyArguments(String),
+    #[error("Unterminated quote")]
+    UnterminatedQuote,
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum CommandRegistrationError {
+    #[error("Enum {0} is already registered with different values")]
+    EnumMismatch(String),
+    #[error("Soft enum {0} is already registered with different values")]
+    SoftEnumMismatch(String),
+}
+
 #[derive(Error, Debug, Clone)]
 pub enum CompressionError {
     #[error("Zlib Error: {0}")]
@@ -59,8 +141,15 @@ pub enum LoginError {
     ConnectionError(#[from] ConnectionError),
     #[error("Login aborted, reason: {reason}")]
     Abort { reason: String },
+    #[error("Login aborted, server is full")]
+    ServerFull,
+    #[error("Login provider hook timed out after {0:?}")]
+    ProviderTimeout(Duration),
     #[error("Wrong protocol version (client: {client}, server: {server:?})")]
-    WrongProtocolVersion { client: i32, server: Vec<i32> },
+    WrongProtocolVersion {
+        client: i32,
+        server: RangeInclusive<i32>,
+    },
     #[error("Format Error: {0}")]
     FormatError(String),
 }
@@ -84,3 +173,21 @@ pub enum RaknetError {
     #[error("Format Error: {0}")]
     FormatError(String),
 }
+
+#[derive(Error, Debug, Clone)]
+pub enum RegistryError {
+    #[error("IO Error at {1:?}: {0}")]
+    IOError(Arc<IOError>, PathBuf),
+    #[error("Json Error at {1:?}: {0}")]
+    JsonError(Arc<serde_json::Error>, PathBuf),
+    #[error("Format Error at {1:?}: {0}")]
+    FormatError(String, PathBuf),
+    #[error("Duplicate entry: {0}")]
+    DuplicateEntry(String),
+    #[error("Unknown entry: {0}")]
+    UnknownEntry(String),
+    #[error("Invalid entry: {0}")]
+    InvalidEntry(String),
+    #[error("No runtime IDs left")]
+    RuntimeIdsExhausted,
+}
@@ -3,32 +3,87 @@
 use std::io::{Cursor, Write};
 use std::sync::Arc;
 
+use crate::packets::add_entity::AddEntityPacket;
+use crate::packets::add_player::AddPlayerPacket;
 use crate::packets::animate::AnimatePacket;
+use crate::packets::available_commands::AvailableCommandsPacket;
+use crate::packets::boss_event::BossEventPacket;
+use crate::packets::change_dimension::ChangeDimensionPacket;
 use crate::packets::chunk_radius_updated::ChunkRadiusUpdatedPacket;
 use crate::packets::client_cache_status::ClientCacheStatusPacket;
+use crate::packets::command_output::CommandOutputPacket;
+use crate::packets::command_request::CommandRequestPacket;
+use crate::packets::container_close::ContainerClosePacket;
+use crate::packets::container_open::ContainerOpenPacket;
+use crate::packets::container_set_data::ContainerSetDataPacket;
+use crate::packets::crafting_data::CraftingDataPacket;
+use crate::packets::crafting_event::CraftingEventPacket;
+use crate::packets::dimension_data::DimensionDataPacket;
 use crate::packets::disconnect::DisconnectPacket;
 use crate::packets::emote_list::EmoteListPacket;
+use crate::packets::game_rules_changed::GameRulesChangedPacket;
 use crate::packets::handshake_server_to_client::HandshakeServerToClientPacket;
+use crate::packets::hurt_armor::HurtArmorPacket;
 use crate::packets::interact::InteractPacket;
+use crate::packets::inventory_content::InventoryContentPacket;
+use crate::packets::inventory_slot::InventorySlotPacket;
+use crate::packets::inventory_transaction::InventoryTransactionPacket;
+use crate::packets::item_component::ItemComponentPacket;
+use crate::packets::item_stack_request::ItemStackRequestPacket;
+use crate::packets::item_stack_response::ItemStackResponsePacket;
 use crate::packets::level_chunk::LevelChunkPacket;
+use crate::packets::level_event::LevelEventPacket;
+use crate::packets::level_event_generic::LevelEventGenericPacket;
+use crate::packets::level_sound_event::LevelSoundEventPacket;
+use crate::packets::level_sound_event_v2::LevelSoundEventV2Packet;
 use crate::packets::login::LoginPacket;
+use crate::packets::mob_effect::MobEffectPacket;
 use crate::packets::modal_form_request::ModalFormRequestPacket;
 use crate::packets::modal_form_response::ModalFormResponsePacket;
+use crate::packets::move_entity::MoveEntityPacket;
+use crate::packets::move_entity_delta::MoveEntityDeltaPacket;
+use crate::packets::network_chunk_publisher_update::NetworkChunkPublisherUpdatePacket;
 use crate::packets::network_settings::NetworkSettingsPacket;
 use crate::packets::network_settings_request::NetworkSettingsRequestPacket;
 use crate::packets::packet_violation_warning::PacketViolationWarningPacket;
+use crate::packets::play_sound::PlaySoundPacket;
 use crate::packets::play_status::PlayStatusPacket;
+use crate::packets::player_action::PlayerActionPacket;
 use crate::packets::player_auth_input::PlayerAuthInputPacket;
+use crate::packets::player_hotbar::PlayerHotbarPacket;
+use crate::packets::player_list::PlayerListPacket;
 use crate::packets::player_move::MovePlayerPacket;
+use crate::packets::player_skin::PlayerSkinPacket;
+use crate::packets::remove_entity::RemoveEntityPacket;
+use crate::packets::remove_objective::RemoveObjectivePacket;
 use crate::packets::request_chunk_radius::RequestChunkRadiusPacket;
 use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
 use crate::packets::resource_packs_response::ResourcePacksResponsePacket;
 use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
+use crate::packets::respawn::RespawnPacket;
 use crate::packets::server_settings_request::ServerSettingsRequestPacket;
 use crate::packets::server_settings_response::ServerSettingsResponsePacket;
+use crate::packets::set_default_game_type::SetDefaultGameTypePacket;
+use crate::packets::set_difficulty::SetDifficultyPacket;
+use crate::packets::set_display_objective::SetDisplayObjectivePacket;
+use crate::packets::set_entity_data::SetEntityDataPacket;
+use crate::packets::set_entity_link::SetEntityLinkPacket;
+use crate::packets::set_entity_motion::SetEntityMotionPacket;
+use crate::packets::set_health::SetHealthPacket;
 use crate::packets::set_local_player_as_initialized::SetLocalPlayerAsInitializedPacket;
+use crate::packets::set_player_game_type::SetPlayerGameTypePacket;
+use crate::packets::set_score::SetScorePacket;
+use crate::packets::set_scoreboard_identity::SetScoreboardIdentityPacket;
+use crate::packets::set_time::SetTimePacket;
+use crate::packets::set_title::SetTitlePacket;
+use crate::packets::spawn_particle_effect::SpawnParticleEffectPacket;
 use crate::packets::start_game::StartGamePacket;
+use crate::packets::stop_sound::StopSoundPacket;
 use crate::packets::text_message::TextMessagePacket;
+use crate::packets::toast_request::ToastRequestPacket;
+use crate::packets::update_attributes::UpdateAttributesPacket;
+use crate::packets::update_player_game_type::UpdatePlayerGameTypePacket;
+use crate::packets::update_soft_enum::UpdateSoftEnumPacket;
 use bedrockrs_core::int::VAR;
 use bedrockrs_proto_core::error::ProtoCodecError;
 use bedrockrs_proto_core::ProtoCodec;
@@ -45,58 +100,58 @@ pub enum GamePacket {
     ResourcePackStack(ResourcePacksStackPacket),
     ResourcePackClientResponse(ResourcePacksResponsePacket),
     TextMessage(TextMessagePacket),
-    SetTime(),
+    SetTime(SetTimePacket),
     StartGame(StartGamePacket),
-    AddPlayer(),
-    AddEntity(),
-    RemoveEntity(),
+    AddPlayer(AddPlayerPacket),
+    AddEntity(AddEntityPacket),
+    RemoveEntity(RemoveEntityPacket),
     AddItemEntity(),
     TakeItemEntity(),
-    MoveEntity(),
+    MoveEntity(MoveEntityPacket),
     MovePlayer(MovePlayerPacket),
     RiderJump(),
     UpdateBlock(),
     AddPainting(),
     TickSync(),
     LevelSoundEventOld(),
-    LevelEvent(),
+    LevelEvent(LevelEventPacket),
     BlockEvent(),
     EntityEvent(),
-    MobEffect(),
-    UpdateAttributes(),
-    InventoryTransaction(),
+    MobEffect(MobEffectPacket),
+    UpdateAttributes(UpdateAttributesPacket),
+    InventoryTransaction(InventoryTransactionPacket),
     MobEquipment(),
     MobArmorEquipment(),
     Interact(InteractPacket),
     BlockPickRequest(),
     EntityPickRequest(),
-    PlayerAction(),
-    HurtArmor(),
-    SetEntityData(),
-    SetEntityMotion(),
-    SetEntityLink(),
-    SetHealth(),
+    PlayerAction(PlayerActionPacket),
+    HurtArmor(HurtArmorPacket),
+    SetEntityData(SetEntityDataPacket),
+    SetEntityMotion(SetEntityMotionPacket),
+    SetEntityLink(SetEntityLinkPacket),
+    SetHealth(SetHealthPacket),
     SetSpawnPosition(),
     Animate(AnimatePacket),
-    Respawn(),
-    ContainerOpen(),
-    ContainerClose(),
-    PlayerHotbar(),
-    InventoryContent(),
-    InventorySlot(),
-    ContainerSetData(),
-    CraftingData(),
-    CraftingEvent(),
+    Respawn(RespawnPacket),
+    ContainerOpen(ContainerOpenPacket),
+    ContainerClose(ContainerClosePacket),
+    PlayerHotbar(PlayerHotbarPacket),
+    InventoryContent(InventoryContentPacket),
+    InventorySlot(InventorySlotPacket),
+    ContainerSetData(ContainerSetDataPacket),
+    CraftingData(CraftingDataPacket),
+    CraftingEvent(CraftingEventPacket),
     GuiDataPickItem(),
     AdventureSettings(),
     BlockEntityData(),
     PlayerInput(),
     LevelChunk(LevelChunkPacket),
     SetCommandsEnabled(),
-    SetDifficulty(),
-    ChangeDimension(),
-    SetPlayerGameType(),
-    PlayerList(),
+    SetDifficulty(SetDifficultyPacket),
+    ChangeDimension(ChangeDimensionPacket),
+    SetPlayerGameType(SetPlayerGameTypePacket),
+    PlayerList(PlayerListPacket),
     SimpleEvent(),
     TelemetryEvent(),
     SpawnExperienceOrb(),
@@ -105,28 +160,28 @@ pub enum GamePacket {
     RequestChunkRadius(RequestChunkRadiusPacket),
     ChunkRadiusUpdate(ChunkRadiusUpdatedPacket),
     ItemFrameDropItem(),
-    GameRulesChanged(),
+    GameRulesChanged(GameRulesChangedPacket),
     Camera(),
-    BossEvent(),
+    BossEvent(BossEventPacket),
     ShowCredits(),
-    AvailableCommands(),
-    CommandRequest(),
+    AvailableCommands(AvailableCommandsPacket),
+    CommandRequest(CommandRequestPacket),
     CommandBlockUpdate(),
-    CommandOutput(),
+    CommandOutput(CommandOutputPacket),
     UpdateTrade(),
     UpdateEquipment(),
     ResourcePackDataInfo(),
     ResourcePackChunkData(),
     ResourcePackChunkRequest(),
     Transfer(),
-    PlaySound(),
-    StopSound(),
-    SetTitle(),
+    PlaySound(PlaySoundPacket),
+    StopSound(StopSoundPacket),
+    SetTitle(SetTitlePacket),
     AddBehaviorTree(),
     StructureBlockUpdate(),
     ShowStoreOffer(),
     PurchaseReceipt(),
-    PlayerSkin(),
+    PlayerSkin(PlayerSkinPacket),
     SubClientLogin(),
     InitiateWebSocketConnection(),
     SetLastHurtBy(),
@@ -138,25 +193,25 @@ pub enum GamePacket {
     ServerSettingsRequest(ServerSettingsRequestPacket),
     ServerSettingsResponse(ServerSettingsResponsePacket),
     ShowProfile(),
-    SetDefaultGameType(),
-    RemoveObjective(),
-    SetDisplayObjective(),
-    SetScore(),
+    SetDefaultGameType(SetDefaultGameTypePacket),
+    RemoveObjective(RemoveObjectivePacket),
+    SetDisplayObjective(SetDisplayObjectivePacket),
+    SetScore(SetScorePacket),
     LabTable(),
     UpdateBlockSynced(),
-    MoveEntityDelta(),
-    SetScoreboardIdentity(),
+    MoveEntityDelta(MoveEntityDeltaPacket),
+    SetScoreboardIdentity(SetScoreboardIdentityPacket),
     SetLocalPlayerAsInitialized(SetLocalPlayerAsInitializedPacket),
-    UpdateSoftEnum(),
+    UpdateSoftEnum(UpdateSoftEnumPacket),
     NetworkStackLatency(),
     ScriptCustomEvent(),
-    SpawnParticleEffect(),
+    SpawnParticleEffect(SpawnParticleEffectPacket),
     AvailableEntityIdentifiers(),
-    LevelSoundEventV2(),
-    NetworkChunkPublisherUpdate(),
+    LevelSoundEventV2(LevelSoundEventV2Packet),
+    NetworkChunkPublisherUpdate(NetworkChunkPublisherUpdatePacket),
     BiomeDefinitionList(),
-    LevelSoundEvent(),
-    LevelEventGeneric(),
+    LevelSoundEvent(LevelSoundEventPacket),
+    LevelEventGeneric(LevelEventGenericPacket),
     LecternUpdate(),
     VideoStreamConnect(),
     ClientCacheStatus(ClientCacheStatusPacket),
@@ -171,17 +226,18 @@ pub enum GamePacket {
     PlayerAuthInput(PlayerAuthInputPacket),
     CreativeContent(),
     PlayerEnchantOptions(),
-    ItemStackRequest(),
-    ItemStackResponse(),
-    UpdatePlayerGameType(),
+    ItemStackRequest(ItemStackRequestPacket),
+    ItemStackResponse(ItemStackResponsePacket),
+    UpdatePlayerGameType(UpdatePlayerGameTypePacket),
     EmoteList(EmoteListPacket),
     PacketViolationWarning(PacketViolationWarningPacket),
-    ItemComponent(),
+    ItemComponent(ItemComponentPacket),
     FilterTextPacket(),
     UpdateSubChunkBlocksPacket(),
     SubChunkPacket(),
     SubChunkRequestPacket(),
-    DimensionData(),
+    DimensionData(DimensionDataPacket),
+    ToastRequest(ToastRequestPacket),
     RequestNetworkSettings(NetworkSettingsRequestPacket),
     AlexEntityAnimation(),
 }
@@ -333,6 +389,7 @@ impl GamePacket {
     const SubChunkPacketID: u16 = 174;
     const SubChunkRequestPacketID: u16 = 175;
     const DimensionDataID: u16 = 180;
+    const ToastRequestID: u16 = 186;
     const RequestNetworkSettingsID: u16 = 193;
     const AlexEntityAnimationID: u16 = 224;
 }
@@ -420,20 +477,20 @@ impl GamePacket {
             GamePacket::TextMessage(pk) => {
                 ser_packet!(stream, GamePacket::TextMessageID, pk)
             }
-            GamePacket::SetTime() => {
-                unimplemented!()
+            GamePacket::SetTime(pk) => {
+                ser_packet!(stream, GamePacket::SetTimeID, pk)
             }
             GamePacket::StartGame(pk) => {
                 ser_packet!(stream, GamePacket::StartGameID, pk)
             }
-            GamePacket::AddPlayer() => {
-                unimplemented!()
+            GamePacket::AddPlayer(pk) => {
+                ser_packet!(stream, GamePacket::AddPlayerID, pk)
             }
-            GamePacket::AddEntity() => {
-                unimplemented!()
+            GamePacket::AddEntity(pk) => {
+                ser_packet!(stream, GamePacket::AddEntityID, pk)
             }
-            GamePacket::RemoveEntity() => {
-                unimplemented!()
+            GamePacket::RemoveEntity(pk) => {
+                ser_packet!(stream, GamePacket::RemoveEntityID, pk)
             }
             GamePacket::AddItemEntity() => {
                 unimplemented!()
@@ -441,8 +498,8 @@ impl GamePacket {
             GamePacket::TakeItemEntity() => {
                 unimplemented!()
             }
-            GamePacket::MoveEntity() => {
-                unimplemented!()
+            GamePacket::MoveEntity(pk) => {
+                ser_packet!(stream, GamePacket::MoveEntityID, pk)
             }
             GamePacket::MovePlayer(pk) => {
                 ser_packet!(stream, GamePacket::MovePlayerID, pk)
@@ -462,8 +519,8 @@ impl GamePacket {
             GamePacket::LevelSoundEventOld() => {
                 unimplemented!()
             }
-            GamePacket::LevelEvent() => {
-                unimplemented!()
+            GamePacket::LevelEvent(pk) => {
+                ser_packet!(stream, GamePacket::LevelEventID, pk)
             }
             GamePacket::BlockEvent() => {
                 unimplemented!()
@@ -471,14 +528,14 @@ impl GamePacket {
             GamePacket::EntityEvent() => {
                 unimplemented!()
             }
-            GamePacket::MobEffect() => {
-                unimplemented!()
+            GamePacket::MobEffect(pk) => {
+                ser_packet!(stream, GamePacket::MobEffectID, pk)
             }
-            GamePacket::UpdateAttributes() => {
-                unimplemented!()
+            GamePacket::UpdateAttributes(pk) => {
+                ser_packet!(stream, GamePacket::UpdateAttributesID, pk)
             }
-            GamePacket::InventoryTransaction() => {
-                unimplemented!()
+            GamePacket::InventoryTransaction(pk) => {
+                ser_packet!(stream, GamePacket::InventoryTransactionID, pk)
             }
             GamePacket::MobEquipment() => {
                 unimplemented!()
@@ -495,23 +552,23 @@ impl GamePacket {
             GamePacket::EntityPickRequest() => {
                 unimplemented!()
             }
-            GamePacket::PlayerAction() => {
-                unimplemented!()
+            GamePacket::PlayerAction(pk) => {
+                ser_packet!(stream, GamePacket::PlayerActionID, pk)
             }
-            GamePacket::HurtArmor() => {
-                unimplemented!()
+            GamePacket::HurtArmor(pk) => {
+                ser_packet!(stream, GamePacket::HurtArmorID, pk)
             }
-            GamePacket::SetEntityData() => {
-                unimplemented!()
+            GamePacket::SetEntityData(pk) => {
+                ser_packet!(stream, GamePacket::SetEntityDataID, pk)
             }
-            GamePacket::SetEntityMotion() => {
-                unimplemented!()
+            GamePacket::SetEntityMotion(pk) => {
+                ser_packet!(stream, GamePacket::SetEntityMotionID, pk)
             }
-            GamePacket::SetEntityLink() => {
-                unimplemented!()
+            GamePacket::SetEntityLink(pk) => {
+                ser_packet!(stream, GamePacket::SetEntityLinkID, pk)
             }
-            GamePacket::SetHealth() => {
-                unimplemented!()
+            GamePacket::SetHealth(pk) => {
+                ser_packet!(stream, GamePacket::SetHealthID, pk)
             }
             GamePacket::SetSpawnPosition() => {
                 unimplemented!()
@@ -519,32 +576,32 @@ impl GamePacket {
             GamePacket::Animate(pk) => {
                 ser_packet!(stream, GamePacket::AnimateID, pk)
             }
-            GamePacket::Respawn() => {
-                unimplemented!()
+            GamePacket::Respawn(pk) => {
+                ser_packet!(stream, GamePacket::RespawnID, pk)
             }
-            GamePacket::ContainerOpen() => {
-                unimplemented!()
+            GamePacket::ContainerOpen(pk) => {
+                ser_packet!(stream, GamePacket::ContainerOpenID, pk)
             }
-            GamePacket::ContainerClose() => {
-                unimplemented!()
+            GamePacket::ContainerClose(pk) => {
+                ser_packet!(stream, GamePacket::ContainerCloseID, pk)
             }
-            GamePacket::PlayerHotbar() => {
-                unimplemented!()
+            GamePacket::PlayerHotbar(pk) => {
+                ser_packet!(stream, GamePacket::PlayerHotbarID, pk)
             }
-            GamePacket::InventoryContent() => {
-                unimplemented!()
+            GamePacket::InventoryContent(pk) => {
+                ser_packet!(stream, GamePacket::InventoryContentID, pk)
             }
-            GamePacket::InventorySlot() => {
-                unimplemented!()
+            GamePacket::InventorySlot(pk) => {
+                ser_packet!(stream, GamePacket::InventorySlotID, pk)
             }
-            GamePacket::ContainerSetData() => {
-                unimplemented!()
+            GamePacket::ContainerSetData(pk) => {
+                ser_packet!(stream, GamePacket::ContainerSetDataID, pk)
             }
-            GamePacket::CraftingData() => {
-                unimplemented!()
+            GamePacket::CraftingData(pk) => {
+                ser_packet!(stream, GamePacket::CraftingDataID, pk)
             }
-            GamePacket::CraftingEvent() => {
-                unimplemented!()
+            GamePacket::CraftingEvent(pk) => {
+                ser_packet!(stream, GamePacket::CraftingEventID, pk)
             }
             GamePacket::GuiDataPickItem() => {
                 unimplemented!()
@@ -564,17 +621,17 @@ impl GamePacket {
             GamePacket::SetCommandsEnabled() => {
                 unimplemented!()
             }
-            GamePacket::SetDifficulty() => {
-                unimplemented!()
+            GamePacket::SetDifficulty(pk) => {
+                ser_packet!(stream, GamePacket::SetDifficultyID, pk)
             }
-            GamePacket::ChangeDimension() => {
-                unimplemented!()
+            GamePacket::ChangeDimension(pk) => {
+                ser_packet!(stream, GamePacket::ChangeDimensionID, pk)
             }
-            GamePacket::SetPlayerGameType() => {
-                unimplemented!()
+            GamePacket::SetPlayerGameType(pk) => {
+                ser_packet!(stream, GamePacket::SetPlayerGameTypeID, pk)
             }
-            GamePacket::PlayerList() => {
-                unimplemented!()
+            GamePacket::PlayerList(pk) => {
+                ser_packet!(stream, GamePacket::PlayerListID, pk)
             }
             GamePacket::SimpleEvent() => {
                 unimplemented!()
@@ -600,29 +657,29 @@ impl GamePacket {
             GamePacket::ItemFrameDropItem() => {
                 unimplemented!()
             }
-            GamePacket::GameRulesChanged() => {
-                unimplemented!()
+            GamePacket::GameRulesChanged(pk) => {
+                ser_packet!(stream, GamePacket::GameRulesChangedID, pk)
             }
             GamePacket::Camera() => {
                 unimplemented!()
             }
-            GamePacket::BossEvent() => {
-                unimplemented!()
+            GamePacket::BossEvent(pk) => {
+                ser_packet!(stream, GamePacket::BossEventID, pk)
             }
             GamePacket::ShowCredits() => {
                 unimplemented!()
             }
-            GamePacket::AvailableCommands() => {
-                unimplemented!()
+            GamePacket::AvailableCommands(pk) => {
+                ser_packet!(stream, GamePacket::AvailableCommandsID, pk)
             }
-            GamePacket::CommandRequest() => {
-                unimplemented!()
+            GamePacket::CommandRequest(pk) => {
+                ser_packet!(stream, GamePacket::CommandRequestID, pk)
             }
             GamePacket::CommandBlockUpdate() => {
                 unimplemented!()
             }
-            GamePacket::CommandOutput() => {
-                unimplemented!()
+            GamePacket::CommandOutput(pk) => {
+                ser_packet!(stream, GamePacket::CommandOutputID, pk)
             }
             GamePacket::UpdateTrade() => {
                 unimplemented!()
@@ -642,14 +699,14 @@ impl GamePacket {
             GamePacket::Transfer() => {
                 unimplemented!()
             }
-            GamePacket::PlaySound() => {
-                unimplemented!()
+            GamePacket::PlaySound(pk) => {
+                ser_packet!(stream, GamePacket::PlaySoundID, pk)
             }
-            GamePacket::StopSound() => {
-                unimplemented!()
+            GamePacket::StopSound(pk) => {
+                ser_packet!(stream, GamePacket::StopSoundID, pk)
             }
-            GamePacket::SetTitle() => {
-                unimplemented!()
+            GamePacket::SetTitle(pk) => {
+                ser_packet!(stream, GamePacket::SetTitleID, pk)
             }
             GamePacket::AddBehaviorTree() => {
                 unimplemented!()
@@ -663,8 +720,8 @@ impl GamePacket {
             GamePacket::PurchaseReceipt() => {
                 unimplemented!()
             }
-            GamePacket::PlayerSkin() => {
-                unimplemented!()
+            GamePacket::PlayerSkin(pk) => {
+                ser_packet!(stream, GamePacket::PlayerSkinID, pk)
             }
             GamePacket::SubClientLogin() => {
                 unimplemented!()
@@ -699,17 +756,17 @@ impl GamePacket {
             GamePacket::ShowProfile() => {
                 unimplemented!()
             }
-            GamePacket::SetDefaultGameType() => {
-                unimplemented!()
+            GamePacket::SetDefaultGameType(pk) => {
+                ser_packet!(stream, GamePacket::SetDefaultGameTypeID, pk)
             }
-            GamePacket::RemoveObjective() => {
-                unimplemented!()
+            GamePacket::RemoveObjective(pk) => {
+                ser_packet!(stream, GamePacket::RemoveObjectiveID, pk)
             }
-            GamePacket::SetDisplayObjective() => {
-                unimplemented!()
+            GamePacket::SetDisplayObjective(pk) => {
+                ser_packet!(stream, GamePacket::SetDisplayObjectiveID, pk)
             }
-            GamePacket::SetScore() => {
-                unimplemented!()
+            GamePacket::SetScore(pk) => {
+                ser_packet!(stream, GamePacket::SetScoreID, pk)
             }
             GamePacket::LabTable() => {
                 unimplemented!()
@@ -717,17 +774,17 @@ impl GamePacket {
             GamePacket::UpdateBlockSynced() => {
                 unimplemented!()
             }
-            GamePacket::MoveEntityDelta() => {
-                unimplemented!()
+            GamePacket::MoveEntityDelta(pk) => {
+                ser_packet!(stream, GamePacket::MoveEntityDeltaID, pk)
             }
-            GamePacket::SetScoreboardIdentity() => {
-                unimplemented!()
+            GamePacket::SetScoreboardIdentity(pk) => {
+                ser_packet!(stream, GamePacket::SetScoreboardIdentityID, pk)
             }
             GamePacket::SetLocalPlayerAsInitialized(pk) => {
                 ser_packet!(stream, GamePacket::SetLocalPlayerAsInitializedID, pk)
             }
-            GamePacket::UpdateSoftEnum() => {
-                unimplemented!()
+            GamePacket::UpdateSoftEnum(pk) => {
+                ser_packet!(stream, GamePacket::UpdateSoftEnumID, pk)
             }
             GamePacket::NetworkStackLatency() => {
                 unimplemented!()
@@ -735,26 +792,26 @@ impl GamePacket {
             GamePacket::ScriptCustomEvent() => {
                 unimplemented!()
             }
-            GamePacket::SpawnParticleEffect() => {
-                unimplemented!()
+            GamePacket::SpawnParticleEffect(pk) => {
+                ser_packet!(stream, GamePacket::SpawnParticleEffectID, pk)
             }
             GamePacket::AvailableEntityIdentifiers() => {
                 unimplemented!()
             }
-            GamePacket::LevelSoundEventV2() => {
-                unimplemented!()
+            GamePacket::LevelSoundEventV2(pk) => {
+                ser_packet!(stream, GamePacket::LevelSoundEventV2ID, pk)
             }
-            GamePacket::NetworkChunkPublisherUpdate() => {
-                unimplemented!()
+            GamePacket::NetworkChunkPublisherUpdate(pk) => {
+                ser_packet!(stream, GamePacket::NetworkChunkPublisherUpdateID, pk)
             }
             GamePacket::BiomeDefinitionList() => {
                 unimplemented!()
             }
-            GamePacket::LevelSoundEvent() => {
-                unimplemented!()
+            GamePacket::LevelSoundEvent(pk) => {
+                ser_packet!(stream, GamePacket::LevelSoundEventID, pk)
             }
-            GamePacket::LevelEventGeneric() => {
-                unimplemented!()
+            GamePacket::LevelEventGeneric(pk) => {
+                ser_packet!(stream, GamePacket::LevelEventGenericID, pk)
             }
             GamePacket::LecternUpdate() => {
                 unimplemented!()
@@ -798,14 +855,14 @@ impl GamePacket {
             GamePacket::PlayerEnchantOptions() => {
                 unimplemented!()
             }
-            GamePacket::ItemStackRequest() => {
-                unimplemented!()
+            GamePacket::ItemStackRequest(pk) => {
+                ser_packet!(stream, GamePacket::ItemStackRequestID, pk)
             }
-            GamePacket::ItemStackResponse() => {
-                unimplemented!()
+            GamePacket::ItemStackResponse(pk) => {
+                ser_packet!(stream, GamePacket::ItemStackResponseID, pk)
             }
-            GamePacket::UpdatePlayerGameType() => {
-                unimplemented!()
+            GamePacket::UpdatePlayerGameType(pk) => {
+                ser_packet!(stream, GamePacket::UpdatePlayerGameTypeID, pk)
             }
             GamePacket::EmoteList(pk) => {
                 ser_packet!(stream, GamePacket::EmoteListID, pk)
@@ -813,8 +870,8 @@ impl GamePacket {
             GamePacket::PacketViolationWarning(pk) => {
                 ser_packet!(stream, GamePacket::PacketViolationWarningID, pk)
             }
-            GamePacket::ItemComponent() => {
-                unimplemented!()
+            GamePacket::ItemComponent(pk) => {
+                ser_packet!(stream, GamePacket::ItemComponentID, pk)
             }
             GamePacket::FilterTextPacket() => {
                 unimplemented!()
@@ -828,8 +885,11 @@ impl GamePacket {
             GamePacket::SubChunkRequestPacket() => {
                 unimplemented!()
             }
-            GamePacket::DimensionData() => {
-                unimplemented!()
+            GamePacket::DimensionData(pk) => {
+                ser_packet!(stream, GamePacket::DimensionDataID, pk)
+            }
+            GamePacket::ToastRequest(pk) => {
+                ser_packet!(stream, GamePacket::ToastRequestID, pk)
             }
             GamePacket::RequestNetworkSettings(pk) => {
                 ser_packet!(stream, GamePacket::RequestNetworkSettingsID, pk)
@@ -893,18 +953,12 @@ impl GamePacket {
             GamePacket::TextMessageID => {
                 GamePacket::TextMessage(de_packet!(stream, TextMessagePacket))
             }
-            GamePacket::SetTimeID => {
-                unimplemented!()
-            }
+            GamePacket::SetTimeID => GamePacket::SetTime(de_packet!(stream, SetTimePacket)),
             GamePacket::StartGameID => GamePacket::StartGame(de_packet!(stream, StartGamePacket)),
-            GamePacket::AddPlayerID => {
-                unimplemented!()
-            }
-            GamePacket::AddEntityID => {
-                unimplemented!()
-            }
+            GamePacket::AddPlayerID => GamePacket::AddPlayer(de_packet!(stream, AddPlayerPacket)),
+            GamePacket::AddEntityID => GamePacket::AddEntity(de_packet!(stream, AddEntityPacket)),
             GamePacket::RemoveEntityID => {
-                unimplemented!()
+                GamePacket::RemoveEntity(de_packet!(stream, RemoveEntityPacket))
             }
             GamePacket::AddItemEntityID => {
                 unimplemented!()
@@ -913,7 +967,7 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::MoveEntityID => {
-                unimplemented!()
+                GamePacket::MoveEntity(de_packet!(stream, MoveEntityPacket))
             }
             GamePacket::MovePlayerID => {
                 GamePacket::MovePlayer(de_packet!(stream, MovePlayerPacket))
@@ -934,7 +988,7 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::LevelEventID => {
-                unimplemented!()
+                GamePacket::LevelEvent(de_packet!(stream, LevelEventPacket))
             }
             GamePacket::BlockEventID => {
                 unimplemented!()
@@ -942,14 +996,12 @@ impl GamePacket {
             GamePacket::EntityEventID => {
                 unimplemented!()
             }
-            GamePacket::MobEffectID => {
-                unimplemented!()
-            }
+            GamePacket::MobEffectID => GamePacket::MobEffect(de_packet!(stream, MobEffectPacket)),
             GamePacket::UpdateAttributesID => {
-                unimplemented!()
+                GamePacket::UpdateAttributes(de_packet!(stream, UpdateAttributesPacket))
             }
             GamePacket::InventoryTransactionID => {
-                unimplemented!()
+                GamePacket::InventoryTransaction(de_packet!(stream, InventoryTransactionPacket))
             }
             GamePacket::MobEquipmentID => {
                 unimplemented!()
@@ -965,53 +1017,47 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::PlayerActionID => {
-                unimplemented!()
-            }
-            GamePacket::HurtArmorID => {
-                unimplemented!()
+                GamePacket::PlayerAction(de_packet!(stream, PlayerActionPacket))
             }
+            GamePacket::HurtArmorID => GamePacket::HurtArmor(de_packet!(stream, HurtArmorPacket)),
             GamePacket::SetEntityDataID => {
-                unimplemented!()
+                GamePacket::SetEntityData(de_packet!(stream, SetEntityDataPacket))
             }
             GamePacket::SetEntityMotionID => {
-                unimplemented!()
+                GamePacket::SetEntityMotion(de_packet!(stream, SetEntityMotionPacket))
             }
             GamePacket::SetEntityLinkID => {
-                unimplemented!()
-            }
-            GamePacket::SetHealthID => {
-                unimplemented!()
+                GamePacket::SetEntityLink(de_packet!(stream, SetEntityLinkPacket))
             }
+            GamePacket::SetHealthID => GamePacket::SetHealth(de_packet!(stream, SetHealthPacket)),
             GamePacket::SetSpawnPositionID => {
                 unimplemented!()
             }
             GamePacket::AnimateID => GamePacket::Animate(de_packet!(stream, AnimatePacket)),
-            GamePacket::RespawnID => {
-                unimplemented!()
-            }
+            GamePacket::RespawnID => GamePacket::Respawn(de_packet!(stream, RespawnPacket)),
             GamePacket::ContainerOpenID => {
-                unimplemented!()
+                GamePacket::ContainerOpen(de_packet!(stream, ContainerOpenPacket))
             }
             GamePacket::ContainerCloseID => {
-                unimplemented!()
+                GamePacket::ContainerClose(de_packet!(stream, ContainerClosePacket))
             }
             GamePacket::PlayerHotbarID => {
-                unimplemented!()
+                GamePacket::PlayerHotbar(de_packet!(stream, PlayerHotbarPacket))
             }
             GamePacket::InventoryContentID => {
-                unimplemented!()
+                GamePacket::InventoryContent(de_packet!(stream, InventoryContentPacket))
             }
             GamePacket::InventorySlotID => {
-                unimplemented!()
+                GamePacket::InventorySlot(de_packet!(stream, InventorySlotPacket))
             }
             GamePacket::ContainerSetDataID => {
-                unimplemented!()
+                GamePacket::ContainerSetData(de_packet!(stream, ContainerSetDataPacket))
             }
             GamePacket::CraftingDataID => {
-                unimplemented!()
+                GamePacket::CraftingData(de_packet!(stream, CraftingDataPacket))
             }
             GamePacket::CraftingEventID => {
-                unimplemented!()
+                GamePacket::CraftingEvent(de_packet!(stream, CraftingEventPacket))
             }
             GamePacket::GuiDataPickItemID => {
                 unimplemented!()
@@ -1032,16 +1078,16 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::SetDifficultyID => {
-                unimplemented!()
+                GamePacket::SetDifficulty(de_packet!(stream, SetDifficultyPacket))
             }
             GamePacket::ChangeDimensionID => {
-                unimplemented!()
+                GamePacket::ChangeDimension(de_packet!(stream, ChangeDimensionPacket))
             }
             GamePacket::SetPlayerGameTypeID => {
-                unimplemented!()
+                GamePacket::SetPlayerGameType(de_packet!(stream, SetPlayerGameTypePacket))
             }
             GamePacket::PlayerListID => {
-                unimplemented!()
+                GamePacket::PlayerList(de_packet!(stream, PlayerListPacket))
             }
             GamePacket::SimpleEventID => {
                 unimplemented!()
@@ -1068,28 +1114,26 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::GameRulesChangedID => {
-                unimplemented!()
+                GamePacket::GameRulesChanged(de_packet!(stream, GameRulesChangedPacket))
             }
             GamePacket::CameraID => {
                 unimplemented!()
             }
-            GamePacket::BossEventID => {
-                unimplemented!()
-            }
+            GamePacket::BossEventID => GamePacket::BossEvent(de_packet!(stream, BossEventPacket)),
             GamePacket::ShowCreditsID => {
                 unimplemented!()
             }
             GamePacket::AvailableCommandsID => {
-                unimplemented!()
+                GamePacket::AvailableCommands(de_packet!(stream, AvailableCommandsPacket))
             }
             GamePacket::CommandRequestID => {
-                unimplemented!()
+                GamePacket::CommandRequest(de_packet!(stream, CommandRequestPacket))
             }
             GamePacket::CommandBlockUpdateID => {
                 unimplemented!()
             }
             GamePacket::CommandOutputID => {
-                unimplemented!()
+                GamePacket::CommandOutput(de_packet!(stream, CommandOutputPacket))
             }
             GamePacket::UpdateTradeID => {
                 unimplemented!()
@@ -1109,15 +1153,9 @@ impl GamePacket {
             GamePacket::TransferID => {
                 unimplemented!()
             }
-            GamePacket::PlaySoundID => {
-                unimplemented!()
-            }
-            GamePacket::StopSoundID => {
-                unimplemented!()
-            }
-            GamePacket::SetTitleID => {
-                unimplemented!()
-            }
+            GamePacket::PlaySoundID => GamePacket::PlaySound(de_packet!(stream, PlaySoundPacket)),
+            GamePacket::StopSoundID => GamePacket::StopSound(de_packet!(stream, StopSoundPacket)),
+            GamePacket::SetTitleID => GamePacket::SetTitle(de_packet!(stream, SetTitlePacket)),
             GamePacket::AddBehaviorTreeID => {
                 unimplemented!()
             }
@@ -1131,7 +1169,7 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::PlayerSkinID => {
-                unimplemented!()
+                GamePacket::PlayerSkin(de_packet!(stream, PlayerSkinPacket))
             }
             GamePacket::SubClientLoginID => {
                 unimplemented!()
@@ -1167,17 +1205,15 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::SetDefaultGameTypeID => {
-                unimplemented!()
+                GamePacket::SetDefaultGameType(de_packet!(stream, SetDefaultGameTypePacket))
             }
             GamePacket::RemoveObjectiveID => {
-                unimplemented!()
+                GamePacket::RemoveObjective(de_packet!(stream, RemoveObjectivePacket))
             }
             GamePacket::SetDisplayObjectiveID => {
-                unimplemented!()
-            }
-            GamePacket::SetScoreID => {
-                unimplemented!()
+                GamePacket::SetDisplayObjective(de_packet!(stream, SetDisplayObjectivePacket))
             }
+            GamePacket::SetScoreID => GamePacket::SetScore(de_packet!(stream, SetScorePacket)),
             GamePacket::LabTableID => {
                 unimplemented!()
             }
@@ -1185,16 +1221,16 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::MoveEntityDeltaID => {
-                unimplemented!()
+                GamePacket::MoveEntityDelta(de_packet!(stream, MoveEntityDeltaPacket))
             }
             GamePacket::SetScoreboardIdentityID => {
-                unimplemented!()
+                GamePacket::SetScoreboardIdentity(de_packet!(stream, SetScoreboardIdentityPacket))
             }
             GamePacket::SetLocalPlayerAsInitializedID => GamePacket::SetLocalPlayerAsInitialized(
                 de_packet!(stream, SetLocalPlayerAsInitializedPacket),
             ),
             GamePacket::UpdateSoftEnumID => {
-                unimplemented!()
+                GamePacket::UpdateSoftEnum(de_packet!(stream, UpdateSoftEnumPacket))
             }
             GamePacket::NetworkStackLatencyID => {
                 unimplemented!()
@@ -1203,25 +1239,25 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::SpawnParticleEffectID => {
-                unimplemented!()
+                GamePacket::SpawnParticleEffect(de_packet!(stream, SpawnParticleEffectPacket))
             }
             GamePacket::AvailableEntityIdentifiersID => {
                 unimplemented!()
             }
             GamePacket::LevelSoundEventV2ID => {
-                unimplemented!()
-            }
-            GamePacket::NetworkChunkPublisherUpdateID => {
-                unimplemented!()
+                GamePacket::LevelSoundEventV2(de_packet!(stream, LevelSoundEventV2Packet))
             }
+            GamePacket::NetworkChunkPublisherUpdateID => GamePacket::NetworkChunkPublisherUpdate(
+                de_packet!(stream, NetworkChunkPublisherUpdatePacket),
+            ),
             GamePacket::BiomeDefinitionListID => {
                 unimplemented!()
             }
             GamePacket::LevelSoundEventID => {
-                unimplemented!()
+                GamePacket::LevelSoundEvent(de_packet!(stream, LevelSoundEventPacket))
             }
             GamePacket::LevelEventGenericID => {
-                unimplemented!()
+                GamePacket::LevelEventGeneric(de_packet!(stream, LevelEventGenericPacket))
             }
             GamePacket::LecternUpdateID => {
                 unimplemented!()
@@ -1266,20 +1302,20 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::ItemStackRequestID => {
-                unimplemented!()
+                GamePacket::ItemStackRequest(de_packet!(stream, ItemStackRequestPacket))
             }
             GamePacket::ItemStackResponseID => {
-                unimplemented!()
+                GamePacket::ItemStackResponse(de_packet!(stream, ItemStackResponsePacket))
             }
             GamePacket::UpdatePlayerGameTypeID => {
-                unimplemented!()
+                GamePacket::UpdatePlayerGameType(de_packet!(stream, UpdatePlayerGameTypePacket))
             }
             GamePacket::EmoteListID => GamePacket::EmoteList(de_packet!(stream, EmoteListPacket)),
             GamePacket::PacketViolationWarningID => {
                 GamePacket::PacketViolationWarning(de_packet!(stream, PacketViolationWarningPacket))
             }
             GamePacket::ItemComponentID => {
-                unimplemented!()
+                GamePacket::ItemComponent(de_packet!(stream, ItemComponentPacket))
             }
             GamePacket::FilterTextPacketID => {
                 unimplemented!()
@@ -1294,7 +1330,10 @@ impl GamePacket {
                 unimplemented!()
             }
             GamePacket::DimensionDataID => {
-                unimplemented!()
+                GamePacket::DimensionData(de_packet!(stream, DimensionDataPacket))
+            }
+            GamePacket::ToastRequestID => {
+                GamePacket::ToastRequest(de_packet!(stream, ToastRequestPacket))
             }
             GamePacket::RequestNetworkSettingsID => {
                 GamePacket::RequestNetworkSettings(de_packet!(stream, NetworkSettingsRequestPacket))
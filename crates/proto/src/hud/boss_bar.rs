@@ -0,0 +1,80 @@
+use crate::types::boss_event::{BossBarColor, BossBarOverlay, BossEventType};
+
+/// The state of a boss bar shown to a player.
+#[derive(Debug, Clone)]
+pub struct BossBar {
+    pub title: String,
+    /// From `0.0` to `1.0`.
+    pub health_percent: f32,
+    pub color: BossBarColor,
+    pub overlay: BossBarOverlay,
+    pub darken_screen: bool,
+}
+
+impl BossBar {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            health_percent: 1.0,
+            color: BossBarColor::Purple,
+            overlay: BossBarOverlay::Progress,
+            darken_screen: false,
+        }
+    }
+
+    pub fn health_percent(mut self, health_percent: f32) -> Self {
+        self.health_percent = health_percent.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn color(mut self, color: BossBarColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn overlay(mut self, overlay: BossBarOverlay) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    pub fn darken_screen(mut self, darken_screen: bool) -> Self {
+        self.darken_screen = darken_screen;
+        self
+    }
+
+    pub(crate) fn add_event(&self) -> BossEventType {
+        BossEventType::Add {
+            title: self.title.clone(),
+            health_percent: self.health_percent,
+            darken_screen: self.darken_screen as i16,
+            color: self.color,
+            overlay: self.overlay,
+        }
+    }
+
+    /// Returns the events changing `self` into `other`.
+    pub(crate) fn diff(&self, other: &BossBar) -> Vec<BossEventType> {
+        let mut events = vec![];
+
+        if self.title != other.title {
+            events.push(BossEventType::UpdateName(other.title.clone()));
+        }
+        if self.health_percent != other.health_percent {
+            events.push(BossEventType::UpdatePercent(other.health_percent));
+        }
+        if self.darken_screen != other.darken_screen {
+            events.push(BossEventType::UpdateProperties {
+                darken_screen: other.darken_screen as i16,
+                color: other.color,
+                overlay: other.overlay,
+            });
+        } else if self.color != other.color || self.overlay != other.overlay {
+            events.push(BossEventType::UpdateStyle {
+                color: other.color,
+                overlay: other.overlay,
+            });
+        }
+
+        events
+    }
+}
@@ -0,0 +1,2 @@
+pub mod boss_bar;
+pub mod player_hud;
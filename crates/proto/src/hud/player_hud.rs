@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+use bedrockrs_shared::text::rawtext::RawText;
+
+use crate::connection::ConnectionShard;
+use crate::error::HudError;
+use crate::gamepacket::GamePacket;
+use crate::hud::boss_bar::BossBar;
+use crate::packets::boss_event::BossEventPacket;
+use crate::packets::set_title::SetTitlePacket;
+use crate::packets::toast_request::ToastRequestPacket;
+use crate::types::boss_event::BossEventType;
+use crate::types::title_type::TitleType;
+
+/// The fade times of a title in ticks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TitleTimes {
+    pub fade_in: i32,
+    pub stay: i32,
+    pub fade_out: i32,
+}
+
+impl Default for TitleTimes {
+    fn default() -> Self {
+        Self {
+            fade_in: 10,
+            stay: 70,
+            fade_out: 20,
+        }
+    }
+}
+
+/// Manages the boss bars, titles and toasts of a single player.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerHud {
+    boss_bars: HashMap<i64, BossBar>,
+    title_times: Option<TitleTimes>,
+}
+
+impl PlayerHud {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn boss_bar(&self, boss_actor_id: i64) -> Option<&BossBar> {
+        self.boss_bars.get(&boss_actor_id)
+    }
+
+    pub fn boss_bars(&self) -> impl Iterator<Item = (i64, &BossBar)> {
+        self.boss_bars.iter().map(|(id, bar)| (*id, bar))
+    }
+
+    /// Shows a boss bar, or updates it if one is already shown for the actor.
+    /// The actor has to exist on the client, for a single custom boss bar
+    /// the unique ID of the player itself can be used.
+    pub async fn show_boss_bar(
+        &mut self,
+        conn: &mut ConnectionShard,
+        boss_actor_id: i64,
+        boss_bar: BossBar,
+    ) -> Result<(), HudError> {
+        let events = match self.boss_bars.get(&boss_actor_id) {
+            Some(shown) => shown.diff(&boss_bar),
+            None => vec![boss_bar.add_event()],
+        };
+
+        for event in events {
+            Self::send_boss_event(conn, boss_actor_id, event).await?;
+        }
+
+        self.boss_bars.insert(boss_actor_id, boss_bar);
+
+        Ok(())
+    }
+
+    /// Changes a shown boss bar, only the changes are sent.
+    pub async fn update_boss_bar(
+        &mut self,
+        conn: &mut ConnectionShard,
+        boss_actor_id: i64,
+        update: impl FnOnce(&mut BossBar),
+    ) -> Result<(), HudError> {
+        let mut boss_bar = self
+            .boss_bars
+            .get(&boss_actor_id)
+            .ok_or(HudError::UnknownBossBar(boss_actor_id))?
+            .clone();
+
+        update(&mut boss_bar);
+
+        self.show_boss_bar(conn, boss_actor_id, boss_bar).await
+    }
+
+    pub async fn set_boss_bar_percent(
+        &mut self,
+        conn: &mut ConnectionShard,
+        boss_actor_id: i64,
+        health_percent: f32,
+    ) -> Result<(), HudError> {
+        self.update_boss_bar(conn, boss_actor_id, |v| {
+            v.health_percent = health_percent.clamp(0.0, 1.0)
+        })
+        .await
+    }
+
+    pub async fn remove_boss_bar(
+        &mut self,
+        conn: &mut ConnectionShard,
+        boss_actor_id: i64,
+    ) -> Result<(), HudError> {
+        if self.boss_bars.remove(&boss_actor_id).is_none() {
+            return Err(HudError::UnknownBossBar(boss_actor_id));
+        }
+
+        Self::send_boss_event(conn, boss_actor_id, BossEventType::Remove).await
+    }
+
+    pub async fn clear_boss_bars(&mut self, conn: &mut ConnectionShard) -> Result<(), HudError> {
+        for (boss_actor_id, _) in self.boss_bars.drain() {
+            Self::send_boss_event(conn, boss_actor_id, BossEventType::Remove).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Resends all boss bars, e.g. after the client requested them or changed dimension.
+    pub async fn resend_boss_bars(&self, conn: &mut ConnectionShard) -> Result<(), HudError> {
+        for (boss_actor_id, boss_bar) in &self.boss_bars {
+            Self::send_boss_event(conn, *boss_actor_id, boss_bar.add_event()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_boss_event(
+        conn: &mut ConnectionShard,
+        boss_actor_id: i64,
+        event: BossEventType,
+    ) -> Result<(), HudError> {
+        conn.send(GamePacket::BossEvent(BossEventPacket {
+            boss_actor_id: ActorUniqueID(boss_actor_id),
+            event,
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sets the fade times, they are only sent if they changed.
+    pub async fn set_title_times(
+        &mut self,
+        conn: &mut ConnectionShard,
+        times: TitleTimes,
+    ) -> Result<(), HudError> {
+        if self.title_times == Some(times) {
+            return Ok(());
+        }
+
+        conn.send(GamePacket::SetTitle(SetTitlePacket::times(
+            times.fade_in,
+            times.stay,
+            times.fade_out,
+        )))
+        .await?;
+
+        self.title_times = Some(times);
+
+        Ok(())
+    }
+
+    /// Shows a title, the subtitle has to be sent before the title to be shown with it.
+    pub async fn title(
+        &mut self,
+        conn: &mut ConnectionShard,
+        title: impl Into<String>,
+        subtitle: Option<String>,
+        times: Option<TitleTimes>,
+    ) -> Result<(), HudError> {
+        if let Some(times) = times {
+            self.set_title_times(conn, times).await?;
+        }
+        if let Some(subtitle) = subtitle {
+            conn.send(GamePacket::SetTitle(SetTitlePacket::new(
+                TitleType::Subtitle,
+                subtitle,
+            )))
+            .await?;
+        }
+
+        conn.send(GamePacket::SetTitle(SetTitlePacket::new(
+            TitleType::Title,
+            title,
+        )))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Shows a title made of raw text components.
+    pub async fn title_raw_text(
+        &mut self,
+        conn: &mut ConnectionShard,
+        title_type: TitleType,
+        text: &RawText,
+    ) -> Result<(), HudError> {
+        conn.send(GamePacket::SetTitle(SetTitlePacket::raw_text(
+            title_type, text,
+        )))
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn action_bar(
+        &mut self,
+        conn: &mut ConnectionShard,
+        text: impl Into<String>,
+    ) -> Result<(), HudError> {
+        conn.send(GamePacket::SetTitle(SetTitlePacket::new(
+            TitleType::ActionBar,
+            text,
+        )))
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn clear_title(&mut self, conn: &mut ConnectionShard) -> Result<(), HudError> {
+        conn.send(GamePacket::SetTitle(SetTitlePacket::new(
+            TitleType::Clear,
+            "",
+        )))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Clears the title and resets the subtitle and fade times.
+    pub async fn reset_title(&mut self, conn: &mut ConnectionShard) -> Result<(), HudError> {
+        conn.send(GamePacket::SetTitle(SetTitlePacket::new(
+            TitleType::Reset,
+            "",
+        )))
+        .await?;
+
+        self.title_times = None;
+
+        Ok(())
+    }
+
+    pub async fn toast(
+        &mut self,
+        conn: &mut ConnectionShard,
+        title: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Result<(), HudError> {
+        conn.send(GamePacket::ToastRequest(ToastRequestPacket {
+            title: title.into(),
+            content: content.into(),
+        }))
+        .await?;
+
+        Ok(())
+    }
+}
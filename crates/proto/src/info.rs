@@ -1,5 +1,17 @@
+use std::ops::RangeInclusive;
+
 pub const RAKNET_GAME_PACKET_ID: u8 = 0xfe;
-pub const PROTOCOL_VERSION: i32 = 685;
+
+pub const PROTOCOL_VERSION_1_20_70: i32 = 662;
+pub const PROTOCOL_VERSION_1_20_80: i32 = 671;
+pub const PROTOCOL_VERSION_1_21_0: i32 = 685;
+
+/// The newest protocol version, used when no other version has been negotiated.
+pub const PROTOCOL_VERSION: i32 = PROTOCOL_VERSION_1_21_0;
+/// All protocol versions that can be negotiated by default,
+/// so clients that are one or two updates behind can still join.
+pub const SUPPORTED_PROTOCOL_VERSIONS: RangeInclusive<i32> =
+    PROTOCOL_VERSION_1_20_70..=PROTOCOL_VERSION;
 pub const MINECRAFT_VERSION: &'static str = "1.21.0";
 pub const MINECRAFT_EDITION_MOTD: &'static str = "MCPE";
 
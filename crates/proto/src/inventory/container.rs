@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::connection::ConnectionShard;
+use crate::error::ContainerError;
+use crate::gamepacket::GamePacket;
+use crate::inventory::model::InventoryModel;
+use crate::packets::container_close::ContainerClosePacket;
+use crate::packets::container_open::ContainerOpenPacket;
+use crate::packets::container_set_data::ContainerSetDataPacket;
+use crate::packets::inventory_content::InventoryContentPacket;
+use crate::packets::inventory_slot::InventorySlotPacket;
+use crate::packets::player_hotbar::PlayerHotbarPacket;
+use crate::types::container_name::ContainerName;
+use crate::types::container_type::ContainerType;
+use crate::types::item_stack::ItemStack;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+pub const WINDOW_ID_INVENTORY: u8 = 0;
+pub const WINDOW_ID_FIRST: u8 = 1;
+pub const WINDOW_ID_LAST: u8 = 100;
+pub const WINDOW_ID_OFFHAND: u8 = 119;
+pub const WINDOW_ID_ARMOR: u8 = 120;
+pub const WINDOW_ID_UI: u8 = 124;
+
+/// Describes a container that can be opened for a player.
+#[derive(Debug, Clone)]
+pub struct ContainerDefinition {
+    pub container_type: ContainerType,
+    /// The name the client uses for the slots of the container in item stack requests.
+    pub container_name: ContainerName,
+    pub size: u8,
+    /// The position of the container block, for fake inventories a matching block
+    /// has to be sent to the client at this position first.
+    pub position: NetworkBlockPos,
+    /// The entity holding the container, -1 for block containers.
+    pub entity_unique_id: ActorUniqueID,
+}
+
+impl ContainerDefinition {
+    pub fn chest(position: NetworkBlockPos) -> Self {
+        Self::block(ContainerType::Container, 27, position)
+    }
+
+    /// Needs two chest blocks next to each other, paired via their block entity data.
+    pub fn double_chest(position: NetworkBlockPos) -> Self {
+        Self::block(ContainerType::Container, 54, position)
+    }
+
+    pub fn hopper(position: NetworkBlockPos) -> Self {
+        Self::block(ContainerType::Hopper, 5, position)
+    }
+
+    pub fn dispenser(position: NetworkBlockPos) -> Self {
+        Self::block(ContainerType::Dispenser, 9, position)
+    }
+
+    pub fn dropper(position: NetworkBlockPos) -> Self {
+        Self::block(ContainerType::Dropper, 9, position)
+    }
+
+    fn block(container_type: ContainerType, size: u8, position: NetworkBlockPos) -> Self {
+        Self {
+            container_type,
+            container_name: ContainerName::LevelEntity,
+            size,
+            position,
+            entity_unique_id: ActorUniqueID(-1),
+        }
+    }
+}
+
+/// Tracks the containers a player has open, allocates their window IDs
+/// and sends the packets to open, update and close them.
+///
+/// The slots are stored in an [`InventoryModel`], so item stack requests
+/// of the player can be applied via [`ContainerManager::model_mut`].
+#[derive(Debug, Clone)]
+pub struct ContainerManager {
+    model: InventoryModel,
+    windows: HashMap<u8, ContainerDefinition>,
+    next_window_id: u8,
+}
+
+impl Default for ContainerManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContainerManager {
+    pub fn new() -> Self {
+        Self {
+            model: InventoryModel::new(),
+            windows: HashMap::new(),
+            next_window_id: WINDOW_ID_FIRST,
+        }
+    }
+
+    #[inline]
+    pub fn model(&self) -> &InventoryModel {
+        &self.model
+    }
+
+    #[inline]
+    pub fn model_mut(&mut self) -> &mut InventoryModel {
+        &mut self.model
+    }
+
+    pub fn window(&self, window_id: u8) -> Option<&ContainerDefinition> {
+        self.windows.get(&window_id)
+    }
+
+    /// Opens a container filled with the given items and returns its window ID.
+    /// The client can only show a single container, an already open one gets closed first.
+    pub async fn open(
+        &mut self,
+        conn: &mut ConnectionShard,
+        definition: ContainerDefinition,
+        items: Vec<ItemStack>,
+    ) -> Result<u8, ContainerError> {
+        let open: Vec<u8> = self.windows.keys().copied().collect();
+        for window_id in open {
+            self.close(conn, window_id).await?;
+        }
+
+        let window_id = self.allocate_window_id();
+
+        self.model
+            .add_container(definition.container_name, definition.size);
+        for (slot, item) in items.into_iter().take(definition.size as usize).enumerate() {
+            self.model
+                .set_item(definition.container_name, slot as u8, item);
+        }
+
+        conn.send(GamePacket::ContainerOpen(ContainerOpenPacket {
+            window_id,
+            container_type: definition.container_type,
+            position: definition.position.clone(),
+            entity_unique_id: definition.entity_unique_id.clone(),
+        }))
+        .await?;
+
+        self.windows.insert(window_id, definition);
+
+        self.sync(conn, window_id).await?;
+
+        Ok(window_id)
+    }
+
+    /// Closes a container from the server side and returns the items left in it.
+    pub async fn close(
+        &mut self,
+        conn: &mut ConnectionShard,
+        window_id: u8,
+    ) -> Result<Vec<ItemStack>, ContainerError> {
+        let definition = self
+            .windows
+            .remove(&window_id)
+            .ok_or(ContainerError::UnknownWindow(window_id))?;
+
+        conn.send(GamePacket::ContainerClose(ContainerClosePacket {
+            window_id,
+            container_type: definition.container_type,
+            server_side: true,
+        }))
+        .await?;
+
+        Ok(self.model.remove_container(definition.container_name))
+    }
+
+    /// Handles a [`ContainerClosePacket`] of the client, the client waits for the
+    /// server to confirm the close before it can open another container.
+    /// Returns the items left in the container.
+    pub async fn on_close(
+        &mut self,
+        conn: &mut ConnectionShard,
+        pk: &ContainerClosePacket,
+    ) -> Result<Vec<ItemStack>, ContainerError> {
+        let definition = self.windows.remove(&pk.window_id);
+
+        conn.send(GamePacket::ContainerClose(ContainerClosePacket {
+            window_id: pk.window_id,
+            container_type: pk.container_type,
+            server_side: false,
+        }))
+        .await?;
+
+        Ok(match definition {
+            Some(definition) => self.model.remove_container(definition.container_name),
+            None => vec![],
+        })
+    }
+
+    /// Sets a single slot of an open container.
+    pub async fn set_slot(
+        &mut self,
+        conn: &mut ConnectionShard,
+        window_id: u8,
+        slot: u8,
+        item: ItemStack,
+    ) -> Result<(), ContainerError> {
+        let definition = self
+            .windows
+            .get(&window_id)
+            .ok_or(ContainerError::UnknownWindow(window_id))?;
+
+        if slot >= definition.size {
+            return Err(ContainerError::InvalidSlot {
+                slot,
+                size: definition.size,
+            });
+        }
+
+        self.model.set_item(definition.container_name, slot, item);
+
+        conn.send(GamePacket::InventorySlot(InventorySlotPacket {
+            window_id: VAR::new(window_id as u32),
+            slot: VAR::new(slot as u32),
+            item: self.model.item(definition.container_name, slot),
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sends all slots of an open container, e.g. after a rejected item stack request.
+    pub async fn sync(
+        &mut self,
+        conn: &mut ConnectionShard,
+        window_id: u8,
+    ) -> Result<(), ContainerError> {
+        let definition = self
+            .windows
+            .get(&window_id)
+            .ok_or(ContainerError::UnknownWindow(window_id))?;
+
+        conn.send(GamePacket::InventoryContent(InventoryContentPacket {
+            window_id: VAR::new(window_id as u32),
+            content: (0..definition.size)
+                .map(|slot| self.model.item(definition.container_name, slot))
+                .collect(),
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Updates a property of an open container, e.g. the progress of a furnace.
+    pub async fn set_data(
+        &mut self,
+        conn: &mut ConnectionShard,
+        window_id: u8,
+        key: i32,
+        value: i32,
+    ) -> Result<(), ContainerError> {
+        if !self.windows.contains_key(&window_id) {
+            return Err(ContainerError::UnknownWindow(window_id));
+        }
+
+        conn.send(GamePacket::ContainerSetData(ContainerSetDataPacket {
+            window_id,
+            key: VAR::new(key),
+            value: VAR::new(value),
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Selects a hotbar slot of the player.
+    pub async fn select_hotbar_slot(
+        &mut self,
+        conn: &mut ConnectionShard,
+        slot: u8,
+    ) -> Result<(), ContainerError> {
+        conn.send(GamePacket::PlayerHotbar(PlayerHotbarPacket {
+            selected_slot: VAR::new(slot as u32),
+            window_id: WINDOW_ID_INVENTORY,
+            select_slot: true,
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    fn allocate_window_id(&mut self) -> u8 {
+        let window_id = self.next_window_id;
+
+        self.next_window_id = match self.next_window_id {
+            WINDOW_ID_LAST => WINDOW_ID_FIRST,
+            id => id + 1,
+        };
+
+        window_id
+    }
+}
@@ -0,0 +1,3 @@
+pub mod container;
+pub mod model;
+pub mod response;
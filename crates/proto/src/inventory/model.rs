@@ -0,0 +1,651 @@
+use std::collections::HashMap;
+
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::inventory::response::ItemStackResponseBuilder;
+use crate::registry::recipes::ANY_METADATA;
+use crate::types::container_name::ContainerName;
+use crate::types::item_descriptor::{ItemDescriptor, ItemDescriptorCount};
+use crate::types::item_stack::{ItemStack, ItemStackDescriptor};
+use crate::types::item_stack_request::{
+    ItemStackRequest, ItemStackRequestAction, ItemStackRequestSlotInfo,
+};
+use crate::types::item_stack_response::{ItemStackResponse, ItemStackResponseStatus};
+
+/// The slot of the [`ContainerName::CreatedOutput`] container crafting results are placed in.
+pub const CREATED_OUTPUT_SLOT: u8 = 50;
+
+/// The result of [`InventoryModel::apply`].
+#[derive(Debug, Clone)]
+pub struct ItemStackRequestOutcome {
+    /// The response that needs to be sent to the client.
+    pub response: ItemStackResponse,
+    /// Items the client dropped, these should be spawned as item entities.
+    pub dropped: Vec<ItemStack>,
+}
+
+/// The server side state of the containers of a player, which validates and applies
+/// item stack requests.
+///
+/// The hotbar, the inventory and the combined hotbar and inventory share their slots
+/// (hotbar `0..9`, inventory `9..36`), like they do on the client.
+#[derive(Debug, Clone)]
+pub struct InventoryModel {
+    sizes: HashMap<ContainerName, u8>,
+    slots: HashMap<(ContainerName, u8), ItemStack>,
+    creative_items: HashMap<u32, ItemStackDescriptor>,
+    recipes: HashMap<u32, ModelRecipe>,
+    item_tags: HashMap<String, Vec<i32>>,
+    next_stack_network_id: i32,
+    max_stack_size: fn(&ItemStack) -> u16,
+}
+
+impl Default for InventoryModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InventoryModel {
+    /// Creates a model with the containers every player has.
+    pub fn new() -> Self {
+        let mut model = Self {
+            sizes: HashMap::new(),
+            slots: HashMap::new(),
+            creative_items: HashMap::new(),
+            recipes: HashMap::new(),
+            item_tags: HashMap::new(),
+            next_stack_network_id: 1,
+            max_stack_size: |_| 64,
+        };
+
+        model.add_container(ContainerName::CombinedHotbarAndInventory, 36);
+        model.add_container(ContainerName::Armor, 4);
+        model.add_container(ContainerName::Offhand, 1);
+        model.add_container(ContainerName::Cursor, 1);
+        // The crafting grid uses the slots 28 to 36
+        model.add_container(ContainerName::CraftingInput, 37);
+        model.add_container(ContainerName::CreatedOutput, CREATED_OUTPUT_SLOT + 1);
+
+        model
+    }
+
+    /// Adds a container (e.g. of an opened chest), slots outside of
+    /// the size are rejected.
+    pub fn add_container(&mut self, container: ContainerName, size: u8) {
+        self.sizes.insert(storage(container), size);
+    }
+
+    /// Removes a container and returns the items that were left in it.
+    pub fn remove_container(&mut self, container: ContainerName) -> Vec<ItemStack> {
+        let container = storage(container);
+
+        self.sizes.remove(&container);
+
+        let keys: Vec<_> = self
+            .slots
+            .keys()
+            .filter(|(c, _)| *c == container)
+            .copied()
+            .collect();
+
+        keys.into_iter()
+            .filter_map(|key| self.slots.remove(&key))
+            .filter(|item| !item.is_air())
+            .collect()
+    }
+
+    /// Sets the function that returns the max stack size of an item, defaults to 64 for all items.
+    pub fn set_max_stack_size(&mut self, max_stack_size: fn(&ItemStack) -> u16) {
+        self.max_stack_size = max_stack_size;
+    }
+
+    /// Registers an item of the creative inventory by its creative item network ID.
+    pub fn add_creative_item(&mut self, network_id: u32, item: ItemStackDescriptor) {
+        self.creative_items.insert(network_id, item);
+    }
+
+    /// Registers a recipe by its recipe network ID.
+    ///
+    /// The consume actions of a request crafting the recipe must take exactly the
+    /// ingredients times the crafted amount. Item tag ingredients are resolved via
+    /// [`InventoryModel::add_item_tag`], other descriptors than [`ItemDescriptor::Default`]
+    /// and [`ItemDescriptor::ItemTag`] are never matched.
+    pub fn add_recipe(
+        &mut self,
+        network_id: u32,
+        ingredients: Vec<ItemDescriptorCount>,
+        outputs: Vec<ItemStackDescriptor>,
+    ) {
+        self.recipes.insert(
+            network_id,
+            ModelRecipe {
+                ingredients,
+                outputs,
+            },
+        );
+    }
+
+    /// Registers the runtime IDs of the items with an item tag, e.g. `minecraft:planks`.
+    pub fn add_item_tag(&mut self, tag: impl Into<String>, network_ids: Vec<i32>) {
+        self.item_tags.insert(tag.into(), network_ids);
+    }
+
+    pub fn item(&self, container: ContainerName, slot: u8) -> ItemStack {
+        self.slots
+            .get(&(storage(container), slot))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Sets a slot, the item gets a stack network ID if it doesn't have one yet.
+    pub fn set_item(&mut self, container: ContainerName, slot: u8, mut item: ItemStack) {
+        if item.is_air() {
+            self.slots.remove(&(storage(container), slot));
+            return;
+        }
+
+        if item.stack_network_id.is_none() {
+            item.stack_network_id = Some(self.allocate_stack_network_id());
+        }
+
+        self.slots.insert((storage(container), slot), item);
+    }
+
+    /// Validates and applies all actions of a request. If any action is invalid
+    /// none of them are applied and an error response is returned.
+    pub fn apply(&mut self, request: &ItemStackRequest) -> ItemStackRequestOutcome {
+        let request_id = request.request_id.into_inner();
+
+        let snapshot = self.slots.clone();
+        let next_stack_network_id = self.next_stack_network_id;
+
+        let mut changes = RequestChanges::default();
+
+        let result = request
+            .actions
+            .iter()
+            .try_for_each(|action| self.apply_action(action, &mut changes))
+            .and_then(|_| changes.validate_ingredients(&self.item_tags));
+
+        if let Err(status) = result {
+            self.slots = snapshot;
+            self.next_stack_network_id = next_stack_network_id;
+
+            return ItemStackRequestOutcome {
+                response: ItemStackResponseBuilder::error(request_id, status),
+                dropped: vec![],
+            };
+        }
+
+        let mut builder = ItemStackResponseBuilder::new(request_id);
+        for (container, slot) in changes.touched {
+            builder = builder.slot(container, slot, &self.item(container, slot));
+        }
+
+        ItemStackRequestOutcome {
+            response: builder.build(),
+            dropped: changes.dropped,
+        }
+    }
+
+    fn apply_action(
+        &mut self,
+        action: &ItemStackRequestAction,
+        changes: &mut RequestChanges,
+    ) -> Result<(), ItemStackResponseStatus> {
+        match action {
+            ItemStackRequestAction::Take {
+                count,
+                source,
+                destination,
+            }
+            | ItemStackRequestAction::Place {
+                count,
+                source,
+                destination,
+            }
+            | ItemStackRequestAction::PlaceInContainer {
+                count,
+                source,
+                destination,
+            }
+            | ItemStackRequestAction::TakeOutContainer {
+                count,
+                source,
+                destination,
+            } => self.transfer(*count, source, destination, changes),
+            ItemStackRequestAction::Swap {
+                source,
+                destination,
+            } => {
+                let source_item =
+                    self.validate(source, ItemStackResponseStatus::FailedToValidateSrcSlot)?;
+                let destination_item = self.validate(
+                    destination,
+                    ItemStackResponseStatus::FailedToValidateDstSlot,
+                )?;
+
+                if storage(destination.container) == ContainerName::CreatedOutput {
+                    return Err(ItemStackResponseStatus::CannotSwapItem);
+                }
+
+                self.slots
+                    .insert((storage(source.container), source.slot), destination_item);
+                self.slots.insert(
+                    (storage(destination.container), destination.slot),
+                    source_item,
+                );
+
+                changes.touch(source);
+                changes.touch(destination);
+
+                Ok(())
+            }
+            ItemStackRequestAction::Drop { count, source, .. } => {
+                let dropped =
+                    self.remove(*count, source, ItemStackResponseStatus::CannotDropItem)?;
+                changes.touch(source);
+                changes.dropped.push(dropped);
+
+                Ok(())
+            }
+            ItemStackRequestAction::Destroy { count, source } => {
+                self.remove(*count, source, ItemStackResponseStatus::CannotDestroyItem)?;
+                changes.touch(source);
+
+                Ok(())
+            }
+            ItemStackRequestAction::Consume { count, source } => {
+                let consumed =
+                    self.remove(*count, source, ItemStackResponseStatus::CannotConsumeItem)?;
+                changes.touch(source);
+                changes.consumed.push(consumed);
+
+                Ok(())
+            }
+            ItemStackRequestAction::Create { results_slot } => {
+                let result = changes
+                    .results
+                    .get(*results_slot as usize)
+                    .cloned()
+                    .ok_or(ItemStackResponseStatus::InvalidCraftResultIndex)?;
+
+                self.set_created_output(result);
+
+                Ok(())
+            }
+            ItemStackRequestAction::CraftCreative {
+                creative_item_network_id,
+            } => {
+                let mut item: ItemStack = self
+                    .creative_items
+                    .get(creative_item_network_id)
+                    .cloned()
+                    .ok_or(ItemStackResponseStatus::FailedToCraftCreative)?
+                    .into();
+                item.count = (self.max_stack_size)(&item);
+
+                changes.results = vec![item.clone()];
+                self.set_created_output(item);
+
+                Ok(())
+            }
+            ItemStackRequestAction::CraftRecipe { recipe_network_id } => {
+                self.craft_recipe(*recipe_network_id, 1, changes)
+            }
+            ItemStackRequestAction::CraftRecipeAuto {
+                recipe_network_id,
+                times_crafted,
+                ..
+            } => self.craft_recipe(*recipe_network_id, *times_crafted, changes),
+            // Only informs about the results the client expects
+            ItemStackRequestAction::CraftResultsDeprecated { .. } => Ok(()),
+            // Only predicts the durability of the tool after mining a block
+            ItemStackRequestAction::MineBlock { .. } => Ok(()),
+            ItemStackRequestAction::CraftRecipeOptional { .. }
+            | ItemStackRequestAction::CraftGrindstone { .. }
+            | ItemStackRequestAction::CraftLoom { .. }
+            | ItemStackRequestAction::CraftNonImplemented => {
+                Err(ItemStackResponseStatus::InvalidRequestCraftActionType)
+            }
+            ItemStackRequestAction::LabTableCombine
+            | ItemStackRequestAction::BeaconPayment { .. } => {
+                Err(ItemStackResponseStatus::ActionRequestNotAllowed)
+            }
+        }
+    }
+
+    fn transfer(
+        &mut self,
+        count: u8,
+        source: &ItemStackRequestSlotInfo,
+        destination: &ItemStackRequestSlotInfo,
+        changes: &mut RequestChanges,
+    ) -> Result<(), ItemStackResponseStatus> {
+        let count = count as u16;
+
+        if storage(source.container) == storage(destination.container)
+            && source.slot == destination.slot
+        {
+            return Err(ItemStackResponseStatus::DstContainerAndSlotEqualToSrcContainerAndSlot);
+        }
+        if storage(destination.container) == ContainerName::CreatedOutput {
+            return Err(ItemStackResponseStatus::DstContainerEqualToCreatedOutputContainer);
+        }
+
+        let mut source_item =
+            self.validate(source, ItemStackResponseStatus::FailedToValidateSrcSlot)?;
+        let mut destination_item = self.validate(
+            destination,
+            ItemStackResponseStatus::FailedToValidateDstSlot,
+        )?;
+
+        if source_item.is_air() || count == 0 || count > source_item.count {
+            return Err(ItemStackResponseStatus::InvalidTransferAmount);
+        }
+
+        if destination_item.is_air() {
+            destination_item = source_item.clone();
+            destination_item.count = count;
+
+            // A split off part of a stack is a new stack
+            if count != source_item.count {
+                destination_item.stack_network_id = Some(self.allocate_stack_network_id());
+            }
+        } else if stackable(&source_item, &destination_item) {
+            if destination_item.count + count > (self.max_stack_size)(&destination_item) {
+                return Err(ItemStackResponseStatus::InvalidTransferAmount);
+            }
+
+            destination_item.count += count;
+        } else {
+            return Err(ItemStackResponseStatus::CannotPlaceItem);
+        }
+
+        source_item.count -= count;
+        if source_item.count == 0 {
+            source_item = ItemStack::air();
+        }
+
+        self.slots
+            .insert((storage(source.container), source.slot), source_item);
+        self.slots.insert(
+            (storage(destination.container), destination.slot),
+            destination_item,
+        );
+
+        changes.touch(source);
+        changes.touch(destination);
+
+        Ok(())
+    }
+
+    /// Removes items from a slot and returns them.
+    fn remove(
+        &mut self,
+        count: u8,
+        source: &ItemStackRequestSlotInfo,
+        status: ItemStackResponseStatus,
+    ) -> Result<ItemStack, ItemStackResponseStatus> {
+        let count = count as u16;
+
+        let mut item = self.validate(source, ItemStackResponseStatus::FailedToValidateSrcSlot)?;
+
+        if item.is_air() || count == 0 || count > item.count {
+            return Err(status);
+        }
+
+        let mut removed = item.clone();
+        removed.count = count;
+
+        item.count -= count;
+        if item.count == 0 {
+            item = ItemStack::air();
+        } else {
+            removed.stack_network_id = Some(self.allocate_stack_network_id());
+        }
+
+        self.slots
+            .insert((storage(source.container), source.slot), item);
+
+        Ok(removed)
+    }
+
+    fn craft_recipe(
+        &mut self,
+        recipe_network_id: u32,
+        times_crafted: u8,
+        changes: &mut RequestChanges,
+    ) -> Result<(), ItemStackResponseStatus> {
+        if times_crafted == 0 {
+            return Err(ItemStackResponseStatus::InvalidNumCrafts);
+        }
+
+        let recipe = self
+            .recipes
+            .get(&recipe_network_id)
+            .ok_or(ItemStackResponseStatus::FailedToFindRecipeByNetId)?;
+
+        // The created output is split by the following actions, so only a single
+        // craft has to fit into a stack
+        changes.results = recipe
+            .outputs
+            .iter()
+            .cloned()
+            .map(|output| {
+                let mut item: ItemStack = output.into();
+                if item.count > (self.max_stack_size)(&item) {
+                    return Err(ItemStackResponseStatus::InvalidCraftResultStackSize);
+                }
+
+                item.count = item
+                    .count
+                    .checked_mul(times_crafted as u16)
+                    .ok_or(ItemStackResponseStatus::InvalidCraftResultStackSize)?;
+                Ok(item)
+            })
+            .collect::<Result<_, _>>()?;
+
+        for ingredient in &recipe.ingredients {
+            changes.ingredients.push(ingredient.clone());
+            changes
+                .ingredient_counts
+                .push(ingredient.count.into_inner().max(0) as u64 * times_crafted as u64);
+        }
+
+        let result = changes
+            .results
+            .first()
+            .cloned()
+            .ok_or(ItemStackResponseStatus::EmptyCraftResults)?;
+
+        self.set_created_output(result);
+
+        Ok(())
+    }
+
+    /// Returns the item in the referenced slot, if the slot exists and
+    /// contains the item the client expects.
+    fn validate(
+        &self,
+        info: &ItemStackRequestSlotInfo,
+        status: ItemStackResponseStatus,
+    ) -> Result<ItemStack, ItemStackResponseStatus> {
+        let size = self
+            .sizes
+            .get(&storage(info.container))
+            .ok_or(ItemStackResponseStatus::InvalidSourceContainer)?;
+
+        if info.slot >= *size {
+            return Err(status);
+        }
+
+        let item = self.item(info.container, info.slot);
+
+        // Negative IDs refer to stacks created by an earlier action of the same request
+        let expected = info.stack_network_id.into_inner();
+        if expected > 0 && item.stack_network_id != Some(expected) {
+            return Err(ItemStackResponseStatus::InvalidItemNetId);
+        }
+
+        Ok(item)
+    }
+
+    fn set_created_output(&mut self, mut item: ItemStack) {
+        item.stack_network_id = Some(self.allocate_stack_network_id());
+
+        self.slots
+            .insert((ContainerName::CreatedOutput, CREATED_OUTPUT_SLOT), item);
+    }
+
+    fn allocate_stack_network_id(&mut self) -> i32 {
+        let id = self.next_stack_network_id;
+        self.next_stack_network_id = self.next_stack_network_id.wrapping_add(1).max(1);
+        id
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ModelRecipe {
+    ingredients: Vec<ItemDescriptorCount>,
+    outputs: Vec<ItemStackDescriptor>,
+}
+
+#[derive(Default)]
+struct RequestChanges {
+    touched: Vec<(ContainerName, u8)>,
+    dropped: Vec<ItemStack>,
+    results: Vec<ItemStack>,
+    /// The items taken by consume actions.
+    consumed: Vec<ItemStack>,
+    /// The ingredients of all crafted recipes, with their counts
+    /// multiplied by the crafted amount in `ingredient_counts`.
+    ingredients: Vec<ItemDescriptorCount>,
+    ingredient_counts: Vec<u64>,
+}
+
+impl RequestChanges {
+    fn touch(&mut self, info: &ItemStackRequestSlotInfo) {
+        let key = (info.container, info.slot);
+
+        if !self.touched.contains(&key) {
+            self.touched.push(key);
+        }
+    }
+
+    /// Checks that the consumed items are exactly the ingredients of the crafted recipes.
+    fn validate_ingredients(
+        &self,
+        item_tags: &HashMap<String, Vec<i32>>,
+    ) -> Result<(), ItemStackResponseStatus> {
+        if self.ingredients.is_empty() {
+            return Ok(());
+        }
+
+        let mut remaining: Vec<(&ItemStack, u64)> = self
+            .consumed
+            .iter()
+            .map(|item| (item, item.count as u64))
+            .collect();
+
+        // Specific ingredients take their items first, so they aren't used up
+        // by ingredients that accept more items
+        let mut order: Vec<usize> = (0..self.ingredients.len()).collect();
+        order.sort_by_key(|&index| specificity(&self.ingredients[index].descriptor));
+
+        for index in order {
+            let descriptor = &self.ingredients[index].descriptor;
+            let mut needed = self.ingredient_counts[index];
+
+            for (item, count) in remaining.iter_mut() {
+                if needed == 0 {
+                    break;
+                }
+                if *count == 0 || !matches_ingredient(descriptor, item, item_tags) {
+                    continue;
+                }
+
+                let taken = needed.min(*count);
+                *count -= taken;
+                needed -= taken;
+            }
+
+            if needed != 0 {
+                return Err(ItemStackResponseStatus::CannotConsumeItem);
+            }
+        }
+
+        if remaining.iter().any(|(_, count)| *count != 0) {
+            return Err(ItemStackResponseStatus::CannotConsumeItem);
+        }
+
+        Ok(())
+    }
+}
+
+/// Orders ingredients from the most to the least specific.
+fn specificity(descriptor: &ItemDescriptor) -> u8 {
+    match descriptor {
+        ItemDescriptor::Default { metadata, .. } if *metadata != ANY_METADATA => 0,
+        ItemDescriptor::Default { .. } => 1,
+        _ => 2,
+    }
+}
+
+/// If an item can be used for an ingredient.
+fn matches_ingredient(
+    descriptor: &ItemDescriptor,
+    item: &ItemStack,
+    item_tags: &HashMap<String, Vec<i32>>,
+) -> bool {
+    match descriptor {
+        ItemDescriptor::Default {
+            network_id,
+            metadata,
+        } => {
+            item.network_id == *network_id as i32
+                && (*metadata == ANY_METADATA || item.metadata == *metadata as u32)
+        }
+        // Empty slots of shaped recipes
+        ItemDescriptor::Invalid => false,
+        ItemDescriptor::ItemTag { tag } => item_tags
+            .get(tag)
+            .is_some_and(|network_ids| network_ids.contains(&item.network_id)),
+        // These can't be resolved without evaluating molang or item aliases
+        ItemDescriptor::MoLang { .. }
+        | ItemDescriptor::Deferred { .. }
+        | ItemDescriptor::ComplexAlias { .. } => false,
+    }
+}
+
+/// The container the slots of a container are stored in.
+fn storage(container: ContainerName) -> ContainerName {
+    match container {
+        ContainerName::Hotbar | ContainerName::Inventory => {
+            ContainerName::CombinedHotbarAndInventory
+        }
+        other => other,
+    }
+}
+
+/// If two items can be stacked onto each other.
+fn stackable(a: &ItemStack, b: &ItemStack) -> bool {
+    if a.network_id != b.network_id
+        || a.metadata != b.metadata
+        || a.block_runtime_id != b.block_runtime_id
+    {
+        return false;
+    }
+
+    let mut a_extra = vec![];
+    let mut b_extra = vec![];
+
+    match (
+        a.extra_data.proto_serialize(&mut a_extra),
+        b.extra_data.proto_serialize(&mut b_extra),
+    ) {
+        (Ok(_), Ok(_)) => a_extra == b_extra,
+        _ => false,
+    }
+}
@@ -0,0 +1,96 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_nbt::NbtTag;
+
+use crate::types::container_name::ContainerName;
+use crate::types::item_stack::ItemStack;
+use crate::types::item_stack_response::{
+    ItemStackResponse, ItemStackResponseContainer, ItemStackResponseSlot, ItemStackResponseStatus,
+};
+
+/// Builds the [`ItemStackResponse`] for a request, containing the new state of all changed slots.
+#[derive(Debug, Clone)]
+pub struct ItemStackResponseBuilder {
+    request_id: i32,
+    containers: Vec<ItemStackResponseContainer>,
+}
+
+impl ItemStackResponseBuilder {
+    pub fn new(request_id: i32) -> Self {
+        Self {
+            request_id,
+            containers: vec![],
+        }
+    }
+
+    /// A response rejecting the request, the client reverts all of its actions.
+    pub fn error(request_id: i32, status: ItemStackResponseStatus) -> ItemStackResponse {
+        ItemStackResponse {
+            status,
+            request_id,
+            containers: vec![],
+        }
+    }
+
+    /// Adds the new state of a slot, a slot that was already added gets replaced.
+    pub fn slot(mut self, container: ContainerName, slot: u8, item: &ItemStack) -> Self {
+        let entry = ItemStackResponseSlot {
+            slot,
+            hotbar_slot: slot,
+            count: match item.is_air() {
+                true => 0,
+                false => item.count.min(u8::MAX as u16) as u8,
+            },
+            stack_network_id: item.stack_network_id.unwrap_or(0),
+            custom_name: custom_name(item).unwrap_or_default(),
+            filtered_custom_name: String::new(),
+            durability_correction: 0,
+        };
+
+        let index = match self
+            .containers
+            .iter()
+            .position(|v| v.container == container)
+        {
+            Some(index) => index,
+            None => {
+                self.containers.push(ItemStackResponseContainer {
+                    container,
+                    dynamic_id: LE::new(0),
+                    slots: vec![],
+                });
+                self.containers.len() - 1
+            }
+        };
+
+        let slots = &mut self.containers[index].slots;
+        match slots.iter_mut().find(|v| v.slot == slot) {
+            Some(existing) => *existing = entry,
+            None => slots.push(entry),
+        }
+
+        self
+    }
+
+    pub fn build(self) -> ItemStackResponse {
+        ItemStackResponse {
+            status: ItemStackResponseStatus::Ok,
+            request_id: self.request_id,
+            containers: self.containers,
+        }
+    }
+}
+
+/// The custom name of an item, stored in its user data at `display.Name`.
+fn custom_name(item: &ItemStack) -> Option<String> {
+    let Some(NbtTag::Compound(ref nbt)) = item.extra_data.nbt else {
+        return None;
+    };
+    let Some(NbtTag::Compound(display)) = nbt.get("display") else {
+        return None;
+    };
+
+    match display.get("Name") {
+        Some(NbtTag::String(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
@@ -1,13 +1,20 @@
 extern crate core;
 
+pub mod command;
 pub mod compression;
 pub mod connection;
+pub mod dimension;
 pub mod encryption;
 pub mod error;
 pub mod gamepacket;
+pub mod hud;
 pub mod info;
+pub mod inventory;
 pub mod listener;
 pub mod login;
 pub mod packets;
+pub mod registry;
+pub mod scoreboard;
+pub mod send_queue;
 pub mod transport_layer;
 pub mod types;
@@ -0,0 +1,103 @@
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use crate::connection::ConnectionShard;
+use crate::error::LoginError;
+use crate::gamepacket::GamePacket;
+use crate::login::provider::LoginProviderStatus;
+use crate::packets::disconnect::DisconnectPacket;
+use crate::packets::play_status::PlayStatusPacket;
+use crate::types::disconnect_reason::DisconnectReason;
+use crate::types::play_status::PlayStatusType;
+
+/// Awaits a provider hook and turns the returned status into the matching packets for the
+/// client, so players see why they were refused.
+pub(crate) async fn handle_provider_hook(
+    conn: &mut ConnectionShard,
+    hook_timeout: Duration,
+    hook: impl Future<Output = LoginProviderStatus>,
+) -> Result<(), LoginError> {
+    let status = match timeout(hook_timeout, hook).await {
+        Ok(status) => status,
+        Err(_) => {
+            disconnect(
+                conn,
+                DisconnectReason::Timeout,
+                Some(String::from("Login timed out")),
+            )
+            .await?;
+
+            return Err(LoginError::ProviderTimeout(hook_timeout));
+        }
+    };
+
+    match status {
+        LoginProviderStatus::ContinueLogin => Ok(()),
+        LoginProviderStatus::AbortLogin {
+            reason,
+            disconnect_reason,
+        } => {
+            disconnect(conn, disconnect_reason, Some(reason.clone())).await?;
+
+            Err(LoginError::Abort { reason })
+        }
+        LoginProviderStatus::ServerFull => {
+            play_status_failed(conn, PlayStatusType::FailedServerFull).await?;
+
+            Err(LoginError::ServerFull)
+        }
+    }
+}
+
+/// Checks if the given client protocol version is supported,
+/// if not the client gets told whether it or the server is outdated.
+pub(crate) async fn check_protocol_version(
+    conn: &mut ConnectionShard,
+    supported_protocol_versions: RangeInclusive<i32>,
+    client_protocol_version: i32,
+) -> Result<(), LoginError> {
+    if supported_protocol_versions.contains(&client_protocol_version) {
+        return Ok(());
+    }
+
+    let status = if client_protocol_version < *supported_protocol_versions.start() {
+        PlayStatusType::FailedClientOld
+    } else {
+        PlayStatusType::FailedServerOld
+    };
+
+    play_status_failed(conn, status).await?;
+
+    Err(LoginError::WrongProtocolVersion {
+        client: client_protocol_version,
+        server: supported_protocol_versions,
+    })
+}
+
+pub(crate) async fn play_status_failed(
+    conn: &mut ConnectionShard,
+    status: PlayStatusType,
+) -> Result<(), LoginError> {
+    conn.send(GamePacket::PlayStatus(PlayStatusPacket { status }))
+        .await
+        .map_err(LoginError::ConnectionError)?;
+    conn.flush().await.map_err(LoginError::ConnectionError)?;
+
+    Ok(())
+}
+
+pub(crate) async fn disconnect(
+    conn: &mut ConnectionShard,
+    reason: DisconnectReason,
+    message: Option<String>,
+) -> Result<(), LoginError> {
+    conn.send(GamePacket::Disconnect(DisconnectPacket { reason, message }))
+        .await
+        .map_err(LoginError::ConnectionError)?;
+    conn.flush().await.map_err(LoginError::ConnectionError)?;
+
+    Ok(())
+}
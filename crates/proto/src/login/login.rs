@@ -1,7 +1,8 @@
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::abort::{check_protocol_version, handle_provider_hook};
+use crate::login::provider::LoginProviderServer;
 
 pub async fn login(
     conn: &mut ConnectionShard,
@@ -21,13 +22,31 @@ pub async fn login(
         Err(e) => return Err(LoginError::ConnectionError(e)),
     };
 
-    match provider.on_login_pk(&mut login) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
+    // The client has to log in with the same protocol version it requested the network settings with
+    let protocol_version = login.client_network_version.into_inner();
+
+    match conn.get_protocol_version().await {
+        Ok(Some(negotiated)) if negotiated != protocol_version => {
+            check_protocol_version(conn, negotiated..=negotiated, protocol_version).await?;
+        }
+        Ok(_) => {
+            check_protocol_version(
+                conn,
+                provider.supported_protocol_versions(),
+                protocol_version,
+            )
+            .await?;
         }
+        Err(e) => return Err(LoginError::ConnectionError(e)),
     };
 
+    handle_provider_hook(
+        conn,
+        provider.hook_timeout(),
+        provider.on_login_pk(&mut login),
+    )
+    .await?;
+
     if provider.auth_enabled() {
         todo!("impl xbox auth with data from login pk")
     };
@@ -1,5 +1,7 @@
 pub use handle::*;
+pub use start_game::spawn_player;
 
+mod abort;
 pub mod handle;
 mod handshake;
 mod login;
@@ -3,7 +3,8 @@ use bedrockrs_core::int::LE;
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::abort::{check_protocol_version, handle_provider_hook};
+use crate::login::provider::LoginProviderServer;
 use crate::packets::network_settings::NetworkSettingsPacket;
 
 pub async fn network_settings(
@@ -24,12 +25,21 @@ pub async fn network_settings(
         Err(e) => return Err(LoginError::ConnectionError(e)),
     };
 
-    match provider.on_network_settings_request_pk(&mut network_settings_request) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    handle_provider_hook(
+        conn,
+        provider.hook_timeout(),
+        provider.on_network_settings_request_pk(&mut network_settings_request),
+    )
+    .await?;
+
+    let protocol_version = network_settings_request.client_network_version.into_inner();
+
+    check_protocol_version(
+        conn,
+        provider.supported_protocol_versions(),
+        protocol_version,
+    )
+    .await?;
 
     //////////////////////////////////////
     // Network Settings Packet
@@ -46,12 +56,12 @@ pub async fn network_settings(
         client_throttle_scalar: LE::new(0.0),
     };
 
-    match provider.on_network_settings_pk(&mut network_settings) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    handle_provider_hook(
+        conn,
+        provider.hook_timeout(),
+        provider.on_network_settings_pk(&mut network_settings),
+    )
+    .await?;
 
     match conn
         .send(GamePacket::NetworkSettings(network_settings))
@@ -71,5 +81,10 @@ pub async fn network_settings(
         Err(e) => return Err(LoginError::ConnectionError(e)),
     };
 
+    match conn.set_protocol_version(Some(protocol_version)).await {
+        Ok(_) => {}
+        Err(e) => return Err(LoginError::ConnectionError(e)),
+    };
+
     Ok(())
 }
@@ -1,8 +1,9 @@
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
+use crate::login::abort::handle_provider_hook;
 use crate::login::provider::packs::LoginProviderPacks;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::provider::LoginProviderServer;
 use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
 use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
 use crate::types::base_game_version::BaseGameVersion;
@@ -33,12 +34,12 @@ pub async fn packs(
                 cdn_urls: cdn_urls.clone(),
             };
 
-            match provider.on_resource_packs_info_pk(&mut resource_packs_info) {
-                LoginProviderStatus::ContinueLogin => {}
-                LoginProviderStatus::AbortLogin { reason } => {
-                    return Err(LoginError::Abort { reason });
-                }
-            };
+            handle_provider_hook(
+                conn,
+                provider.hook_timeout(),
+                provider.on_resource_packs_info_pk(&mut resource_packs_info),
+            )
+            .await?;
 
             match conn
                 .send(GamePacket::ResourcePacksInfo(resource_packs_info))
@@ -60,12 +61,7 @@ pub async fn packs(
 
             match conn.recv().await {
                 Ok(GamePacket::ClientCacheStatus(mut client_cache_status)) => {
-                    match provider.on_client_cache_status_pk(&mut client_cache_status) {
-                        LoginProviderStatus::ContinueLogin => {}
-                        LoginProviderStatus::AbortLogin { reason } => {
-                            return Err(LoginError::Abort { reason });
-                        }
-                    };
+                    handle_provider_hook(conn, provider.hook_timeout(), provider.on_client_cache_status_pk(&mut client_cache_status)).await?;
 
                     if let Err(e) = conn.set_cache_supported(client_cache_status.cache_supported).await {
                         return Err(LoginError::ConnectionError(e));
@@ -73,12 +69,7 @@ pub async fn packs(
 
                     match conn.recv().await {
                         Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
-                            match provider.on_resource_packs_response_pk(&mut resource_pack_client_response) {
-                                LoginProviderStatus::ContinueLogin => {}
-                                LoginProviderStatus::AbortLogin { reason } => {
-                                    return Err(LoginError::Abort { reason });
-                                }
-                            };
+                            handle_provider_hook(conn, provider.hook_timeout(), provider.on_resource_packs_response_pk(&mut resource_pack_client_response)).await?;
                         }
                         Ok(other) => {
                             return Err(LoginError::FormatError(format!(
@@ -89,12 +80,7 @@ pub async fn packs(
                     }
                 }
                 Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
-                    match provider.on_resource_packs_response_pk(&mut resource_pack_client_response) {
-                        LoginProviderStatus::ContinueLogin => {}
-                        LoginProviderStatus::AbortLogin { reason } => {
-                            return Err(LoginError::Abort { reason });
-                        }
-                    };
+                    handle_provider_hook(conn, provider.hook_timeout(), provider.on_resource_packs_response_pk(&mut resource_pack_client_response)).await?;
                 }
                 Ok(other) => {
                     return Err(LoginError::FormatError(format!(
@@ -121,12 +107,12 @@ pub async fn packs(
                 include_editor_packs: false,
             };
 
-            match provider.on_resource_packs_stack_pk(&mut resource_packs_stack) {
-                LoginProviderStatus::ContinueLogin => {}
-                LoginProviderStatus::AbortLogin { reason } => {
-                    return Err(LoginError::Abort { reason });
-                }
-            };
+            handle_provider_hook(
+                conn,
+                provider.hook_timeout(),
+                provider.on_resource_packs_stack_pk(&mut resource_packs_stack),
+            )
+            .await?;
 
             match conn
                 .send(GamePacket::ResourcePackStack(resource_packs_stack))
@@ -147,13 +133,12 @@ pub async fn packs(
 
             match conn.recv().await {
                 Ok(GamePacket::ResourcePackClientResponse(mut resource_pack_client_response)) => {
-                    match provider.on_resource_packs_response_pk(&mut resource_pack_client_response)
-                    {
-                        LoginProviderStatus::ContinueLogin => {}
-                        LoginProviderStatus::AbortLogin { reason } => {
-                            return Err(LoginError::Abort { reason });
-                        }
-                    };
+                    handle_provider_hook(
+                        conn,
+                        provider.hook_timeout(),
+                        provider.on_resource_packs_response_pk(&mut resource_pack_client_response),
+                    )
+                    .await?;
                 }
                 Ok(other) => {
                     return Err(LoginError::FormatError(format!(
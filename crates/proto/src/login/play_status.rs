@@ -1,7 +1,8 @@
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
-use crate::login::provider::{LoginProviderServer, LoginProviderStatus};
+use crate::login::abort::handle_provider_hook;
+use crate::login::provider::LoginProviderServer;
 use crate::packets::play_status::PlayStatusPacket;
 use crate::types::play_status::PlayStatusType;
 
@@ -17,12 +18,12 @@ pub async fn play_status_login(
         status: PlayStatusType::LoginSuccess,
     };
 
-    match provider.on_play_status_pk(&mut play_status) {
-        LoginProviderStatus::ContinueLogin => {}
-        LoginProviderStatus::AbortLogin { reason } => {
-            return Err(LoginError::Abort { reason });
-        }
-    };
+    handle_provider_hook(
+        conn,
+        provider.hook_timeout(),
+        provider.on_play_status_pk(&mut play_status),
+    )
+    .await?;
 
     match conn.send(GamePacket::PlayStatus(play_status)).await {
         Ok(_) => {}
@@ -42,43 +42,49 @@ impl LoginProviderServer for DefaultLoginProvider {
         &self.packs
     }
 
-    fn on_network_settings_request_pk(
+    async fn on_network_settings_request_pk(
         &mut self,
         pk: &mut NetworkSettingsRequestPacket,
     ) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
 
-    fn on_network_settings_pk(&mut self, pk: &mut NetworkSettingsPacket) -> LoginProviderStatus {
+    async fn on_network_settings_pk(
+        &mut self,
+        pk: &mut NetworkSettingsPacket,
+    ) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
 
-    fn on_login_pk(&mut self, pk: &mut LoginPacket) -> LoginProviderStatus {
+    async fn on_login_pk(&mut self, pk: &mut LoginPacket) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
 
-    fn on_play_status_pk(&mut self, pk: &mut PlayStatusPacket) -> LoginProviderStatus {
+    async fn on_play_status_pk(&mut self, pk: &mut PlayStatusPacket) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
-    fn on_resource_packs_info_pk(
+    async fn on_resource_packs_info_pk(
         &mut self,
         pk: &mut ResourcePacksInfoPacket,
     ) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
-    fn on_resource_packs_stack_pk(
+    async fn on_resource_packs_stack_pk(
         &mut self,
         pk: &mut ResourcePacksStackPacket,
     ) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
-    fn on_resource_packs_response_pk(
+    async fn on_resource_packs_response_pk(
         &mut self,
         pk: &mut ResourcePacksResponsePacket,
     ) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
-    fn on_client_cache_status_pk(&self, pk: &mut ClientCacheStatusPacket) -> LoginProviderStatus {
+    async fn on_client_cache_status_pk(
+        &self,
+        pk: &mut ClientCacheStatusPacket,
+    ) -> LoginProviderStatus {
         LoginProviderStatus::ContinueLogin
     }
 }
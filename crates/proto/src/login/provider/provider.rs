@@ -1,7 +1,13 @@
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
 use crate::compression::Compression;
+use crate::info::SUPPORTED_PROTOCOL_VERSIONS;
 use crate::login::provider::packs::LoginProviderPacks;
 use crate::login::provider::status::LoginProviderStatus;
 use crate::packets::client_cache_status::ClientCacheStatusPacket;
+use crate::packets::item_component::ItemComponentPacket;
 use crate::packets::login::LoginPacket;
 use crate::packets::network_settings::NetworkSettingsPacket;
 use crate::packets::network_settings_request::NetworkSettingsRequestPacket;
@@ -9,6 +15,7 @@ use crate::packets::play_status::PlayStatusPacket;
 use crate::packets::resource_packs_info::ResourcePacksInfoPacket;
 use crate::packets::resource_packs_response::ResourcePacksResponsePacket;
 use crate::packets::resource_packs_stack::ResourcePacksStackPacket;
+use crate::packets::start_game::StartGamePacket;
 
 pub trait LoginProviderServer {
     fn compression(&self) -> Compression;
@@ -17,41 +24,88 @@ pub trait LoginProviderServer {
 
     fn packs(&self) -> &LoginProviderPacks;
 
+    /// The protocol versions clients are allowed to join with,
+    /// every packet is en-/decoded for the version the client requested.
+    fn supported_protocol_versions(&self) -> RangeInclusive<i32> {
+        SUPPORTED_PROTOCOL_VERSIONS
+    }
+
+    /// If the player should be spawned right after the [`StartGamePacket`] was sent.
+    /// Disable this to send chunks first and spawn the player later on via
+    /// [`spawn_player`](crate::login::spawn_player).
+    fn spawn_player_on_login(&self) -> bool {
+        true
+    }
+
+    /// How long a single `on_*` hook may take before the login is aborted.
+    /// Hooks are async so they can query ban lists, whitelists or session stores.
+    fn hook_timeout(&self) -> Duration {
+        Duration::from_secs(10)
+    }
+
     fn on_network_settings_request_pk(
         &mut self,
         _pk: &mut NetworkSettingsRequestPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_network_settings_pk(&mut self, _pk: &mut NetworkSettingsPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_network_settings_pk(
+        &mut self,
+        _pk: &mut NetworkSettingsPacket,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_login_pk(&mut self, _pk: &mut LoginPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_login_pk(
+        &mut self,
+        _pk: &mut LoginPacket,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_play_status_pk(&mut self, _pk: &mut PlayStatusPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    fn on_play_status_pk(
+        &mut self,
+        _pk: &mut PlayStatusPacket,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
     fn on_resource_packs_info_pk(
         &mut self,
         _pk: &mut ResourcePacksInfoPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
     fn on_resource_packs_stack_pk(
         &mut self,
         _pk: &mut ResourcePacksStackPacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
     fn on_resource_packs_response_pk(
         &mut self,
         _pk: &mut ResourcePacksResponsePacket,
-    ) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    fn on_client_cache_status_pk(
+        &self,
+        _pk: &mut ClientCacheStatusPacket,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
-    fn on_client_cache_status_pk(&self, _pk: &mut ClientCacheStatusPacket) -> LoginProviderStatus {
-        LoginProviderStatus::ContinueLogin
+    /// Called with placeholder start game data, which should be overwritten
+    /// with the players position, gamemode, level settings, gamerules and registries.
+    fn on_start_game_pk(
+        &mut self,
+        _pk: &mut StartGamePacket,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
+    }
+    /// Called with an empty item component packet, custom items need their component data
+    /// filled in, e.g. via [`ItemRegistry::item_component_packet`](crate::registry::items::ItemRegistry::item_component_packet).
+    fn on_item_component_pk(
+        &mut self,
+        _pk: &mut ItemComponentPacket,
+    ) -> impl Future<Output = LoginProviderStatus> + Send {
+        async { LoginProviderStatus::ContinueLogin }
     }
 }
 
@@ -1,4 +1,12 @@
+use crate::types::disconnect_reason::DisconnectReason;
+
 pub enum LoginProviderStatus {
     ContinueLogin,
-    AbortLogin { reason: String },
+    /// Aborts the login, the client gets disconnected and is shown the reason.
+    AbortLogin {
+        reason: String,
+        disconnect_reason: DisconnectReason,
+    },
+    /// Aborts the login, the client is told that the server is full.
+    ServerFull,
 }
@@ -14,7 +14,10 @@ use bedrockrs_shared::world::generator_type::GeneratorType;
 use crate::connection::ConnectionShard;
 use crate::error::LoginError;
 use crate::gamepacket::GamePacket;
+use crate::info::MINECRAFT_VERSION;
+use crate::login::abort::handle_provider_hook;
 use crate::login::provider::LoginProviderServer;
+use crate::packets::item_component::ItemComponentPacket;
 use crate::packets::play_status::PlayStatusPacket;
 use crate::packets::start_game::StartGamePacket;
 use crate::types::base_game_version::BaseGameVersion;
@@ -39,7 +42,9 @@ pub async fn start_game(
     // Start Game Packet
     //////////////////////////////////////
 
-    let start_game = StartGamePacket {
+    // Placeholder world, providers are expected to overwrite this
+    // with the actual player and level data
+    let mut start_game = StartGamePacket {
         target_actor_id: ActorUniqueID(609),
         target_runtime_id: ActorRuntimeID(402),
         actor_game_type: Gamemode::Creative,
@@ -106,7 +111,7 @@ pub async fn start_game(
             persona_disabled: false,
             custom_skins_disabled: false,
             emote_chat_muted: false,
-            base_game_version: BaseGameVersion(String::from("1.21.0")),
+            base_game_version: BaseGameVersion(String::from(MINECRAFT_VERSION)),
             limited_world_width: LE::new(16),
             limited_world_depth: LE::new(16),
             new_nether: true,
@@ -136,7 +141,7 @@ pub async fn start_game(
         items: vec![],
         multiplayer_correlation_id: String::from("c5d3d2cc-27fd-4221-9de6-d22c4d423d53"),
         enable_item_stack_net_manager: false,
-        server_version: String::from("1.19.2"),
+        server_version: String::from(MINECRAFT_VERSION),
         player_property_data: NbtTag::Compound(HashMap::new()),
         block_type_registry_checksum: LE::new(0),
         world_template_id: Uuid::nil(),
@@ -147,9 +152,52 @@ pub async fn start_game(
         },
     };
 
+    handle_provider_hook(
+        conn,
+        provider.hook_timeout(),
+        provider.on_start_game_pk(&mut start_game),
+    )
+    .await?;
+
     conn.send(GamePacket::StartGame(start_game))
         .await
         .map_err(|e| LoginError::ConnectionError(e))?;
+
+    //////////////////////////////////////
+    // Item Component Packet
+    //////////////////////////////////////
+
+    let mut item_component = ItemComponentPacket { items: vec![] };
+
+    handle_provider_hook(
+        conn,
+        provider.hook_timeout(),
+        provider.on_item_component_pk(&mut item_component),
+    )
+    .await?;
+
+    conn.send(GamePacket::ItemComponent(item_component))
+        .await
+        .map_err(|e| LoginError::ConnectionError(e))?;
+    conn.flush()
+        .await
+        .map_err(|e| LoginError::ConnectionError(e))?;
+
+    //////////////////////////////////////
+    // Play Status Packet (Player Spawn)
+    //////////////////////////////////////
+
+    if provider.spawn_player_on_login() {
+        spawn_player(conn).await?;
+    }
+
+    Ok(())
+}
+
+/// Sends the [`PlayStatusType::PlayerSpawn`] status, which makes the client leave the loading
+/// screen. Only needs to be called manually if [`LoginProviderServer::spawn_player_on_login`]
+/// is disabled, usually after the chunks around the player have been sent.
+pub async fn spawn_player(conn: &mut ConnectionShard) -> Result<(), LoginError> {
     conn.send(GamePacket::PlayStatus(PlayStatusPacket {
         status: PlayStatusType::PlayerSpawn,
     }))
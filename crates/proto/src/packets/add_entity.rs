@@ -0,0 +1,31 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::actor_attribute::ActorAttribute;
+use crate::types::actor_data::ActorDataMap;
+use crate::types::actor_link::ActorLink;
+use crate::types::property_sync_data::PropertySyncData;
+
+/// Spawns a non player actor like a mob, projectile or NPC.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AddEntityPacket {
+    pub target_actor_id: ActorUniqueID,
+    pub target_runtime_id: ActorRuntimeID,
+    /// The identifier of the actor type, e.g. `minecraft:zombie`.
+    pub actor_type: String,
+    pub position: Vec3<LE<f32>>,
+    pub velocity: Vec3<LE<f32>>,
+    pub pitch: LE<f32>,
+    pub yaw: LE<f32>,
+    pub head_yaw: LE<f32>,
+    pub body_yaw: LE<f32>,
+    #[len_repr(VAR::<u32>)]
+    pub attributes: Vec<ActorAttribute>,
+    pub actor_data: ActorDataMap,
+    pub synced_properties: PropertySyncData,
+    #[len_repr(VAR::<u32>)]
+    pub links: Vec<ActorLink>,
+}
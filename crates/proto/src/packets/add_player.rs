@@ -0,0 +1,36 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+use bedrockrs_shared::world::gamemode::Gamemode;
+use uuid::Uuid;
+
+use crate::types::ability_data::AbilityData;
+use crate::types::actor_data::ActorDataMap;
+use crate::types::actor_link::ActorLink;
+use crate::types::item_stack::ItemStack;
+use crate::types::property_sync_data::PropertySyncData;
+
+/// Spawns another player, the player must have been added to the player list before.
+/// The skin is not part of this packet, it is taken from the player list entry with the same uuid.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AddPlayerPacket {
+    pub uuid: Uuid,
+    pub username: String,
+    pub target_runtime_id: ActorRuntimeID,
+    pub platform_chat_id: String,
+    pub position: Vec3<LE<f32>>,
+    pub velocity: Vec3<LE<f32>>,
+    pub pitch: LE<f32>,
+    pub yaw: LE<f32>,
+    pub head_yaw: LE<f32>,
+    pub carried_item: ItemStack,
+    pub game_type: Gamemode,
+    pub actor_data: ActorDataMap,
+    pub synced_properties: PropertySyncData,
+    pub abilities: AbilityData,
+    #[len_repr(VAR::<u32>)]
+    pub links: Vec<ActorLink>,
+    pub device_id: String,
+    pub build_platform: LE<i32>,
+}
@@ -0,0 +1,121 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::command_data::{
+    ChainedSubcommand, CommandData, CommandEnum, CommandEnumConstraint, CommandSoftEnum,
+};
+
+/// Sends all commands the player can use, which are shown in the autocompletion.
+/// Usually built from a [`CommandTree`](crate::command::tree::CommandTree).
+#[derive(Debug, Clone, Default)]
+pub struct AvailableCommandsPacket {
+    /// All values of all enums, enums refer to them by index.
+    pub enum_values: Vec<String>,
+    /// All values of chained subcommands, chained subcommands refer to them by index.
+    pub chained_subcommand_values: Vec<String>,
+    /// The suffixes of suffixed parameters, e.g. `L` for levels.
+    pub suffixes: Vec<String>,
+    pub enums: Vec<CommandEnum>,
+    pub chained_subcommands: Vec<ChainedSubcommand>,
+    pub commands: Vec<CommandData>,
+    pub soft_enums: Vec<CommandSoftEnum>,
+    pub enum_constraints: Vec<CommandEnumConstraint>,
+}
+
+fn serialize_vec<T: ProtoCodec>(vec: &[T], stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+    VAR::<u32>::new(vec.len().try_into()?).proto_serialize(stream)?;
+
+    for v in vec {
+        v.proto_serialize(stream)?;
+    }
+
+    Ok(())
+}
+
+fn deserialize_vec<T: ProtoCodec>(stream: &mut Cursor<&[u8]>) -> Result<Vec<T>, ProtoCodecError> {
+    let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+    (0..len).map(|_| T::proto_deserialize(stream)).collect()
+}
+
+impl ProtoCodec for AvailableCommandsPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        serialize_vec(&self.enum_values, stream)?;
+        serialize_vec(&self.chained_subcommand_values, stream)?;
+        serialize_vec(&self.suffixes, stream)?;
+
+        // The width of the value indices depends on the amount of enum values
+        let value_count = self.enum_values.len();
+
+        VAR::<u32>::new(self.enums.len().try_into()?).proto_serialize(stream)?;
+        for command_enum in &self.enums {
+            command_enum.name.proto_serialize(stream)?;
+
+            VAR::<u32>::new(command_enum.value_indices.len().try_into()?)
+                .proto_serialize(stream)?;
+            for index in &command_enum.value_indices {
+                match value_count {
+                    0..=0xFF => u8::try_from(*index)?.proto_serialize(stream)?,
+                    0x100..=0xFFFF => LE::new(u16::try_from(*index)?).proto_serialize(stream)?,
+                    _ => LE::new(*index).proto_serialize(stream)?,
+                }
+            }
+        }
+
+        serialize_vec(&self.chained_subcommands, stream)?;
+        serialize_vec(&self.commands, stream)?;
+        serialize_vec(&self.soft_enums, stream)?;
+        serialize_vec(&self.enum_constraints, stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let enum_values: Vec<String> = deserialize_vec(stream)?;
+        let chained_subcommand_values = deserialize_vec(stream)?;
+        let suffixes = deserialize_vec(stream)?;
+
+        let value_count = enum_values.len();
+
+        let enum_count = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut enums = Vec::with_capacity(enum_count.min(1024) as usize);
+        for _ in 0..enum_count {
+            let name = String::proto_deserialize(stream)?;
+
+            let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+            let value_indices = (0..len)
+                .map(|_| {
+                    Ok(match value_count {
+                        0..=0xFF => u8::proto_deserialize(stream)? as u32,
+                        0x100..=0xFFFF => LE::<u16>::proto_deserialize(stream)?.into_inner() as u32,
+                        _ => LE::<u32>::proto_deserialize(stream)?.into_inner(),
+                    })
+                })
+                .collect::<Result<Vec<_>, ProtoCodecError>>()?;
+
+            enums.push(CommandEnum {
+                name,
+                value_indices,
+            });
+        }
+
+        let chained_subcommands = deserialize_vec(stream)?;
+        let commands = deserialize_vec(stream)?;
+        let soft_enums = deserialize_vec(stream)?;
+        let enum_constraints = deserialize_vec(stream)?;
+
+        Ok(Self {
+            enum_values,
+            chained_subcommand_values,
+            suffixes,
+            enums,
+            chained_subcommands,
+            commands,
+            soft_enums,
+            enum_constraints,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::boss_event::BossEventType;
+
+/// Shows, updates or hides a boss bar. The boss actor has to exist on the client,
+/// otherwise the boss bar isn't shown.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct BossEventPacket {
+    pub boss_actor_id: ActorUniqueID,
+    pub event: BossEventType,
+}
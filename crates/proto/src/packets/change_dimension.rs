@@ -0,0 +1,14 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::world::dimension::Dimension;
+
+/// Starts a dimension change, the client shows a loading screen until it acknowledges
+/// the change with a [`PlayerActionType::DimensionChangeDone`](crate::types::player_action_type::PlayerActionType::DimensionChangeDone).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ChangeDimensionPacket {
+    pub dimension: Dimension,
+    pub position: Vec3<LE<f32>>,
+    /// If the change happens because the player respawns.
+    pub respawn: bool,
+}
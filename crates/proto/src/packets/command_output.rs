@@ -0,0 +1,89 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::command_origin::CommandOriginData;
+use crate::types::command_output::{CommandOutputMessage, CommandOutputType};
+
+/// Answers a [`CommandRequestPacket`](crate::packets::command_request::CommandRequestPacket).
+#[derive(Debug, Clone)]
+pub struct CommandOutputPacket {
+    /// The origin of the request this answers.
+    pub origin: CommandOriginData,
+    pub output_type: CommandOutputType,
+    pub success_count: VAR<u32>,
+    pub messages: Vec<CommandOutputMessage>,
+    /// Only sent for [`CommandOutputType::DataSet`].
+    pub data_set: String,
+}
+
+impl CommandOutputPacket {
+    /// Creates the output for a command that succeeded.
+    pub fn success(origin: CommandOriginData, message: impl Into<String>) -> Self {
+        Self::with_message(origin, true, message.into())
+    }
+
+    /// Creates the output for a command that failed, the message is shown in red.
+    pub fn failure(origin: CommandOriginData, message: impl Into<String>) -> Self {
+        Self::with_message(origin, false, message.into())
+    }
+
+    fn with_message(origin: CommandOriginData, success: bool, message: String) -> Self {
+        Self {
+            origin,
+            output_type: CommandOutputType::AllOutput,
+            success_count: VAR::new(success as u32),
+            messages: vec![CommandOutputMessage {
+                success,
+                message_id: message,
+                parameters: vec![],
+            }],
+            data_set: String::new(),
+        }
+    }
+}
+
+impl ProtoCodec for CommandOutputPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.origin.proto_serialize(stream)?;
+        self.output_type.proto_serialize(stream)?;
+        self.success_count.proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.messages.len().try_into()?).proto_serialize(stream)?;
+        for message in &self.messages {
+            message.proto_serialize(stream)?;
+        }
+
+        if self.output_type == CommandOutputType::DataSet {
+            self.data_set.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let origin = CommandOriginData::proto_deserialize(stream)?;
+        let output_type = CommandOutputType::proto_deserialize(stream)?;
+        let success_count = VAR::<u32>::proto_deserialize(stream)?;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let messages = (0..len)
+            .map(|_| CommandOutputMessage::proto_deserialize(stream))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let data_set = match output_type {
+            CommandOutputType::DataSet => String::proto_deserialize(stream)?,
+            _ => String::new(),
+        };
+
+        Ok(Self {
+            origin,
+            output_type,
+            success_count,
+            messages,
+            data_set,
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::command_origin::CommandOriginData;
+
+/// Sent by the client when a player runs a command.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandRequestPacket {
+    /// The command as typed, including the leading `/`.
+    pub command: String,
+    pub origin: CommandOriginData,
+    pub internal: bool,
+    pub version: VAR<i32>,
+}
@@ -0,0 +1,44 @@
+use std::io::Cursor;
+
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::info::PROTOCOL_VERSION_1_21_0;
+use crate::types::container_type::ContainerType;
+
+#[derive(Debug, Clone)]
+pub struct ContainerClosePacket {
+    pub window_id: u8,
+    pub container_type: ContainerType,
+    /// If the server closed the container, the server has to answer a close
+    /// of the client with this set to false.
+    pub server_side: bool,
+}
+
+impl ProtoCodec for ContainerClosePacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.window_id.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.container_type.proto_serialize(stream)?;
+        }
+        self.server_side.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let window_id = u8::proto_deserialize(stream)?;
+        let container_type = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => ContainerType::proto_deserialize(stream)?,
+            false => ContainerType::None,
+        };
+        let server_side = bool::proto_deserialize(stream)?;
+
+        Ok(Self {
+            window_id,
+            container_type,
+            server_side,
+        })
+    }
+}
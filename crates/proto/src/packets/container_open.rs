@@ -0,0 +1,15 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::container_type::ContainerType;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ContainerOpenPacket {
+    pub window_id: u8,
+    pub container_type: ContainerType,
+    /// The position of the container block, the block needs to exist client side.
+    pub position: NetworkBlockPos,
+    /// The entity holding the container, -1 for block containers.
+    pub entity_unique_id: ActorUniqueID,
+}
@@ -0,0 +1,12 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Updates a property of a container, e.g. the progress of a furnace.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ContainerSetDataPacket {
+    pub window_id: u8,
+    /// The meaning of the key depends on the container type, e.g. for furnaces
+    /// `0` is the cooking progress, `1` the remaining and `2` the total burn time of the fuel.
+    pub key: VAR<i32>,
+    pub value: VAR<i32>,
+}
@@ -0,0 +1,20 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::recipe::{
+    MaterialReducerRecipe, PotionContainerChangeRecipe, PotionRecipe, Recipe,
+};
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CraftingDataPacket {
+    #[len_repr(VAR::<u32>)]
+    pub recipes: Vec<Recipe>,
+    #[len_repr(VAR::<u32>)]
+    pub potion_recipes: Vec<PotionRecipe>,
+    #[len_repr(VAR::<u32>)]
+    pub potion_container_change_recipes: Vec<PotionContainerChangeRecipe>,
+    #[len_repr(VAR::<u32>)]
+    pub material_reducers: Vec<MaterialReducerRecipe>,
+    /// If the recipes the client already knows should be removed.
+    pub clear_recipes: bool,
+}
@@ -0,0 +1,17 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use uuid::Uuid;
+
+use crate::types::item_stack::ItemStack;
+
+/// Sent by the client after crafting, only informative.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CraftingEventPacket {
+    pub window_id: u8,
+    pub crafting_type: VAR<i32>,
+    pub recipe_uuid: Uuid,
+    #[len_repr(VAR::<u32>)]
+    pub input: Vec<ItemStack>,
+    #[len_repr(VAR::<u32>)]
+    pub output: Vec<ItemStack>,
+}
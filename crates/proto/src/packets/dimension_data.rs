@@ -0,0 +1,11 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::dimension_definition::DimensionDefinition;
+
+/// Changes the height range of dimensions, must be sent before the dimension is entered.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct DimensionDataPacket {
+    #[len_repr(VAR::<u32>)]
+    pub definitions: Vec<DimensionDefinition>,
+}
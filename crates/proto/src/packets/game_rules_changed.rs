@@ -0,0 +1,11 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::gamerule::GameRule;
+
+/// Updates gamerules, gamerules not contained keep their value.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct GameRulesChangedPacket {
+    #[len_repr(VAR::<u32>)]
+    pub gamerules: Vec<GameRule>,
+}
@@ -0,0 +1,12 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Damages the armor of the player, the client calculates the durability loss.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct HurtArmorPacket {
+    /// The ID of the damage cause.
+    pub cause: VAR<i32>,
+    pub damage: VAR<i32>,
+    /// A bitset of the armor slots that get damaged, from helmet (`0`) to boots (`3`).
+    pub armor_slots: VAR<i64>,
+}
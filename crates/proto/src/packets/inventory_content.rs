@@ -0,0 +1,12 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::item_stack::ItemStack;
+
+/// Sets all slots of a window.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct InventoryContentPacket {
+    pub window_id: VAR<u32>,
+    #[len_repr(VAR::<u32>)]
+    pub content: Vec<ItemStack>,
+}
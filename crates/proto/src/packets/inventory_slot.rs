@@ -0,0 +1,12 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::item_stack::ItemStack;
+
+/// Sets a single slot of a window.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct InventorySlotPacket {
+    pub window_id: VAR<u32>,
+    pub slot: VAR<u32>,
+    pub item: ItemStack,
+}
@@ -0,0 +1,77 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::inventory_transaction::{
+    InventoryAction, InventoryTransactionType, LegacySetItemSlot,
+};
+
+#[derive(Debug, Clone)]
+pub struct InventoryTransactionPacket {
+    /// legacy_request_id is an ID that is only non-zero at times when sent by the client. The server should
+    /// always send 0 for this. When this field is not 0, the legacy_set_item_slots below will have values
+    /// in it.
+    /// legacy_request_id ties in with the ItemStackResponse packet. If this field is non-0, the server should
+    /// respond with an ItemStackResponse packet. Some inventory actions such as dropping an item out of the
+    /// hotbar are still one using this packet, and the ItemStackResponse packet needs to tie in with it.
+    pub legacy_request_id: i32,
+    /// Only sent if the legacy_request_id is not 0.
+    pub legacy_set_item_slots: Vec<LegacySetItemSlot>,
+    pub actions: Vec<InventoryAction>,
+    pub transaction_type: InventoryTransactionType,
+}
+
+impl ProtoCodec for InventoryTransactionPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<i32>::new(self.legacy_request_id).proto_serialize(stream)?;
+
+        if self.legacy_request_id != 0 {
+            VAR::<u32>::new(self.legacy_set_item_slots.len().try_into()?)
+                .proto_serialize(stream)?;
+            for slot in &self.legacy_set_item_slots {
+                slot.proto_serialize(stream)?;
+            }
+        }
+
+        VAR::<u32>::new(self.transaction_type.id()).proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.actions.len().try_into()?).proto_serialize(stream)?;
+        for action in &self.actions {
+            action.proto_serialize(stream)?;
+        }
+
+        self.transaction_type.serialize_data(stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let legacy_request_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+
+        let mut legacy_set_item_slots = vec![];
+        if legacy_request_id != 0 {
+            let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+            for _ in 0..len {
+                legacy_set_item_slots.push(LegacySetItemSlot::proto_deserialize(stream)?);
+            }
+        }
+
+        let transaction_type_id = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        let mut actions = vec![];
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        for _ in 0..len {
+            actions.push(InventoryAction::proto_deserialize(stream)?);
+        }
+
+        let transaction_type =
+            InventoryTransactionType::deserialize_data(transaction_type_id, stream)?;
+
+        Ok(Self {
+            legacy_request_id,
+            legacy_set_item_slots,
+            actions,
+            transaction_type,
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_nbt::NbtTag;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Sends the component data of all component based items,
+/// see [`ItemRegistry::item_component_packet`](crate::registry::items::ItemRegistry::item_component_packet).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemComponentPacket {
+    #[len_repr(VAR::<u32>)]
+    pub items: Vec<ItemComponentEntry>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemComponentEntry {
+    pub name: String,
+    pub nbt: NbtTag,
+}
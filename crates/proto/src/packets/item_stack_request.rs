@@ -0,0 +1,10 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::item_stack_request::ItemStackRequest;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackRequestPacket {
+    #[len_repr(VAR::<u32>)]
+    pub requests: Vec<ItemStackRequest>,
+}
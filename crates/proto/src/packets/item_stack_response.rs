@@ -0,0 +1,10 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::item_stack_response::ItemStackResponse;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackResponsePacket {
+    #[len_repr(VAR::<u32>)]
+    pub responses: Vec<ItemStackResponse>,
+}
@@ -0,0 +1,45 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::level_event_type::LevelEventType;
+
+/// Triggers a vanilla level event like a sound, particles or weather changes.
+#[derive(Debug, Clone)]
+pub struct LevelEventPacket {
+    pub event: LevelEventType,
+    pub position: Vec3<LE<f32>>,
+    /// Event specific data, see [`LevelEventType`].
+    pub data: VAR<i32>,
+}
+
+impl LevelEventPacket {
+    pub fn new(event: LevelEventType, position: Vec3<LE<f32>>, data: i32) -> Self {
+        Self {
+            event,
+            position,
+            data: VAR::new(data),
+        }
+    }
+}
+
+impl ProtoCodec for LevelEventPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<i32>::new(self.event.id()).proto_serialize(stream)?;
+        self.position.proto_serialize(stream)?;
+        self.data.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            event: LevelEventType::from_id(VAR::<i32>::proto_deserialize(stream)?.into_inner()),
+            position: Vec3::<LE<f32>>::proto_deserialize(stream)?,
+            data: VAR::<i32>::proto_deserialize(stream)?,
+        })
+    }
+}
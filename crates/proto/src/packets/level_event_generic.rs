@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_nbt::endian::little_endian_network::NbtLittleEndianNetwork;
+use bedrockrs_nbt::NbtTag;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::level_event_type::LevelEventType;
+
+/// A level event with its data as compound tag, like the `x`, `y` and `z` of particle events.
+#[derive(Debug, Clone)]
+pub struct LevelEventGenericPacket {
+    pub event: LevelEventType,
+    /// Sent as the compound's tags only, without the compound header.
+    pub event_data: HashMap<String, NbtTag>,
+}
+
+impl ProtoCodec for LevelEventGenericPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<i32>::new(self.event.id()).proto_serialize(stream)?;
+
+        let mut tags: Vec<_> = self.event_data.iter().collect();
+        tags.sort_by_key(|(name, _)| *name);
+
+        for (name, tag) in tags {
+            tag.nbt_serialize::<NbtLittleEndianNetwork>(name, stream)?;
+        }
+
+        // Closes the compound
+        0u8.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let event = LevelEventType::from_id(VAR::<i32>::proto_deserialize(stream)?.into_inner());
+        let mut event_data = HashMap::new();
+
+        loop {
+            let position = stream.position();
+
+            if u8::proto_deserialize(stream)? == 0 {
+                break;
+            }
+
+            stream.set_position(position);
+
+            let (name, tag) = NbtTag::nbt_deserialize::<NbtLittleEndianNetwork>(stream)?;
+            event_data.insert(name, tag);
+        }
+
+        Ok(Self { event, event_data })
+    }
+}
@@ -0,0 +1,61 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::level_sound_event_type::LevelSoundEventType;
+
+/// Plays a vanilla sound event, sent by both sides.
+#[derive(Debug, Clone)]
+pub struct LevelSoundEventPacket {
+    pub event: LevelSoundEventType,
+    pub position: Vec3<LE<f32>>,
+    /// Event specific data, like the block runtime ID for block sounds or the note for note blocks.
+    pub extra_data: VAR<i32>,
+    /// The actor type playing the sound, like `minecraft:pig`, empty for non actor sounds.
+    pub actor_type: String,
+    pub is_baby_mob: bool,
+    /// Plays the sound at the same volume regardless of the distance.
+    pub is_global: bool,
+}
+
+impl LevelSoundEventPacket {
+    pub fn new(event: LevelSoundEventType, position: Vec3<LE<f32>>) -> Self {
+        Self {
+            event,
+            position,
+            extra_data: VAR::new(-1),
+            actor_type: String::new(),
+            is_baby_mob: false,
+            is_global: false,
+        }
+    }
+}
+
+impl ProtoCodec for LevelSoundEventPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<u32>::new(self.event.id()).proto_serialize(stream)?;
+        self.position.proto_serialize(stream)?;
+        self.extra_data.proto_serialize(stream)?;
+        self.actor_type.proto_serialize(stream)?;
+        self.is_baby_mob.proto_serialize(stream)?;
+        self.is_global.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            event: LevelSoundEventType::from_id(
+                VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+            ),
+            position: Vec3::<LE<f32>>::proto_deserialize(stream)?,
+            extra_data: VAR::<i32>::proto_deserialize(stream)?,
+            actor_type: String::proto_deserialize(stream)?,
+            is_baby_mob: bool::proto_deserialize(stream)?,
+            is_global: bool::proto_deserialize(stream)?,
+        })
+    }
+}
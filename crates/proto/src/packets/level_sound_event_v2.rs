@@ -0,0 +1,46 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::level_sound_event_type::LevelSoundEventType;
+
+/// An older version of the [`LevelSoundEventPacket`](crate::packets::level_sound_event::LevelSoundEventPacket)
+/// limited to the first 256 sound events.
+#[derive(Debug, Clone)]
+pub struct LevelSoundEventV2Packet {
+    pub event: LevelSoundEventType,
+    pub position: Vec3<LE<f32>>,
+    pub extra_data: VAR<i32>,
+    pub actor_type: String,
+    pub is_baby_mob: bool,
+    pub is_global: bool,
+}
+
+impl ProtoCodec for LevelSoundEventV2Packet {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let event: u8 = self.event.id().try_into()?;
+
+        event.proto_serialize(stream)?;
+        self.position.proto_serialize(stream)?;
+        self.extra_data.proto_serialize(stream)?;
+        self.actor_type.proto_serialize(stream)?;
+        self.is_baby_mob.proto_serialize(stream)?;
+        self.is_global.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            event: LevelSoundEventType::from_id(u8::proto_deserialize(stream)?.into()),
+            position: Vec3::<LE<f32>>::proto_deserialize(stream)?,
+            extra_data: VAR::<i32>::proto_deserialize(stream)?,
+            actor_type: String::proto_deserialize(stream)?,
+            is_baby_mob: bool::proto_deserialize(stream)?,
+            is_global: bool::proto_deserialize(stream)?,
+        })
+    }
+}
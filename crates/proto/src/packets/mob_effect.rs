@@ -0,0 +1,111 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::info::PROTOCOL_VERSION_1_20_80;
+use crate::types::mob_effect::{MobEffectEvent, MobEffectType};
+
+/// Adds, modifies or removes a status effect of an actor.
+#[derive(Debug, Clone)]
+pub struct MobEffectPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub event: MobEffectEvent,
+    pub effect: MobEffectType,
+    /// The level of the effect minus one, e.g. `1` for Speed II.
+    pub amplifier: VAR<i32>,
+    pub show_particles: bool,
+    /// The duration in ticks.
+    pub duration: VAR<i32>,
+    /// The server tick the effect was changed at, `0` if it isn't known.
+    /// Only sent since 1.20.80.
+    pub tick: VAR<u64>,
+}
+
+impl MobEffectPacket {
+    pub fn add(
+        target_runtime_id: ActorRuntimeID,
+        effect: MobEffectType,
+        amplifier: i32,
+        duration: i32,
+        show_particles: bool,
+    ) -> Self {
+        Self {
+            target_runtime_id,
+            event: MobEffectEvent::Add,
+            effect,
+            amplifier: VAR::new(amplifier),
+            show_particles,
+            duration: VAR::new(duration),
+            tick: VAR::new(0),
+        }
+    }
+
+    pub fn modify(
+        target_runtime_id: ActorRuntimeID,
+        effect: MobEffectType,
+        amplifier: i32,
+        duration: i32,
+        show_particles: bool,
+    ) -> Self {
+        Self {
+            event: MobEffectEvent::Modify,
+            ..Self::add(
+                target_runtime_id,
+                effect,
+                amplifier,
+                duration,
+                show_particles,
+            )
+        }
+    }
+
+    pub fn remove(target_runtime_id: ActorRuntimeID, effect: MobEffectType) -> Self {
+        Self {
+            event: MobEffectEvent::Remove,
+            ..Self::add(target_runtime_id, effect, 0, 0, false)
+        }
+    }
+}
+
+impl ProtoCodec for MobEffectPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.target_runtime_id.proto_serialize(stream)?;
+        self.event.proto_serialize(stream)?;
+        self.effect.proto_serialize(stream)?;
+        self.amplifier.proto_serialize(stream)?;
+        self.show_particles.proto_serialize(stream)?;
+        self.duration.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.tick.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let target_runtime_id = ActorRuntimeID::proto_deserialize(stream)?;
+        let event = MobEffectEvent::proto_deserialize(stream)?;
+        let effect = MobEffectType::proto_deserialize(stream)?;
+        let amplifier = VAR::<i32>::proto_deserialize(stream)?;
+        let show_particles = bool::proto_deserialize(stream)?;
+        let duration = VAR::<i32>::proto_deserialize(stream)?;
+        let tick = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => VAR::<u64>::proto_deserialize(stream)?,
+            false => VAR::new(0),
+        };
+
+        Ok(Self {
+            target_runtime_id,
+            event,
+            effect,
+            amplifier,
+            show_particles,
+            duration,
+            tick,
+        })
+    }
+}
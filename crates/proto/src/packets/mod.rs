@@ -1,26 +1,81 @@
+pub mod add_entity;
+pub mod add_player;
 pub mod animate;
+pub mod available_commands;
+pub mod boss_event;
+pub mod change_dimension;
 pub mod chunk_radius_updated;
 pub mod client_cache_status;
+pub mod command_output;
+pub mod command_request;
+pub mod container_close;
+pub mod container_open;
+pub mod container_set_data;
+pub mod crafting_data;
+pub mod crafting_event;
+pub mod dimension_data;
 pub mod disconnect;
 pub mod emote_list;
+pub mod game_rules_changed;
 pub mod handshake_server_to_client;
+pub mod hurt_armor;
 pub mod interact;
+pub mod inventory_content;
+pub mod inventory_slot;
+pub mod inventory_transaction;
+pub mod item_component;
+pub mod item_stack_request;
+pub mod item_stack_response;
 pub mod level_chunk;
+pub mod level_event;
+pub mod level_event_generic;
+pub mod level_sound_event;
+pub mod level_sound_event_v2;
 pub mod login;
+pub mod mob_effect;
 pub mod modal_form_request;
 pub mod modal_form_response;
+pub mod move_entity;
+pub mod move_entity_delta;
+pub mod network_chunk_publisher_update;
 pub mod network_settings;
 pub mod network_settings_request;
 pub mod packet_violation_warning;
+pub mod play_sound;
 pub mod play_status;
+pub mod player_action;
 pub mod player_auth_input;
+pub mod player_hotbar;
+pub mod player_list;
 pub mod player_move;
+pub mod player_skin;
+pub mod remove_entity;
+pub mod remove_objective;
 pub mod request_chunk_radius;
 pub mod resource_packs_info;
 pub mod resource_packs_response;
 pub mod resource_packs_stack;
+pub mod respawn;
 pub mod server_settings_request;
 pub mod server_settings_response;
+pub mod set_default_game_type;
+pub mod set_difficulty;
+pub mod set_display_objective;
+pub mod set_entity_data;
+pub mod set_entity_link;
+pub mod set_entity_motion;
+pub mod set_health;
 pub mod set_local_player_as_initialized;
+pub mod set_player_game_type;
+pub mod set_score;
+pub mod set_scoreboard_identity;
+pub mod set_time;
+pub mod set_title;
+pub mod spawn_particle_effect;
 pub mod start_game;
+pub mod stop_sound;
 pub mod text_message;
+pub mod toast_request;
+pub mod update_attributes;
+pub mod update_player_game_type;
+pub mod update_soft_enum;
@@ -0,0 +1,82 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+/// Teleports or moves an actor to an absolute position.
+#[derive(Debug, Clone)]
+pub struct MoveEntityPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub on_ground: bool,
+    /// Skips the interpolation of the movement.
+    pub teleport: bool,
+    pub force_move: bool,
+    pub position: Vec3<LE<f32>>,
+    /// Rotations are sent with a precision of 1/256 of a full turn.
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32,
+}
+
+impl MoveEntityPacket {
+    const FLAG_ON_GROUND: u8 = 0x01;
+    const FLAG_TELEPORT: u8 = 0x02;
+    const FLAG_FORCE_MOVE: u8 = 0x04;
+}
+
+/// Writes an angle in degrees as a single byte.
+pub(crate) fn write_byte_angle(angle: f32, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+    ((angle.rem_euclid(360.0) / (360.0 / 256.0)) as u8).proto_serialize(stream)
+}
+
+/// Reads an angle in degrees sent as a single byte.
+pub(crate) fn read_byte_angle(stream: &mut Cursor<&[u8]>) -> Result<f32, ProtoCodecError> {
+    Ok(u8::proto_deserialize(stream)? as f32 * (360.0 / 256.0))
+}
+
+impl ProtoCodec for MoveEntityPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let mut flags = 0;
+        if self.on_ground {
+            flags |= Self::FLAG_ON_GROUND;
+        }
+        if self.teleport {
+            flags |= Self::FLAG_TELEPORT;
+        }
+        if self.force_move {
+            flags |= Self::FLAG_FORCE_MOVE;
+        }
+
+        self.target_runtime_id.proto_serialize(stream)?;
+        flags.proto_serialize(stream)?;
+        self.position.proto_serialize(stream)?;
+        write_byte_angle(self.pitch, stream)?;
+        write_byte_angle(self.yaw, stream)?;
+        write_byte_angle(self.head_yaw, stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let target_runtime_id = ActorRuntimeID::proto_deserialize(stream)?;
+        let flags = u8::proto_deserialize(stream)?;
+        let position = Vec3::<LE<f32>>::proto_deserialize(stream)?;
+        let pitch = read_byte_angle(stream)?;
+        let yaw = read_byte_angle(stream)?;
+        let head_yaw = read_byte_angle(stream)?;
+
+        Ok(Self {
+            target_runtime_id,
+            on_ground: flags & Self::FLAG_ON_GROUND != 0,
+            teleport: flags & Self::FLAG_TELEPORT != 0,
+            force_move: flags & Self::FLAG_FORCE_MOVE != 0,
+            position,
+            pitch,
+            yaw,
+            head_yaw,
+        })
+    }
+}
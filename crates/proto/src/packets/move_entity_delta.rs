@@ -0,0 +1,154 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::packets::move_entity::{read_byte_angle, write_byte_angle};
+
+/// Moves an actor, only the components that changed are sent.
+#[derive(Debug, Clone)]
+pub struct MoveEntityDeltaPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+    /// Rotations are sent with a precision of 1/256 of a full turn.
+    pub pitch: Option<f32>,
+    pub yaw: Option<f32>,
+    pub head_yaw: Option<f32>,
+    pub on_ground: bool,
+    /// Skips the interpolation of the movement.
+    pub teleport: bool,
+    pub force_move: bool,
+}
+
+impl MoveEntityDeltaPacket {
+    pub const FLAG_HAS_X: u16 = 0x01;
+    pub const FLAG_HAS_Y: u16 = 0x02;
+    pub const FLAG_HAS_Z: u16 = 0x04;
+    pub const FLAG_HAS_PITCH: u16 = 0x08;
+    pub const FLAG_HAS_YAW: u16 = 0x10;
+    pub const FLAG_HAS_HEAD_YAW: u16 = 0x20;
+    pub const FLAG_ON_GROUND: u16 = 0x40;
+    pub const FLAG_TELEPORT: u16 = 0x80;
+    pub const FLAG_FORCE_MOVE: u16 = 0x100;
+
+    /// Creates a delta containing only the components that differ between
+    /// the last sent and the current position and rotation.
+    /// The rotation is compared at the precision it is sent with.
+    pub fn between(
+        target_runtime_id: ActorRuntimeID,
+        (from_position, from_rotation): (Vec3<f32>, Vec3<f32>),
+        (position, rotation): (Vec3<f32>, Vec3<f32>),
+        on_ground: bool,
+    ) -> Self {
+        fn changed(from: f32, to: f32) -> Option<f32> {
+            (from != to).then_some(to)
+        }
+
+        fn changed_angle(from: f32, to: f32) -> Option<f32> {
+            let byte = |angle: f32| (angle.rem_euclid(360.0) / (360.0 / 256.0)) as u8;
+
+            (byte(from) != byte(to)).then_some(to)
+        }
+
+        Self {
+            target_runtime_id,
+            x: changed(from_position.x, position.x),
+            y: changed(from_position.y, position.y),
+            z: changed(from_position.z, position.z),
+            pitch: changed_angle(from_rotation.x, rotation.x),
+            yaw: changed_angle(from_rotation.y, rotation.y),
+            head_yaw: changed_angle(from_rotation.z, rotation.z),
+            on_ground,
+            teleport: false,
+            force_move: false,
+        }
+    }
+
+    /// If the packet doesn't change anything and doesn't need to be sent.
+    pub fn is_empty(&self) -> bool {
+        self.flags() & !Self::FLAG_ON_GROUND == 0
+    }
+
+    pub fn flags(&self) -> u16 {
+        let mut flags = 0;
+
+        for (present, flag) in [
+            (self.x.is_some(), Self::FLAG_HAS_X),
+            (self.y.is_some(), Self::FLAG_HAS_Y),
+            (self.z.is_some(), Self::FLAG_HAS_Z),
+            (self.pitch.is_some(), Self::FLAG_HAS_PITCH),
+            (self.yaw.is_some(), Self::FLAG_HAS_YAW),
+            (self.head_yaw.is_some(), Self::FLAG_HAS_HEAD_YAW),
+            (self.on_ground, Self::FLAG_ON_GROUND),
+            (self.teleport, Self::FLAG_TELEPORT),
+            (self.force_move, Self::FLAG_FORCE_MOVE),
+        ] {
+            if present {
+                flags |= flag;
+            }
+        }
+
+        flags
+    }
+}
+
+impl ProtoCodec for MoveEntityDeltaPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.target_runtime_id.proto_serialize(stream)?;
+        LE::new(self.flags()).proto_serialize(stream)?;
+
+        for v in [self.x, self.y, self.z].into_iter().flatten() {
+            LE::new(v).proto_serialize(stream)?;
+        }
+        for v in [self.pitch, self.yaw, self.head_yaw].into_iter().flatten() {
+            write_byte_angle(v, stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let target_runtime_id = ActorRuntimeID::proto_deserialize(stream)?;
+        let flags = LE::<u16>::proto_deserialize(stream)?.into_inner();
+
+        let mut read_coord = |flag: u16| -> Result<Option<f32>, ProtoCodecError> {
+            match flags & flag {
+                0 => Ok(None),
+                _ => Ok(Some(LE::<f32>::proto_deserialize(stream)?.into_inner())),
+            }
+        };
+
+        let x = read_coord(Self::FLAG_HAS_X)?;
+        let y = read_coord(Self::FLAG_HAS_Y)?;
+        let z = read_coord(Self::FLAG_HAS_Z)?;
+
+        let mut read_angle = |flag: u16| -> Result<Option<f32>, ProtoCodecError> {
+            match flags & flag {
+                0 => Ok(None),
+                _ => Ok(Some(read_byte_angle(stream)?)),
+            }
+        };
+
+        let pitch = read_angle(Self::FLAG_HAS_PITCH)?;
+        let yaw = read_angle(Self::FLAG_HAS_YAW)?;
+        let head_yaw = read_angle(Self::FLAG_HAS_HEAD_YAW)?;
+
+        Ok(Self {
+            target_runtime_id,
+            x,
+            y,
+            z,
+            pitch,
+            yaw,
+            head_yaw,
+            on_ground: flags & Self::FLAG_ON_GROUND != 0,
+            teleport: flags & Self::FLAG_TELEPORT != 0,
+            force_move: flags & Self::FLAG_FORCE_MOVE != 0,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::chunk_pos::ChunkPos;
+
+/// Sets the area the client keeps chunks loaded in, chunks outside of it are discarded.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct NetworkChunkPublisherUpdatePacket {
+    pub position: Vec3<VAR<i32>>,
+    /// The radius in blocks.
+    pub radius: VAR<u32>,
+    /// Chunks the client should keep even if they are outside of the radius.
+    #[len_repr(LE::<u32>)]
+    pub saved_chunks: Vec<ChunkPos>,
+}
@@ -0,0 +1,62 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// Plays a sound by its name from the sound definitions, like `random.levelup`.
+#[derive(Debug, Clone)]
+pub struct PlaySoundPacket {
+    pub sound_name: String,
+    /// Sent as a [`NetworkBlockPos`] scaled by 8, so it is precise to an eighth of a block.
+    pub position: Vec3<f32>,
+    pub volume: LE<f32>,
+    pub pitch: LE<f32>,
+}
+
+impl PlaySoundPacket {
+    pub fn new(sound_name: impl Into<String>, position: Vec3<f32>) -> Self {
+        Self {
+            sound_name: sound_name.into(),
+            position,
+            volume: LE::new(1.0),
+            pitch: LE::new(1.0),
+        }
+    }
+}
+
+impl ProtoCodec for PlaySoundPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.sound_name.proto_serialize(stream)?;
+        NetworkBlockPos {
+            x: VAR::new((self.position.x * 8.0) as i32),
+            // Negative heights wrap around, like they do in the vanilla client
+            y: VAR::new((self.position.y * 8.0) as i32 as u32),
+            z: VAR::new((self.position.z * 8.0) as i32),
+        }
+        .proto_serialize(stream)?;
+        self.volume.proto_serialize(stream)?;
+        self.pitch.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let sound_name = String::proto_deserialize(stream)?;
+        let position = NetworkBlockPos::proto_deserialize(stream)?;
+
+        Ok(Self {
+            sound_name,
+            position: Vec3 {
+                x: position.x.into_inner() as f32 / 8.0,
+                y: position.y.into_inner() as i32 as f32 / 8.0,
+                z: position.z.into_inner() as f32 / 8.0,
+            },
+            volume: LE::<f32>::proto_deserialize(stream)?,
+            pitch: LE::<f32>::proto_deserialize(stream)?,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::network_block_pos::NetworkBlockPos;
+use crate::types::player_action_type::PlayerActionType;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct PlayerActionPacket {
+    pub runtime_id: ActorRuntimeID,
+    pub action: PlayerActionType,
+    pub block_position: NetworkBlockPos,
+    pub result_position: NetworkBlockPos,
+    pub face: VAR<i32>,
+}
@@ -0,0 +1,10 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct PlayerHotbarPacket {
+    pub selected_slot: VAR<u32>,
+    pub window_id: u8,
+    /// If the client should select the slot, otherwise only the hotbar gets updated.
+    pub select_slot: bool,
+}
@@ -0,0 +1,103 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use uuid::Uuid;
+
+use crate::types::player_list_entry::PlayerListEntry;
+
+#[derive(Debug, Clone)]
+pub enum PlayerListAction {
+    Add(Vec<PlayerListEntry>),
+    Remove(Vec<Uuid>),
+}
+
+/// Adds players to or removes them from the player list,
+/// players must be added before they can be spawned with an [`AddPlayerPacket`](crate::packets::add_player::AddPlayerPacket).
+#[derive(Debug, Clone)]
+pub struct PlayerListPacket {
+    pub action: PlayerListAction,
+}
+
+const ADD_ID: u8 = 0;
+const REMOVE_ID: u8 = 1;
+
+impl PlayerListPacket {
+    pub fn add(entries: Vec<PlayerListEntry>) -> Self {
+        Self {
+            action: PlayerListAction::Add(entries),
+        }
+    }
+
+    pub fn remove(uuids: Vec<Uuid>) -> Self {
+        Self {
+            action: PlayerListAction::Remove(uuids),
+        }
+    }
+}
+
+impl ProtoCodec for PlayerListPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match &self.action {
+            PlayerListAction::Add(entries) => {
+                ADD_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(entries.len().try_into()?).proto_serialize(stream)?;
+
+                for entry in entries {
+                    entry.proto_serialize(stream)?;
+                }
+
+                for entry in entries {
+                    entry.skin.trusted.proto_serialize(stream)?;
+                }
+            }
+            PlayerListAction::Remove(uuids) => {
+                REMOVE_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(uuids.len().try_into()?).proto_serialize(stream)?;
+
+                for uuid in uuids {
+                    uuid.proto_serialize(stream)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let action = u8::proto_deserialize(stream)?;
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        let action = match action {
+            ADD_ID => {
+                let mut entries = Vec::with_capacity(len.min(1024) as usize);
+                for _ in 0..len {
+                    entries.push(PlayerListEntry::proto_deserialize(stream)?);
+                }
+
+                for entry in &mut entries {
+                    entry.skin.trusted = bool::proto_deserialize(stream)?;
+                }
+
+                PlayerListAction::Add(entries)
+            }
+            REMOVE_ID => {
+                let mut uuids = Vec::with_capacity(len.min(1024) as usize);
+                for _ in 0..len {
+                    uuids.push(Uuid::proto_deserialize(stream)?);
+                }
+
+                PlayerListAction::Remove(uuids)
+            }
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("PlayerListAction"),
+                ))
+            }
+        };
+
+        Ok(Self { action })
+    }
+}
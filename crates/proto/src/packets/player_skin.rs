@@ -0,0 +1,43 @@
+use std::io::Cursor;
+
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use uuid::Uuid;
+
+use crate::types::skin::Skin;
+
+/// Changes the skin of a player, sent by the client when changing the skin in game.
+#[derive(Debug, Clone)]
+pub struct PlayerSkinPacket {
+    pub uuid: Uuid,
+    pub skin: Skin,
+    pub new_skin_name: String,
+    pub old_skin_name: String,
+}
+
+impl ProtoCodec for PlayerSkinPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.uuid.proto_serialize(stream)?;
+        self.skin.proto_serialize(stream)?;
+        self.new_skin_name.proto_serialize(stream)?;
+        self.old_skin_name.proto_serialize(stream)?;
+        self.skin.trusted.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let uuid = Uuid::proto_deserialize(stream)?;
+        let mut skin = Skin::proto_deserialize(stream)?;
+        let new_skin_name = String::proto_deserialize(stream)?;
+        let old_skin_name = String::proto_deserialize(stream)?;
+        skin.trusted = bool::proto_deserialize(stream)?;
+
+        Ok(Self {
+            uuid,
+            skin,
+            new_skin_name,
+            old_skin_name,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Removes an objective with all its scores from all display slots.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct RemoveObjectivePacket {
+    pub objective_name: String,
+}
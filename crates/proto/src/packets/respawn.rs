@@ -0,0 +1,13 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::respawn_state::RespawnState;
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct RespawnPacket {
+    pub position: Vec3<LE<f32>>,
+    pub state: RespawnState,
+    pub runtime_id: ActorRuntimeID,
+}
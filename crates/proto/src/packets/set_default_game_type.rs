@@ -0,0 +1,8 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::world::gamemode::Gamemode;
+
+/// Sets the gamemode of players using [`Gamemode::Default`].
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetDefaultGameTypePacket {
+    pub game_type: Gamemode,
+}
@@ -0,0 +1,42 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::world::difficulty::Difficulty;
+
+#[derive(Debug, Clone)]
+pub struct SetDifficultyPacket {
+    pub difficulty: Difficulty,
+}
+
+impl ProtoCodec for SetDifficultyPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        // Unlike in the start game packet the difficulty is unsigned here
+        let difficulty: u32 = match self.difficulty {
+            Difficulty::Peaceful => 0,
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 3,
+        };
+
+        VAR::<u32>::new(difficulty).proto_serialize(stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let difficulty = match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            0 => Difficulty::Peaceful,
+            1 => Difficulty::Easy,
+            2 => Difficulty::Normal,
+            3 => Difficulty::Hard,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("Difficulty"),
+                ))
+            }
+        };
+
+        Ok(Self { difficulty })
+    }
+}
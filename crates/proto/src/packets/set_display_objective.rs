@@ -0,0 +1,14 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::scoreboard::{ObjectiveSortOrder, ScoreboardDisplaySlot};
+
+/// Shows an objective in a display slot, objectives only exist on the client while displayed.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetDisplayObjectivePacket {
+    pub display_slot: ScoreboardDisplaySlot,
+    pub objective_name: String,
+    pub display_name: String,
+    /// Always `dummy`, the client doesn't track any criteria.
+    pub criteria: String,
+    pub sort_order: ObjectiveSortOrder,
+}
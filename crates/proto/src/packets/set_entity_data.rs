@@ -0,0 +1,18 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::actor_data::ActorDataMap;
+use crate::types::property_sync_data::PropertySyncData;
+
+/// Updates the actor data and synced properties of an actor,
+/// values not contained in the map stay unchanged.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetEntityDataPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub actor_data: ActorDataMap,
+    pub synced_properties: PropertySyncData,
+    /// The server tick the data was changed at, `0` if it isn't known.
+    /// Unlike the tick of `SetEntityMotion`, it is sent by every supported protocol version.
+    pub tick: VAR<u64>,
+}
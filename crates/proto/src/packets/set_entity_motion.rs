@@ -0,0 +1,46 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::info::PROTOCOL_VERSION_1_20_80;
+
+/// Sets the velocity of an actor, e.g. for knockback.
+#[derive(Debug, Clone)]
+pub struct SetEntityMotionPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    pub motion: Vec3<LE<f32>>,
+    /// The server tick the motion applies at, `0` if it isn't known.
+    pub tick: VAR<u64>,
+}
+
+impl ProtoCodec for SetEntityMotionPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.target_runtime_id.proto_serialize(stream)?;
+        self.motion.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.tick.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let target_runtime_id = ActorRuntimeID::proto_deserialize(stream)?;
+        let motion = Vec3::<LE<f32>>::proto_deserialize(stream)?;
+        let tick = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => VAR::<u64>::proto_deserialize(stream)?,
+            false => VAR::new(0),
+        };
+
+        Ok(Self {
+            target_runtime_id,
+            motion,
+            tick,
+        })
+    }
+}
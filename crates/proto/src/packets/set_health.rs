@@ -0,0 +1,9 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Sets the health of the player, the `minecraft:health` attribute
+/// should be used instead since it also updates the health bar.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetHealthPacket {
+    pub health: VAR<i32>,
+}
@@ -0,0 +1,8 @@
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::world::gamemode::Gamemode;
+
+/// Sets the gamemode of the receiving player.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetPlayerGameTypePacket {
+    pub game_type: Gamemode,
+}
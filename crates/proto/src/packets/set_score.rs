@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::scoreboard::ScoreEntry;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SetScoreAction {
+    /// Sets scores, adding entries that don't exist yet.
+    Change,
+    Remove,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetScorePacket {
+    pub action: SetScoreAction,
+    pub entries: Vec<ScoreEntry>,
+}
+
+impl SetScorePacket {
+    const CHANGE_ID: u8 = 0;
+    const REMOVE_ID: u8 = 1;
+}
+
+impl ProtoCodec for SetScorePacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let change = self.action == SetScoreAction::Change;
+
+        match change {
+            true => Self::CHANGE_ID,
+            false => Self::REMOVE_ID,
+        }
+        .proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.entries.len().try_into()?).proto_serialize(stream)?;
+        for entry in &self.entries {
+            entry.serialize(stream, change)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let action = match u8::proto_deserialize(stream)? {
+            Self::CHANGE_ID => SetScoreAction::Change,
+            Self::REMOVE_ID => SetScoreAction::Remove,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("SetScoreAction"),
+                ))
+            }
+        };
+        let change = action == SetScoreAction::Change;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let entries = (0..len)
+            .map(|_| ScoreEntry::deserialize(stream, change))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { action, entries })
+    }
+}
@@ -0,0 +1,87 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::types::scoreboard::ScoreboardIdentityEntry;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScoreboardIdentityAction {
+    Register,
+    Clear,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetScoreboardIdentityPacket {
+    pub action: ScoreboardIdentityAction,
+    pub entries: Vec<ScoreboardIdentityEntry>,
+}
+
+impl SetScoreboardIdentityPacket {
+    const REGISTER_ID: u8 = 0;
+    const CLEAR_ID: u8 = 1;
+}
+
+impl ProtoCodec for SetScoreboardIdentityPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let register = self.action == ScoreboardIdentityAction::Register;
+
+        match register {
+            true => Self::REGISTER_ID,
+            false => Self::CLEAR_ID,
+        }
+        .proto_serialize(stream)?;
+
+        VAR::<u32>::new(self.entries.len().try_into()?).proto_serialize(stream)?;
+        for entry in &self.entries {
+            VAR::new(entry.scoreboard_id).proto_serialize(stream)?;
+
+            if register {
+                match &entry.player_unique_id {
+                    Some(id) => id.proto_serialize(stream)?,
+                    None => {
+                        return Err(ProtoCodecError::FormatMismatch(String::from(
+                            "Registered scoreboard identities need a player unique id",
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let action = match u8::proto_deserialize(stream)? {
+            Self::REGISTER_ID => ScoreboardIdentityAction::Register,
+            Self::CLEAR_ID => ScoreboardIdentityAction::Clear,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ScoreboardIdentityAction"),
+                ))
+            }
+        };
+        let register = action == ScoreboardIdentityAction::Register;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let entries = (0..len)
+            .map(|_| {
+                let scoreboard_id = VAR::<i64>::proto_deserialize(stream)?.into_inner();
+                let player_unique_id = match register {
+                    true => Some(ActorUniqueID::proto_deserialize(stream)?),
+                    false => None,
+                };
+
+                Ok(ScoreboardIdentityEntry {
+                    scoreboard_id,
+                    player_unique_id,
+                })
+            })
+            .collect::<Result<Vec<_>, ProtoCodecError>>()?;
+
+        Ok(Self { action, entries })
+    }
+}
@@ -0,0 +1,8 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Sets the time of day in ticks, a day lasts 24000 ticks.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct SetTimePacket {
+    pub time: VAR<i32>,
+}
@@ -0,0 +1,104 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::text::rawtext::RawText;
+
+use crate::info::PROTOCOL_VERSION_1_21_0;
+use crate::types::title_type::TitleType;
+
+/// Shows a title, subtitle or action bar, or changes the fade times.
+#[derive(Debug, Clone)]
+pub struct SetTitlePacket {
+    pub title_type: TitleType,
+    pub text: String,
+    /// The fade times in ticks, `-1` keeps the current ones.
+    pub fade_in_time: VAR<i32>,
+    pub stay_time: VAR<i32>,
+    pub fade_out_time: VAR<i32>,
+    pub xuid: String,
+    pub platform_online_id: String,
+    /// The text shown to players with profanity filtering enabled.
+    pub filtered_text: String,
+}
+
+impl SetTitlePacket {
+    pub fn new(title_type: TitleType, text: impl Into<String>) -> Self {
+        Self {
+            title_type,
+            text: text.into(),
+            fade_in_time: VAR::new(-1),
+            stay_time: VAR::new(-1),
+            fade_out_time: VAR::new(-1),
+            xuid: String::new(),
+            platform_online_id: String::new(),
+            filtered_text: String::new(),
+        }
+    }
+
+    /// Shows raw text, the title type is switched to its json counterpart.
+    pub fn raw_text(title_type: TitleType, text: &RawText) -> Self {
+        let title_type = match title_type {
+            TitleType::Title => TitleType::TitleJson,
+            TitleType::Subtitle => TitleType::SubtitleJson,
+            TitleType::ActionBar => TitleType::ActionBarJson,
+            other => other,
+        };
+
+        Self::new(title_type, text.to_string())
+    }
+
+    /// Sets the fade times of all following titles.
+    pub fn times(fade_in_time: i32, stay_time: i32, fade_out_time: i32) -> Self {
+        Self {
+            fade_in_time: VAR::new(fade_in_time),
+            stay_time: VAR::new(stay_time),
+            fade_out_time: VAR::new(fade_out_time),
+            ..Self::new(TitleType::Times, "")
+        }
+    }
+}
+
+impl ProtoCodec for SetTitlePacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.title_type.proto_serialize(stream)?;
+        self.text.proto_serialize(stream)?;
+        self.fade_in_time.proto_serialize(stream)?;
+        self.stay_time.proto_serialize(stream)?;
+        self.fade_out_time.proto_serialize(stream)?;
+        self.xuid.proto_serialize(stream)?;
+        self.platform_online_id.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.filtered_text.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let title_type = TitleType::proto_deserialize(stream)?;
+        let text = String::proto_deserialize(stream)?;
+        let fade_in_time = VAR::<i32>::proto_deserialize(stream)?;
+        let stay_time = VAR::<i32>::proto_deserialize(stream)?;
+        let fade_out_time = VAR::<i32>::proto_deserialize(stream)?;
+        let xuid = String::proto_deserialize(stream)?;
+        let platform_online_id = String::proto_deserialize(stream)?;
+        let filtered_text = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => String::proto_deserialize(stream)?,
+            false => String::new(),
+        };
+
+        Ok(Self {
+            title_type,
+            text,
+            fade_in_time,
+            stay_time,
+            fade_out_time,
+            xuid,
+            platform_online_id,
+            filtered_text,
+        })
+    }
+}
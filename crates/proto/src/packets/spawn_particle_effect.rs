@@ -0,0 +1,84 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+use bedrockrs_shared::world::dimension::Dimension;
+
+use crate::types::molang_variables::MolangVariables;
+
+/// Spawns a particle effect by its identifier, like `minecraft:villager_happy`.
+#[derive(Debug, Clone)]
+pub struct SpawnParticleEffectPacket {
+    pub dimension: Dimension,
+    /// The actor the particle is attached to, `-1` for none.
+    pub actor_unique_id: ActorUniqueID,
+    pub position: Vec3<LE<f32>>,
+    pub effect_name: String,
+    pub molang_variables: Option<MolangVariables>,
+}
+
+impl SpawnParticleEffectPacket {
+    pub fn new(
+        dimension: Dimension,
+        position: Vec3<LE<f32>>,
+        effect_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            dimension,
+            actor_unique_id: ActorUniqueID(-1),
+            position,
+            effect_name: effect_name.into(),
+            molang_variables: None,
+        }
+    }
+}
+
+impl ProtoCodec for SpawnParticleEffectPacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        // The dimension is sent as a plain byte here
+        let dimension: u8 = match self.dimension {
+            Dimension::Overworld => 0,
+            Dimension::Nether => 1,
+            Dimension::End => 2,
+        };
+
+        dimension.proto_serialize(stream)?;
+        self.actor_unique_id.proto_serialize(stream)?;
+        self.position.proto_serialize(stream)?;
+        self.effect_name.proto_serialize(stream)?;
+        self.molang_variables
+            .as_ref()
+            .map(|variables| variables.to_json().to_string())
+            .proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let dimension = match u8::proto_deserialize(stream)? {
+            0 => Dimension::Overworld,
+            1 => Dimension::Nether,
+            2 => Dimension::End,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("Dimension"),
+                ))
+            }
+        };
+
+        Ok(Self {
+            dimension,
+            actor_unique_id: ActorUniqueID::proto_deserialize(stream)?,
+            position: Vec3::<LE<f32>>::proto_deserialize(stream)?,
+            effect_name: String::proto_deserialize(stream)?,
+            molang_variables: match Option::<String>::proto_deserialize(stream)? {
+                Some(json) => Some(MolangVariables::parse(&json)?),
+                None => None,
+            },
+        })
+    }
+}
@@ -8,6 +8,8 @@ use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
 use bedrockrs_shared::actor_unique_id::ActorUniqueID;
 use bedrockrs_shared::world::gamemode::Gamemode;
 
+use crate::types::block_property::BlockProperty;
+use crate::types::item_entry::ItemEntry;
 use crate::types::level_settings::LevelSettings;
 use crate::types::network_permissions::NetworkPermissions;
 use crate::types::player_movement_settings::PlayerMovementSettings;
@@ -27,12 +29,10 @@ pub struct StartGamePacket {
     pub movement_settings: PlayerMovementSettings,
     pub current_level_time: LE<u64>,
     pub enchantment_seed: VAR<i32>,
-    // TODO Add real value
     #[len_repr(VAR::<u32>)]
-    pub block_properties: Vec<u8>,
-    // TODO Add real value
+    pub block_properties: Vec<BlockProperty>,
     #[len_repr(VAR::<u32>)]
-    pub items: Vec<u8>,
+    pub items: Vec<ItemEntry>,
     pub multiplayer_correlation_id: String,
     pub enable_item_stack_net_manager: bool,
     pub server_version: String,
@@ -40,6 +40,8 @@ pub struct StartGamePacket {
     pub block_type_registry_checksum: LE<u64>,
     pub world_template_id: Uuid,
     pub enable_clientside_world_generation: bool,
+    /// If the block network IDs are hashes of the block states instead of sequential IDs,
+    /// see `bedrockrs_paletted_storage::block_states::BlockNetworkIdMode`.
     pub use_block_network_id_hashes: bool,
     pub network_permission: NetworkPermissions,
 }
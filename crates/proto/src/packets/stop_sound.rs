@@ -0,0 +1,25 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Stops a sound played with a [`PlaySoundPacket`](crate::packets::play_sound::PlaySoundPacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct StopSoundPacket {
+    pub sound_name: String,
+    /// Stops all sounds, the sound name is ignored.
+    pub stop_all: bool,
+}
+
+impl StopSoundPacket {
+    pub fn new(sound_name: impl Into<String>) -> Self {
+        Self {
+            sound_name: sound_name.into(),
+            stop_all: false,
+        }
+    }
+
+    pub fn all() -> Self {
+        Self {
+            sound_name: String::new(),
+            stop_all: true,
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use crate::info::PROTOCOL_VERSION_1_21_0;
 use crate::types::text_message_data::TextMessageData;
 use bedrockrs_core::int::VAR;
 use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
 use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::text::rawtext::RawText;
 use std::io::Cursor;
 
 #[derive(Debug, Clone)]
@@ -10,6 +13,7 @@ pub struct TextMessagePacket {
     pub localize: bool,
     pub sender_xuid: String,
     pub platform_id: String,
+    /// Only sent to clients on 1.21.0 or newer.
     pub filtered_message: String,
 }
 
@@ -128,7 +132,10 @@ impl ProtoCodec for TextMessagePacket {
 
         self.sender_xuid.proto_serialize(stream)?;
         self.platform_id.proto_serialize(stream)?;
-        self.filtered_message.proto_serialize(stream)?;
+
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.filtered_message.proto_serialize(stream)?;
+        }
 
         Ok(())
     }
@@ -207,9 +214,9 @@ impl ProtoCodec for TextMessagePacket {
                 player_name: String::proto_deserialize(stream)?,
                 message: String::proto_deserialize(stream)?,
             },
-            9 => TextMessageData::TextObjectWhisper(String::proto_deserialize(stream)?),
-            10 => TextMessageData::TextObject(String::proto_deserialize(stream)?),
-            11 => TextMessageData::TextObjectAnnouncement(String::proto_deserialize(stream)?),
+            9 => TextMessageData::TextObjectWhisper(RawText::proto_deserialize(stream)?),
+            10 => TextMessageData::TextObject(RawText::proto_deserialize(stream)?),
+            11 => TextMessageData::TextObjectAnnouncement(RawText::proto_deserialize(stream)?),
             other => {
                 return Err(ProtoCodecError::InvalidEnumID(
                     format!("{other:?}"),
@@ -220,7 +227,10 @@ impl ProtoCodec for TextMessagePacket {
 
         let sender_xuid = String::proto_deserialize(stream)?;
         let platform_id = String::proto_deserialize(stream)?;
-        let filtered_message = String::proto_deserialize(stream)?;
+        let filtered_message = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => String::proto_deserialize(stream)?,
+            false => String::new(),
+        };
 
         Ok(Self {
             message_type,
@@ -0,0 +1,8 @@
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Shows a toast notification at the top of the screen.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ToastRequestPacket {
+    pub title: String,
+    pub content: String,
+}
@@ -0,0 +1,16 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::attribute::AttributeData;
+
+/// Updates attributes of an actor, attributes not contained stay unchanged.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UpdateAttributesPacket {
+    pub target_runtime_id: ActorRuntimeID,
+    #[len_repr(VAR::<u32>)]
+    pub attributes: Vec<AttributeData>,
+    /// The server tick the attributes were changed at, `0` if it isn't known.
+    /// Unlike the tick of `SetEntityMotion`, it is sent by every supported protocol version.
+    pub tick: VAR<u64>,
+}
@@ -0,0 +1,46 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+use bedrockrs_shared::world::gamemode::Gamemode;
+
+use crate::info::PROTOCOL_VERSION_1_20_80;
+
+/// Updates the gamemode of another player, shown in the player list.
+#[derive(Debug, Clone)]
+pub struct UpdatePlayerGameTypePacket {
+    pub game_type: Gamemode,
+    pub player_unique_id: ActorUniqueID,
+    /// The server tick the change applies at, `0` if it isn't known.
+    pub tick: VAR<u64>,
+}
+
+impl ProtoCodec for UpdatePlayerGameTypePacket {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.game_type.proto_serialize(stream)?;
+        self.player_unique_id.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.tick.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let game_type = Gamemode::proto_deserialize(stream)?;
+        let player_unique_id = ActorUniqueID::proto_deserialize(stream)?;
+        let tick = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => VAR::<u64>::proto_deserialize(stream)?,
+            false => VAR::new(0),
+        };
+
+        Ok(Self {
+            game_type,
+            player_unique_id,
+            tick,
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::types::soft_enum_action::SoftEnumAction;
+
+/// Changes the values of a soft enum sent in the
+/// [`AvailableCommandsPacket`](crate::packets::available_commands::AvailableCommandsPacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UpdateSoftEnumPacket {
+    pub enum_name: String,
+    #[len_repr(VAR::<u32>)]
+    pub values: Vec<String>,
+    pub action: SoftEnumAction,
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use bedrockrs_addon::behavior::blocks::AddonBlock;
+use bedrockrs_addon::behavior::menu_category::AddonMenuCategory;
+use bedrockrs_addon::behavior::BehaviorPack;
+use bedrockrs_nbt::NbtTag;
+
+use crate::error::RegistryError;
+use crate::registry::json::json_to_nbt;
+use crate::types::block_property::BlockProperty;
+
+/// The MoLang version custom block definitions are evaluated with.
+pub const BLOCK_MOLANG_VERSION: i32 = 9;
+
+/// Holds all custom blocks that get sent to the client in the
+/// [`StartGamePacket`](crate::packets::start_game::StartGamePacket).
+#[derive(Debug, Clone, Default)]
+pub struct BlockPropertyRegistry {
+    properties: Vec<BlockProperty>,
+}
+
+impl BlockPropertyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, property: BlockProperty) -> Result<(), RegistryError> {
+        if self.get(&property.name).is_some() {
+            return Err(RegistryError::DuplicateEntry(property.name));
+        }
+
+        self.properties.push(property);
+
+        Ok(())
+    }
+
+    pub fn register_addon_block(&mut self, block: &AddonBlock) -> Result<(), RegistryError> {
+        let description = &block.definition.description;
+
+        let mut nbt = HashMap::new();
+
+        nbt.insert(
+            String::from("components"),
+            NbtTag::Compound(
+                block
+                    .definition
+                    .components
+                    .iter()
+                    .map(|(id, value)| {
+                        (format!("{}:{}", id.namespace, id.name), json_to_nbt(value))
+                    })
+                    .collect(),
+            ),
+        );
+
+        if let Some(ref menu_category) = description.menu_category {
+            nbt.insert(
+                String::from("menu_category"),
+                menu_category_nbt(menu_category),
+            );
+        }
+
+        nbt.insert(
+            String::from("molangVersion"),
+            NbtTag::Int32(BLOCK_MOLANG_VERSION),
+        );
+
+        let mut states: Vec<_> = description
+            .states
+            .iter()
+            .flatten()
+            .map(|(name, values)| {
+                let mut state = HashMap::new();
+                state.insert(String::from("name"), NbtTag::String(name.clone()));
+                state.insert(
+                    String::from("enum"),
+                    NbtTag::List(values.0.iter().map(json_to_nbt).collect()),
+                );
+                (name, NbtTag::Compound(state))
+            })
+            .collect();
+        // Sort the states so the definition is the same on every start
+        states.sort_by_key(|(name, _)| *name);
+
+        nbt.insert(
+            String::from("properties"),
+            NbtTag::List(states.into_iter().map(|(_, state)| state).collect()),
+        );
+
+        self.register(BlockProperty {
+            name: format!(
+                "{}:{}",
+                description.identifier.namespace, description.identifier.name
+            ),
+            nbt: NbtTag::Compound(nbt),
+        })
+    }
+
+    pub fn register_behavior_pack(&mut self, pack: &BehaviorPack) -> Result<(), RegistryError> {
+        let mut blocks: Vec<_> = pack.blocks.values().collect();
+        blocks.sort_by(|a, b| {
+            a.definition
+                .description
+                .identifier
+                .cmp(&b.definition.description.identifier)
+        });
+
+        for block in blocks {
+            self.register_addon_block(block)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&BlockProperty> {
+        self.properties.iter().find(|v| v.name == name)
+    }
+
+    /// Returns all registered blocks in the order they were registered.
+    pub fn properties(&self) -> &[BlockProperty] {
+        &self.properties
+    }
+}
+
+fn menu_category_nbt(menu_category: &AddonMenuCategory) -> NbtTag {
+    let mut nbt = HashMap::new();
+
+    nbt.insert(
+        String::from("category"),
+        NbtTag::String(menu_category.category.clone()),
+    );
+    nbt.insert(
+        String::from("group"),
+        NbtTag::String(menu_category.group.clone().unwrap_or_default()),
+    );
+    nbt.insert(
+        String::from("is_hidden_in_commands"),
+        NbtTag::Byte(menu_category.hidden_in_commands.unwrap_or(false) as u8),
+    );
+
+    NbtTag::Compound(nbt)
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use bedrockrs_addon::behavior::items::AddonItem;
+use bedrockrs_addon::behavior::menu_category::AddonMenuCategory;
+use bedrockrs_nbt::NbtTag;
+use serde_json::Value;
+
+use crate::registry::json::json_to_nbt;
+
+/// Converts the components of an addon item into the network component data
+/// that the client expects in the [`ItemComponentPacket`](crate::packets::item_component::ItemComponentPacket).
+///
+/// Components that only change properties of the item (icon, max stack size, hand equipped, ...)
+/// are moved into `item_properties`, known components are brought into their network layout,
+/// all other components are passed on as they are.
+pub fn item_component_nbt(item: &AddonItem, runtime_id: i16) -> NbtTag {
+    let description = &item.definition.description;
+
+    let mut properties = HashMap::new();
+    let mut components = HashMap::new();
+
+    if let Some(ref menu_category) = description.menu_category {
+        insert_menu_category(&mut properties, menu_category);
+    }
+
+    // Sort the components so the data is the same on every start
+    let mut item_components: Vec<_> = item.definition.components.iter().collect();
+    item_components.sort_by_key(|(id, _)| *id);
+
+    for (id, value) in item_components {
+        let name = format!("{}:{}", id.namespace, id.name);
+
+        match name.as_str() {
+            "minecraft:icon" => {
+                let texture = match value {
+                    Value::String(texture) => Some(texture.as_str()),
+                    Value::Object(_) => value
+                        .pointer("/textures/default")
+                        .or_else(|| value.get("texture"))
+                        .and_then(Value::as_str),
+                    _ => None,
+                };
+
+                if let Some(texture) = texture {
+                    let mut textures = HashMap::new();
+                    textures.insert(String::from("default"), NbtTag::String(texture.to_string()));
+
+                    let mut icon = HashMap::new();
+                    icon.insert(String::from("textures"), NbtTag::Compound(textures));
+
+                    properties.insert(name, NbtTag::Compound(icon));
+                }
+            }
+            "minecraft:max_stack_size" => {
+                if let Some(v) = value_or_field(value).and_then(Value::as_i64) {
+                    properties.insert(String::from("max_stack_size"), NbtTag::Int32(v as i32));
+                }
+            }
+            "minecraft:hand_equipped" => {
+                insert_bool_property(&mut properties, "hand_equipped", value);
+            }
+            "minecraft:allow_off_hand" => {
+                insert_bool_property(&mut properties, "allow_off_hand", value);
+            }
+            "minecraft:glint" | "minecraft:foil" => {
+                insert_bool_property(&mut properties, "foil", value);
+            }
+            "minecraft:stacked_by_data" => {
+                insert_bool_property(&mut properties, "stacked_by_data", value);
+            }
+            "minecraft:can_destroy_in_creative" => {
+                insert_bool_property(&mut properties, "can_destroy_in_creative", value);
+            }
+            "minecraft:liquid_clipped" => {
+                insert_bool_property(&mut properties, "liquid_clipped", value);
+            }
+            "minecraft:damage" => {
+                if let Some(v) = value_or_field(value).and_then(Value::as_i64) {
+                    properties.insert(String::from("damage"), NbtTag::Int32(v as i32));
+                }
+            }
+            "minecraft:use_animation" => {
+                if let Some(animation) = value_or_field(value).and_then(Value::as_str) {
+                    properties.insert(
+                        String::from("use_animation"),
+                        NbtTag::Int32(use_animation_id(animation)),
+                    );
+                }
+            }
+            "minecraft:use_modifiers" => {
+                if let Some(duration) = value.get("use_duration").and_then(Value::as_f64) {
+                    // The duration is given in seconds but sent in ticks
+                    properties.insert(
+                        String::from("use_duration"),
+                        NbtTag::Int32((duration * 20.0) as i32),
+                    );
+                }
+
+                components.insert(name, json_to_nbt(value));
+            }
+            "minecraft:enchantable" => {
+                if let Some(slot) = value.get("slot").and_then(Value::as_str) {
+                    properties.insert(
+                        String::from("enchantable_slot"),
+                        NbtTag::String(slot.to_string()),
+                    );
+                }
+                if let Some(v) = value.get("value").and_then(Value::as_i64) {
+                    properties.insert(String::from("enchantable_value"), NbtTag::Int32(v as i32));
+                }
+            }
+            "minecraft:display_name" => {
+                if let Some(display_name) = value_or_field(value).and_then(Value::as_str) {
+                    let mut v = HashMap::new();
+                    v.insert(
+                        String::from("value"),
+                        NbtTag::String(display_name.to_string()),
+                    );
+
+                    components.insert(name, NbtTag::Compound(v));
+                }
+            }
+            "minecraft:food" => {
+                let mut food = HashMap::new();
+
+                food.insert(
+                    String::from("nutrition"),
+                    NbtTag::Int32(
+                        value
+                            .get("nutrition")
+                            .and_then(Value::as_i64)
+                            .unwrap_or_default() as i32,
+                    ),
+                );
+                food.insert(
+                    String::from("saturation_modifier"),
+                    NbtTag::Float32(
+                        value
+                            .get("saturation_modifier")
+                            .and_then(Value::as_f64)
+                            .unwrap_or_default() as f32,
+                    ),
+                );
+                food.insert(
+                    String::from("can_always_eat"),
+                    NbtTag::Byte(
+                        value
+                            .get("can_always_eat")
+                            .and_then(Value::as_bool)
+                            .unwrap_or(false) as u8,
+                    ),
+                );
+
+                if let Some(Value::String(using_converts_to)) = value.get("using_converts_to") {
+                    food.insert(
+                        String::from("using_converts_to"),
+                        NbtTag::String(using_converts_to.clone()),
+                    );
+                }
+
+                // Food needs to be eaten, unless an animation was set explicitly
+                properties
+                    .entry(String::from("use_animation"))
+                    .or_insert(NbtTag::Int32(use_animation_id("eat")));
+                properties
+                    .entry(String::from("use_duration"))
+                    .or_insert(NbtTag::Int32(32));
+
+                components.insert(name, NbtTag::Compound(food));
+            }
+            "minecraft:wearable" => {
+                let mut wearable = HashMap::new();
+
+                if let Some(slot) = value.get("slot").and_then(Value::as_str) {
+                    wearable.insert(String::from("slot"), NbtTag::String(slot.to_string()));
+                }
+                wearable.insert(
+                    String::from("protection"),
+                    NbtTag::Int32(
+                        value
+                            .get("protection")
+                            .and_then(Value::as_i64)
+                            .unwrap_or_default() as i32,
+                    ),
+                );
+
+                // Armor doesn't stack
+                properties
+                    .entry(String::from("max_stack_size"))
+                    .or_insert(NbtTag::Int32(1));
+
+                components.insert(name, NbtTag::Compound(wearable));
+            }
+            _ => {
+                components.insert(name, json_to_nbt(value));
+            }
+        }
+    }
+
+    components.insert(
+        String::from("item_properties"),
+        NbtTag::Compound(properties),
+    );
+
+    let mut nbt = HashMap::new();
+    nbt.insert(String::from("components"), NbtTag::Compound(components));
+    nbt.insert(String::from("id"), NbtTag::Int32(runtime_id as i32));
+    nbt.insert(
+        String::from("name"),
+        NbtTag::String(format!(
+            "{}:{}",
+            description.identifier.namespace, description.identifier.name
+        )),
+    );
+
+    NbtTag::Compound(nbt)
+}
+
+/// The network ID of an item use animation.
+pub fn use_animation_id(animation: &str) -> i32 {
+    match animation {
+        "eat" => 1,
+        "drink" => 2,
+        "block" => 3,
+        "bow" => 4,
+        "camera" => 5,
+        "spear" => 6,
+        "crossbow" => 9,
+        "spyglass" => 10,
+        "brush" => 12,
+        _ => 0,
+    }
+}
+
+/// The network ID of an inventory menu category.
+pub fn creative_category_id(category: &str) -> i32 {
+    match category {
+        "construction" => 1,
+        "nature" => 2,
+        "equipment" => 3,
+        "items" => 4,
+        _ => 0,
+    }
+}
+
+fn insert_menu_category(
+    properties: &mut HashMap<String, NbtTag>,
+    menu_category: &AddonMenuCategory,
+) {
+    properties.insert(
+        String::from("creative_category"),
+        NbtTag::Int32(creative_category_id(&menu_category.category)),
+    );
+
+    if let Some(ref group) = menu_category.group {
+        properties.insert(
+            String::from("creative_group"),
+            NbtTag::String(group.clone()),
+        );
+    }
+}
+
+/// Components can either be given as a plain value or as an object holding a `value` field.
+fn value_or_field(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Object(_) => value.get("value"),
+        _ => Some(value),
+    }
+}
+
+fn insert_bool_property(properties: &mut HashMap<String, NbtTag>, name: &str, value: &Value) {
+    if let Some(v) = value_or_field(value).and_then(Value::as_bool) {
+        properties.insert(name.to_string(), NbtTag::Byte(v as u8));
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use bedrockrs_addon::behavior::items::AddonItem;
+use bedrockrs_addon::behavior::BehaviorPack;
+use bedrockrs_nbt::NbtTag;
+use serde_json::Value;
+
+use crate::error::RegistryError;
+use crate::packets::item_component::{ItemComponentEntry, ItemComponentPacket};
+use crate::registry::item_components::item_component_nbt;
+use crate::types::item_entry::{ItemEntry, ItemVersion};
+
+/// Maps item identifiers to their runtime IDs, holds the vanilla items
+/// and all custom items that get sent to the client in the
+/// [`StartGamePacket`](crate::packets::start_game::StartGamePacket).
+#[derive(Debug, Clone, Default)]
+pub struct ItemRegistry {
+    entries: Vec<ItemEntry>,
+    by_name: HashMap<String, usize>,
+    by_runtime_id: HashMap<i16, usize>,
+}
+
+impl ItemRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the vanilla item table from a json file in the format:
+    /// ```json
+    /// {
+    ///     "minecraft:stone": { "runtime_id": 1, "component_based": false },
+    ///     ...
+    /// }
+    /// ```
+    pub fn load_vanilla(path: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+
+        let table = fs::read_to_string(path)
+            .map_err(|e| RegistryError::IOError(Arc::new(e), path.to_path_buf()))?;
+        let table: HashMap<String, Value> = serde_json::from_str(&table)
+            .map_err(|e| RegistryError::JsonError(Arc::new(e), path.to_path_buf()))?;
+
+        let mut table: Vec<_> = table.into_iter().collect();
+        table.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut registry = Self::new();
+
+        for (name, entry) in table {
+            let runtime_id = entry
+                .get("runtime_id")
+                .and_then(Value::as_i64)
+                .and_then(|v| i16::try_from(v).ok())
+                .ok_or_else(|| {
+                    RegistryError::FormatError(
+                        format!("Missing or invalid runtime_id for {name}"),
+                        path.to_path_buf(),
+                    )
+                })?;
+            let component_based = entry
+                .get("component_based")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+
+            registry.register(ItemEntry::new(name, runtime_id, component_based))?;
+        }
+
+        Ok(registry)
+    }
+
+    pub fn register(&mut self, entry: ItemEntry) -> Result<(), RegistryError> {
+        if self.by_name.contains_key(&entry.name) {
+            return Err(RegistryError::DuplicateEntry(entry.name));
+        }
+        if self.by_runtime_id.contains_key(&entry.runtime_id) {
+            return Err(RegistryError::DuplicateEntry(format!(
+                "{} (runtime id {})",
+                entry.name, entry.runtime_id
+            )));
+        }
+
+        self.by_name.insert(entry.name.clone(), self.entries.len());
+        self.by_runtime_id
+            .insert(entry.runtime_id, self.entries.len());
+        self.entries.push(entry);
+
+        Ok(())
+    }
+
+    /// Registers a custom item with the next free runtime ID and returns that ID.
+    pub fn register_addon_item(&mut self, item: &AddonItem) -> Result<i16, RegistryError> {
+        let description = &item.definition.description;
+        let name = format!(
+            "{}:{}",
+            description.identifier.namespace, description.identifier.name
+        );
+
+        let runtime_id = self.next_runtime_id()?;
+
+        let nbt = item_component_nbt(item, runtime_id);
+
+        self.register(ItemEntry {
+            name,
+            runtime_id,
+            component_based: true,
+            version: ItemVersion::DataDriven,
+            nbt,
+        })?;
+
+        Ok(runtime_id)
+    }
+
+    /// Registers all items of a behavior pack, sorted by their identifier
+    /// so the runtime IDs are the same on every start.
+    pub fn register_behavior_pack(&mut self, pack: &BehaviorPack) -> Result<(), RegistryError> {
+        let mut items: Vec<_> = pack.items.values().collect();
+        items.sort_by(|a, b| {
+            a.definition
+                .description
+                .identifier
+                .cmp(&b.definition.description.identifier)
+        });
+
+        for item in items {
+            self.register_addon_item(item)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ItemEntry> {
+        self.by_name.get(name).map(|i| &self.entries[*i])
+    }
+
+    pub fn get_by_runtime_id(&self, runtime_id: i16) -> Option<&ItemEntry> {
+        self.by_runtime_id
+            .get(&runtime_id)
+            .map(|i| &self.entries[*i])
+    }
+
+    /// Creates the packet containing the component data of all component based items,
+    /// it needs to be sent right after the [`StartGamePacket`](crate::packets::start_game::StartGamePacket).
+    pub fn item_component_packet(&self) -> ItemComponentPacket {
+        ItemComponentPacket {
+            items: self
+                .entries
+                .iter()
+                // Vanilla items don't come with component data
+                .filter(|entry| {
+                    entry.component_based
+                        && !matches!(&entry.nbt, NbtTag::Compound(map) if map.is_empty())
+                })
+                .map(|entry| ItemComponentEntry {
+                    name: entry.name.clone(),
+                    nbt: entry.nbt.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns all registered items in the order they were registered.
+    pub fn entries(&self) -> &[ItemEntry] {
+        &self.entries
+    }
+
+    fn next_runtime_id(&self) -> Result<i16, RegistryError> {
+        match self.by_runtime_id.keys().max() {
+            None => Ok(1),
+            Some(max) => max.checked_add(1).ok_or(RegistryError::RuntimeIdsExhausted),
+        }
+    }
+}
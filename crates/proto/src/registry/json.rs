@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use bedrockrs_nbt::NbtTag;
+use serde_json::Value;
+
+/// Converts a json value from an addon definition into its nbt counterpart.
+///
+/// Integers are stored as [`NbtTag::Int32`] when they fit, otherwise as [`NbtTag::Int64`],
+/// floats are always stored as [`NbtTag::Float32`].
+pub fn json_to_nbt(value: &Value) -> NbtTag {
+    match value {
+        Value::Null => NbtTag::Empty,
+        Value::Bool(v) => NbtTag::Byte(*v as u8),
+        Value::Number(v) => {
+            if let Some(v) = v.as_i64() {
+                match i32::try_from(v) {
+                    Ok(v) => NbtTag::Int32(v),
+                    Err(_) => NbtTag::Int64(v),
+                }
+            } else {
+                NbtTag::Float32(v.as_f64().unwrap_or_default() as f32)
+            }
+        }
+        Value::String(v) => NbtTag::String(v.clone()),
+        Value::Array(v) => NbtTag::List(v.iter().map(json_to_nbt).collect()),
+        Value::Object(v) => NbtTag::Compound(
+            v.iter()
+                .map(|(key, value)| (key.clone(), json_to_nbt(value)))
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
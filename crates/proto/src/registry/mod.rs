@@ -0,0 +1,5 @@
+pub mod blocks;
+pub mod item_components;
+pub mod items;
+pub mod json;
+pub mod recipes;
@@ -0,0 +1,522 @@
+use bedrockrs_addon::behavior::recipes::{
+    AddonRecipe, AddonRecipeDefinition, AddonRecipeItem, AddonRecipeResult,
+};
+use bedrockrs_addon::behavior::BehaviorPack;
+use bedrockrs_core::int::VAR;
+use uuid::Uuid;
+
+use crate::error::RegistryError;
+use crate::inventory::model::InventoryModel;
+use crate::packets::crafting_data::CraftingDataPacket;
+use crate::registry::items::ItemRegistry;
+use crate::types::item_descriptor::{ItemDescriptor, ItemDescriptorCount};
+use crate::types::item_stack::ItemStackDescriptor;
+use crate::types::recipe::{
+    FurnaceDataRecipe, FurnaceRecipe, MaterialReducerRecipe, PotionContainerChangeRecipe,
+    PotionRecipe, Recipe, RecipeUnlockRequirement, ShapedRecipe, ShapelessRecipe,
+    SmithingTransformRecipe, SmithingTrimRecipe,
+};
+
+/// Item metadata that matches any metadata.
+pub const ANY_METADATA: i16 = i16::MAX;
+
+/// Prefix of the potion types used by brewing recipes, e.g. `minecraft:potion_type:awkward`.
+const POTION_TYPE_PREFIX: &str = "minecraft:potion_type:";
+
+/// The names of all potion types, indexed by their potion item metadata.
+const POTION_TYPES: [&str; 47] = [
+    "water",
+    "mundane",
+    "long_mundane",
+    "thick",
+    "awkward",
+    "nightvision",
+    "long_nightvision",
+    "invisibility",
+    "long_invisibility",
+    "leaping",
+    "long_leaping",
+    "strong_leaping",
+    "fire_resistance",
+    "long_fire_resistance",
+    "swiftness",
+    "long_swiftness",
+    "strong_swiftness",
+    "slowness",
+    "long_slowness",
+    "water_breathing",
+    "long_water_breathing",
+    "healing",
+    "strong_healing",
+    "harming",
+    "strong_harming",
+    "poison",
+    "long_poison",
+    "strong_poison",
+    "regeneration",
+    "long_regeneration",
+    "strong_regeneration",
+    "strength",
+    "long_strength",
+    "strong_strength",
+    "weakness",
+    "long_weakness",
+    "wither",
+    "turtle_master",
+    "long_turtle_master",
+    "strong_turtle_master",
+    "slow_falling",
+    "long_slow_falling",
+    "strong_slowness",
+    "wind_charged",
+    "weaving",
+    "oozing",
+    "infested",
+];
+
+/// Holds all recipes that get sent to the client in the [`CraftingDataPacket`].
+#[derive(Debug, Clone)]
+pub struct RecipeRegistry {
+    recipes: Vec<Recipe>,
+    potion_recipes: Vec<PotionRecipe>,
+    potion_container_change_recipes: Vec<PotionContainerChangeRecipe>,
+    material_reducers: Vec<MaterialReducerRecipe>,
+    next_network_id: u32,
+}
+
+impl Default for RecipeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecipeRegistry {
+    pub fn new() -> Self {
+        Self {
+            recipes: vec![],
+            potion_recipes: vec![],
+            potion_container_change_recipes: vec![],
+            material_reducers: vec![],
+            // The network ID 0 is invalid
+            next_network_id: 1,
+        }
+    }
+
+    /// Returns a network ID for a new recipe.
+    pub fn allocate_network_id(&mut self) -> u32 {
+        let id = self.next_network_id;
+        self.next_network_id += 1;
+        id
+    }
+
+    pub fn register(&mut self, recipe: Recipe) -> Result<(), RegistryError> {
+        if let Some(network_id) = recipe.network_id() {
+            if self.recipe(network_id).is_some() {
+                return Err(RegistryError::DuplicateEntry(format!(
+                    "recipe network id {network_id}"
+                )));
+            }
+        }
+
+        self.recipes.push(recipe);
+
+        Ok(())
+    }
+
+    pub fn register_potion_recipe(&mut self, recipe: PotionRecipe) {
+        self.potion_recipes.push(recipe);
+    }
+
+    pub fn register_potion_container_change_recipe(&mut self, recipe: PotionContainerChangeRecipe) {
+        self.potion_container_change_recipes.push(recipe);
+    }
+
+    pub fn register_material_reducer(&mut self, recipe: MaterialReducerRecipe) {
+        self.material_reducers.push(recipe);
+    }
+
+    /// Registers a recipe of a behavior pack, the items are resolved via the item registry.
+    /// Recipes usable in multiple blocks are registered once per block.
+    pub fn register_addon_recipe(
+        &mut self,
+        recipe: &AddonRecipe,
+        items: &ItemRegistry,
+    ) -> Result<(), RegistryError> {
+        match &recipe.definition {
+            AddonRecipeDefinition::Shaped(shaped) => {
+                let recipe_id = identifier(&shaped.description.identifier);
+
+                let height = shaped.pattern.len();
+                let width = shaped
+                    .pattern
+                    .iter()
+                    .map(|row| row.chars().count())
+                    .max()
+                    .unwrap_or(0);
+
+                let mut input = Vec::with_capacity(width * height);
+                for row in &shaped.pattern {
+                    let mut row: Vec<char> = row.chars().collect();
+                    row.resize(width, ' ');
+
+                    for key in row {
+                        input.push(match key {
+                            ' ' => ItemDescriptorCount {
+                                descriptor: ItemDescriptor::Invalid,
+                                count: VAR::new(0),
+                            },
+                            key => ingredient(
+                                shaped.key.get(&key).ok_or_else(|| {
+                                    RegistryError::InvalidEntry(format!(
+                                        "Recipe {recipe_id} has no key for {key:?}"
+                                    ))
+                                })?,
+                                items,
+                            )?,
+                        });
+                    }
+                }
+
+                let output = outputs(&shaped.result, items)?;
+
+                for block in &shaped.tags {
+                    let network_id = self.allocate_network_id();
+
+                    self.register(Recipe::Shaped(ShapedRecipe {
+                        recipe_id: recipe_id.clone(),
+                        width: width as i32,
+                        height: height as i32,
+                        input: input.clone(),
+                        output: output.clone(),
+                        uuid: recipe_uuid(&recipe_id, block),
+                        block: block.clone(),
+                        priority: shaped.priority.unwrap_or(0),
+                        assume_symmetry: shaped.assume_symmetry.unwrap_or(true),
+                        unlock_requirement: RecipeUnlockRequirement::AlwaysUnlocked,
+                        network_id,
+                    }))?;
+                }
+            }
+            AddonRecipeDefinition::Shapeless(shapeless) => {
+                let recipe_id = identifier(&shapeless.description.identifier);
+
+                let input = shapeless
+                    .ingredients
+                    .iter()
+                    .map(|v| ingredient(v, items))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let output = outputs(&shapeless.result, items)?;
+
+                for block in &shapeless.tags {
+                    let network_id = self.allocate_network_id();
+
+                    self.register(Recipe::Shapeless(ShapelessRecipe {
+                        recipe_id: recipe_id.clone(),
+                        input: input.clone(),
+                        output: output.clone(),
+                        uuid: recipe_uuid(&recipe_id, block),
+                        block: block.clone(),
+                        priority: shapeless.priority.unwrap_or(0),
+                        unlock_requirement: RecipeUnlockRequirement::AlwaysUnlocked,
+                        network_id,
+                    }))?;
+                }
+            }
+            AddonRecipeDefinition::Furnace(furnace) => {
+                let (name, metadata) = match &furnace.input {
+                    AddonRecipeItem::Identifier(name) => (name, None),
+                    AddonRecipeItem::Item { item, data, .. } => (item, *data),
+                    AddonRecipeItem::Tag { tag, .. } => {
+                        return Err(RegistryError::InvalidEntry(format!(
+                            "Furnace recipe {} can't use the item tag {tag} as input",
+                            identifier(&furnace.description.identifier)
+                        )))
+                    }
+                };
+                let input_network_id = runtime_id(name, items)? as i32;
+                let output = output(&furnace.output, items)?;
+
+                for block in &furnace.tags {
+                    self.register(match metadata {
+                        None => Recipe::Furnace(FurnaceRecipe {
+                            input_network_id: VAR::new(input_network_id),
+                            output: output.clone(),
+                            block: block.clone(),
+                        }),
+                        Some(metadata) => Recipe::FurnaceData(FurnaceDataRecipe {
+                            input_network_id: VAR::new(input_network_id),
+                            input_metadata: VAR::new(metadata as i32),
+                            output: output.clone(),
+                            block: block.clone(),
+                        }),
+                    })?;
+                }
+            }
+            AddonRecipeDefinition::BrewingMix(brewing) => {
+                let (input_potion_id, input_potion_metadata) = brewing_item(&brewing.input, items)?;
+                let (reagent_item_id, reagent_item_metadata) =
+                    brewing_item(&brewing.reagent, items)?;
+                let (output_potion_id, output_potion_metadata) =
+                    brewing_item(&brewing.output, items)?;
+
+                self.register_potion_recipe(PotionRecipe {
+                    input_potion_id: VAR::new(input_potion_id as i32),
+                    input_potion_metadata: VAR::new(input_potion_metadata as i32),
+                    reagent_item_id: VAR::new(reagent_item_id as i32),
+                    reagent_item_metadata: VAR::new(reagent_item_metadata as i32),
+                    output_potion_id: VAR::new(output_potion_id as i32),
+                    output_potion_metadata: VAR::new(output_potion_metadata as i32),
+                });
+            }
+            AddonRecipeDefinition::BrewingContainer(brewing) => {
+                // Container changes keep the potion type, so only the items matter
+                let (input_item_id, _) = brewing_item(&brewing.input, items)?;
+                let (reagent_item_id, _) = brewing_item(&brewing.reagent, items)?;
+                let (output_item_id, _) = brewing_item(&brewing.output, items)?;
+
+                self.register_potion_container_change_recipe(PotionContainerChangeRecipe {
+                    input_item_id: VAR::new(input_item_id as i32),
+                    reagent_item_id: VAR::new(reagent_item_id as i32),
+                    output_item_id: VAR::new(output_item_id as i32),
+                });
+            }
+            AddonRecipeDefinition::SmithingTransform(smithing) => {
+                let recipe_id = identifier(&smithing.description.identifier);
+
+                let template = ingredient(
+                    &AddonRecipeItem::Identifier(smithing.template.clone()),
+                    items,
+                )?;
+                let base = ingredient(&AddonRecipeItem::Identifier(smithing.base.clone()), items)?;
+                let addition = ingredient(
+                    &AddonRecipeItem::Identifier(smithing.addition.clone()),
+                    items,
+                )?;
+                let result = output(&AddonRecipeItem::Identifier(smithing.result.clone()), items)?;
+
+                for block in &smithing.tags {
+                    let network_id = self.allocate_network_id();
+
+                    self.register(Recipe::SmithingTransform(SmithingTransformRecipe {
+                        recipe_id: recipe_id.clone(),
+                        template: template.clone(),
+                        base: base.clone(),
+                        addition: addition.clone(),
+                        result: result.clone(),
+                        block: block.clone(),
+                        network_id,
+                    }))?;
+                }
+            }
+            AddonRecipeDefinition::SmithingTrim(smithing) => {
+                let recipe_id = identifier(&smithing.description.identifier);
+
+                let template = ingredient(&smithing.template, items)?;
+                let base = ingredient(&smithing.base, items)?;
+                let addition = ingredient(&smithing.addition, items)?;
+
+                for block in &smithing.tags {
+                    let network_id = self.allocate_network_id();
+
+                    self.register(Recipe::SmithingTrim(SmithingTrimRecipe {
+                        recipe_id: recipe_id.clone(),
+                        template: template.clone(),
+                        base: base.clone(),
+                        addition: addition.clone(),
+                        block: block.clone(),
+                        network_id,
+                    }))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers all recipes of a behavior pack, sorted by their path
+    /// so the network IDs are the same on every start.
+    pub fn register_behavior_pack(
+        &mut self,
+        pack: &BehaviorPack,
+        items: &ItemRegistry,
+    ) -> Result<(), RegistryError> {
+        let mut recipes: Vec<_> = pack.recipes.iter().collect();
+        recipes.sort_by_key(|(path, _)| *path);
+
+        for (_, recipe) in recipes {
+            self.register_addon_recipe(recipe, items)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn recipe(&self, network_id: u32) -> Option<&Recipe> {
+        self.recipes
+            .iter()
+            .find(|recipe| recipe.network_id() == Some(network_id))
+    }
+
+    /// Returns all registered recipes in the order they were registered.
+    pub fn recipes(&self) -> &[Recipe] {
+        &self.recipes
+    }
+
+    /// Returns the items a recipe crafts.
+    pub fn outputs(&self, network_id: u32) -> Option<Vec<ItemStackDescriptor>> {
+        Some(match self.recipe(network_id)? {
+            Recipe::Shapeless(recipe)
+            | Recipe::ShulkerBox(recipe)
+            | Recipe::ShapelessChemistry(recipe) => recipe.output.clone(),
+            Recipe::Shaped(recipe) | Recipe::ShapedChemistry(recipe) => recipe.output.clone(),
+            Recipe::SmithingTransform(recipe) => vec![recipe.result.clone()],
+            _ => return None,
+        })
+    }
+
+    /// Returns the ingredients of a recipe.
+    pub fn ingredients(&self, network_id: u32) -> Option<Vec<ItemDescriptorCount>> {
+        Some(match self.recipe(network_id)? {
+            Recipe::Shapeless(recipe)
+            | Recipe::ShulkerBox(recipe)
+            | Recipe::ShapelessChemistry(recipe) => recipe.input.clone(),
+            Recipe::Shaped(recipe) | Recipe::ShapedChemistry(recipe) => recipe.input.clone(),
+            Recipe::SmithingTransform(recipe) => vec![
+                recipe.template.clone(),
+                recipe.base.clone(),
+                recipe.addition.clone(),
+            ],
+            _ => return None,
+        })
+    }
+
+    /// Makes all crafting recipes known to an inventory model,
+    /// so their crafting requests can be applied.
+    pub fn register_model_recipes(&self, model: &mut InventoryModel) {
+        for recipe in &self.recipes {
+            if let Some(network_id) = recipe.network_id() {
+                if let (Some(ingredients), Some(outputs)) =
+                    (self.ingredients(network_id), self.outputs(network_id))
+                {
+                    model.add_recipe(network_id, ingredients, outputs);
+                }
+            }
+        }
+    }
+
+    pub fn crafting_data_packet(&self) -> CraftingDataPacket {
+        CraftingDataPacket {
+            recipes: self.recipes.clone(),
+            potion_recipes: self.potion_recipes.clone(),
+            potion_container_change_recipes: self.potion_container_change_recipes.clone(),
+            material_reducers: self.material_reducers.clone(),
+            clear_recipes: true,
+        }
+    }
+}
+
+fn identifier(id: &bedrockrs_addon::identifier::AddonIdentifier) -> String {
+    format!("{}:{}", id.namespace, id.name)
+}
+
+/// Derives the UUID of a recipe from its identifier and block,
+/// so it stays the same on every start.
+fn recipe_uuid(recipe_id: &str, block: &str) -> Uuid {
+    Uuid::new_v5(
+        &Uuid::NAMESPACE_OID,
+        format!("{recipe_id}/{block}").as_bytes(),
+    )
+}
+
+/// Returns the runtime ID of an item, identifiers without a namespace
+/// refer to vanilla items.
+fn runtime_id(name: &str, items: &ItemRegistry) -> Result<i16, RegistryError> {
+    let name = match name.contains(':') {
+        true => name.to_string(),
+        false => format!("minecraft:{name}"),
+    };
+
+    items
+        .get(&name)
+        .map(|entry| entry.runtime_id)
+        .ok_or(RegistryError::UnknownEntry(name))
+}
+
+/// Returns the runtime ID and metadata of an item used by a brewing recipe,
+/// potion types are resolved to the potion item with the potion type as metadata.
+fn brewing_item(name: &str, items: &ItemRegistry) -> Result<(i16, i16), RegistryError> {
+    let Some(potion_type) = name.strip_prefix(POTION_TYPE_PREFIX) else {
+        return Ok((runtime_id(name, items)?, 0));
+    };
+
+    let metadata = POTION_TYPES
+        .iter()
+        .position(|name| *name == potion_type)
+        .ok_or_else(|| RegistryError::UnknownEntry(String::from(name)))?;
+
+    Ok((runtime_id("minecraft:potion", items)?, metadata as i16))
+}
+
+fn ingredient(
+    item: &AddonRecipeItem,
+    items: &ItemRegistry,
+) -> Result<ItemDescriptorCount, RegistryError> {
+    let (descriptor, count) = match item {
+        AddonRecipeItem::Identifier(name) => (
+            ItemDescriptor::Default {
+                network_id: runtime_id(name, items)?,
+                metadata: ANY_METADATA,
+            },
+            None,
+        ),
+        AddonRecipeItem::Item { item, data, count } => (
+            ItemDescriptor::Default {
+                network_id: runtime_id(item, items)?,
+                metadata: data.unwrap_or(ANY_METADATA),
+            },
+            *count,
+        ),
+        AddonRecipeItem::Tag { tag, count } => {
+            (ItemDescriptor::ItemTag { tag: tag.clone() }, *count)
+        }
+    };
+
+    Ok(ItemDescriptorCount {
+        descriptor,
+        count: VAR::new(count.unwrap_or(1) as i32),
+    })
+}
+
+fn output(
+    item: &AddonRecipeItem,
+    items: &ItemRegistry,
+) -> Result<ItemStackDescriptor, RegistryError> {
+    let (name, metadata, count) = match item {
+        AddonRecipeItem::Identifier(name) => (name, None, None),
+        AddonRecipeItem::Item { item, data, count } => (item, *data, *count),
+        AddonRecipeItem::Tag { tag, .. } => {
+            return Err(RegistryError::InvalidEntry(format!(
+                "The item tag {tag} can't be used as a recipe output"
+            )))
+        }
+    };
+
+    Ok(ItemStackDescriptor {
+        network_id: runtime_id(name, items)? as i32,
+        count: count.unwrap_or(1),
+        metadata: metadata.unwrap_or(0).max(0) as u32,
+        block_runtime_id: 0,
+        extra_data: Default::default(),
+    })
+}
+
+fn outputs(
+    result: &AddonRecipeResult,
+    items: &ItemRegistry,
+) -> Result<Vec<ItemStackDescriptor>, RegistryError> {
+    match result {
+        AddonRecipeResult::Single(item) => Ok(vec![output(item, items)?]),
+        AddonRecipeResult::Multiple(results) => {
+            results.iter().map(|item| output(item, items)).collect()
+        }
+    }
+}
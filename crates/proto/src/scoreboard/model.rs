@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+use crate::connection::ConnectionShard;
+use crate::error::ScoreboardError;
+use crate::gamepacket::GamePacket;
+use crate::packets::remove_objective::RemoveObjectivePacket;
+use crate::packets::set_display_objective::SetDisplayObjectivePacket;
+use crate::packets::set_score::{SetScoreAction, SetScorePacket};
+use crate::packets::set_scoreboard_identity::{
+    ScoreboardIdentityAction, SetScoreboardIdentityPacket,
+};
+use crate::types::scoreboard::{
+    ObjectiveSortOrder, ScoreEntry, ScoreboardDisplaySlot, ScoreboardIdentity,
+    ScoreboardIdentityEntry,
+};
+
+/// Who a score belongs to, players and entities are identified by their unique ID.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ScoreHolder {
+    Player(i64),
+    Entity(i64),
+    FakePlayer(String),
+}
+
+impl ScoreHolder {
+    fn identity(&self) -> ScoreboardIdentity {
+        match self {
+            ScoreHolder::Player(id) => ScoreboardIdentity::Player(ActorUniqueID(*id)),
+            ScoreHolder::Entity(id) => ScoreboardIdentity::Entity(ActorUniqueID(*id)),
+            ScoreHolder::FakePlayer(name) => ScoreboardIdentity::FakePlayer(name.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Objective {
+    pub name: String,
+    pub display_name: String,
+    pub sort_order: ObjectiveSortOrder,
+    scores: HashMap<ScoreHolder, i32>,
+}
+
+impl Objective {
+    pub fn score(&self, holder: &ScoreHolder) -> Option<i32> {
+        self.scores.get(holder).copied()
+    }
+
+    pub fn scores(&self) -> impl Iterator<Item = (&ScoreHolder, i32)> {
+        self.scores.iter().map(|(holder, score)| (holder, *score))
+    }
+}
+
+/// The scoreboard of a single player, mirroring what the client knows
+/// so only changes have to be sent.
+#[derive(Debug, Clone)]
+pub struct Scoreboard {
+    objectives: HashMap<String, Objective>,
+    display_slots: HashMap<ScoreboardDisplaySlot, String>,
+    scoreboard_ids: HashMap<ScoreHolder, i64>,
+    next_scoreboard_id: i64,
+}
+
+impl Default for Scoreboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scoreboard {
+    pub fn new() -> Self {
+        Self {
+            objectives: HashMap::new(),
+            display_slots: HashMap::new(),
+            scoreboard_ids: HashMap::new(),
+            next_scoreboard_id: 1,
+        }
+    }
+
+    pub fn objective(&self, name: &str) -> Option<&Objective> {
+        self.objectives.get(name)
+    }
+
+    /// Returns the objective shown in the display slot.
+    pub fn displayed(&self, slot: ScoreboardDisplaySlot) -> Option<&Objective> {
+        self.objectives.get(self.display_slots.get(&slot)?)
+    }
+
+    pub fn score(&self, objective: &str, holder: &ScoreHolder) -> Option<i32> {
+        self.objectives.get(objective)?.score(holder)
+    }
+
+    /// Returns a scoreboard ID not used by any score holder.
+    pub fn allocate_scoreboard_id(&mut self) -> i64 {
+        let id = self.next_scoreboard_id;
+        self.next_scoreboard_id += 1;
+        id
+    }
+
+    /// Shows an objective in the display slot, creating it if needed.
+    pub async fn display(
+        &mut self,
+        conn: &mut ConnectionShard,
+        slot: ScoreboardDisplaySlot,
+        objective_name: impl Into<String>,
+        display_name: impl Into<String>,
+        sort_order: ObjectiveSortOrder,
+    ) -> Result<(), ScoreboardError> {
+        let objective_name = objective_name.into();
+        let display_name = display_name.into();
+
+        conn.send(GamePacket::SetDisplayObjective(SetDisplayObjectivePacket {
+            display_slot: slot,
+            objective_name: objective_name.clone(),
+            display_name: display_name.clone(),
+            criteria: String::from("dummy"),
+            sort_order,
+        }))
+        .await?;
+
+        let objective = self
+            .objectives
+            .entry(objective_name.clone())
+            .or_insert_with(|| Objective {
+                name: objective_name.clone(),
+                display_name: display_name.clone(),
+                sort_order,
+                scores: HashMap::new(),
+            });
+        objective.display_name = display_name;
+        objective.sort_order = sort_order;
+
+        self.display_slots.insert(slot, objective_name);
+
+        Ok(())
+    }
+
+    /// Removes an objective with all its scores from all display slots.
+    pub async fn remove_objective(
+        &mut self,
+        conn: &mut ConnectionShard,
+        objective_name: &str,
+    ) -> Result<(), ScoreboardError> {
+        if self.objectives.remove(objective_name).is_none() {
+            return Err(ScoreboardError::UnknownObjective(
+                objective_name.to_string(),
+            ));
+        }
+
+        self.display_slots.retain(|_, v| v != objective_name);
+
+        conn.send(GamePacket::RemoveObjective(RemoveObjectivePacket {
+            objective_name: objective_name.to_string(),
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sets multiple scores of an objective, unchanged scores aren't sent.
+    pub async fn set_scores(
+        &mut self,
+        conn: &mut ConnectionShard,
+        objective_name: &str,
+        scores: impl IntoIterator<Item = (ScoreHolder, i32)>,
+    ) -> Result<(), ScoreboardError> {
+        if !self.objectives.contains_key(objective_name) {
+            return Err(ScoreboardError::UnknownObjective(
+                objective_name.to_string(),
+            ));
+        }
+
+        let mut entries = vec![];
+        let mut identities = vec![];
+
+        for (holder, score) in scores {
+            if self.score(objective_name, &holder) == Some(score) {
+                continue;
+            }
+
+            let scoreboard_id = match self.scoreboard_ids.get(&holder) {
+                Some(id) => *id,
+                None => {
+                    let id = self.allocate_scoreboard_id();
+                    self.scoreboard_ids.insert(holder.clone(), id);
+
+                    // Players need their scoreboard ID registered first
+                    if let ScoreHolder::Player(unique_id) = holder {
+                        identities.push(ScoreboardIdentityEntry {
+                            scoreboard_id: id,
+                            player_unique_id: Some(ActorUniqueID(unique_id)),
+                        });
+                    }
+
+                    id
+                }
+            };
+
+            entries.push(ScoreEntry {
+                scoreboard_id,
+                objective_name: objective_name.to_string(),
+                score,
+                identity: Some(holder.identity()),
+            });
+
+            if let Some(objective) = self.objectives.get_mut(objective_name) {
+                objective.scores.insert(holder, score);
+            }
+        }
+
+        if !identities.is_empty() {
+            conn.send(GamePacket::SetScoreboardIdentity(
+                SetScoreboardIdentityPacket {
+                    action: ScoreboardIdentityAction::Register,
+                    entries: identities,
+                },
+            ))
+            .await?;
+        }
+
+        if !entries.is_empty() {
+            conn.send(GamePacket::SetScore(SetScorePacket {
+                action: SetScoreAction::Change,
+                entries,
+            }))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn set_score(
+        &mut self,
+        conn: &mut ConnectionShard,
+        objective_name: &str,
+        holder: ScoreHolder,
+        score: i32,
+    ) -> Result<(), ScoreboardError> {
+        self.set_scores(conn, objective_name, [(holder, score)])
+            .await
+    }
+
+    pub async fn remove_score(
+        &mut self,
+        conn: &mut ConnectionShard,
+        objective_name: &str,
+        holder: &ScoreHolder,
+    ) -> Result<(), ScoreboardError> {
+        let objective = self
+            .objectives
+            .get_mut(objective_name)
+            .ok_or_else(|| ScoreboardError::UnknownObjective(objective_name.to_string()))?;
+
+        let (Some(score), Some(scoreboard_id)) = (
+            objective.scores.remove(holder),
+            self.scoreboard_ids.get(holder),
+        ) else {
+            return Ok(());
+        };
+
+        conn.send(GamePacket::SetScore(SetScorePacket {
+            action: SetScoreAction::Remove,
+            entries: vec![ScoreEntry {
+                scoreboard_id: *scoreboard_id,
+                objective_name: objective_name.to_string(),
+                score,
+                identity: None,
+            }],
+        }))
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes all scores of a holder, e.g. when a player leaves.
+    pub async fn remove_holder(
+        &mut self,
+        conn: &mut ConnectionShard,
+        holder: &ScoreHolder,
+    ) -> Result<(), ScoreboardError> {
+        let Some(scoreboard_id) = self.scoreboard_ids.remove(holder) else {
+            return Ok(());
+        };
+
+        let entries: Vec<ScoreEntry> = self
+            .objectives
+            .values_mut()
+            .filter_map(|objective| {
+                let score = objective.scores.remove(holder)?;
+
+                Some(ScoreEntry {
+                    scoreboard_id,
+                    objective_name: objective.name.clone(),
+                    score,
+                    identity: None,
+                })
+            })
+            .collect();
+
+        if !entries.is_empty() {
+            conn.send(GamePacket::SetScore(SetScorePacket {
+                action: SetScoreAction::Remove,
+                entries,
+            }))
+            .await?;
+        }
+
+        if let ScoreHolder::Player(_) = holder {
+            conn.send(GamePacket::SetScoreboardIdentity(
+                SetScoreboardIdentityPacket {
+                    action: ScoreboardIdentityAction::Clear,
+                    entries: vec![ScoreboardIdentityEntry {
+                        scoreboard_id,
+                        player_unique_id: None,
+                    }],
+                },
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::connection::ConnectionShard;
+use crate::error::ScoreboardError;
+use crate::gamepacket::GamePacket;
+use crate::packets::set_score::{SetScoreAction, SetScorePacket};
+use crate::scoreboard::model::Scoreboard;
+use crate::types::scoreboard::{
+    ObjectiveSortOrder, ScoreEntry, ScoreboardDisplaySlot, ScoreboardIdentity,
+};
+
+/// Shows lines of text in the sidebar, each line is a fake player whose score is its index.
+/// The client shows at most 15 lines.
+#[derive(Debug, Clone)]
+pub struct Sidebar {
+    objective_name: String,
+    /// The scoreboard ID and text of every line, in order.
+    lines: Vec<(i64, String)>,
+}
+
+impl Sidebar {
+    pub fn new(objective_name: impl Into<String>) -> Self {
+        Self {
+            objective_name: objective_name.into(),
+            lines: vec![],
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(|(_, line)| line.as_str())
+    }
+
+    /// Shows the sidebar with a title, lines already set stay visible.
+    pub async fn show(
+        &mut self,
+        conn: &mut ConnectionShard,
+        scoreboard: &mut Scoreboard,
+        title: impl Into<String>,
+    ) -> Result<(), ScoreboardError> {
+        scoreboard
+            .display(
+                conn,
+                ScoreboardDisplaySlot::Sidebar,
+                self.objective_name.clone(),
+                title,
+                ObjectiveSortOrder::Ascending,
+            )
+            .await
+    }
+
+    pub async fn hide(
+        &mut self,
+        conn: &mut ConnectionShard,
+        scoreboard: &mut Scoreboard,
+    ) -> Result<(), ScoreboardError> {
+        self.lines.clear();
+
+        scoreboard
+            .remove_objective(conn, &self.objective_name)
+            .await
+    }
+
+    /// Computes the packets changing the shown lines to `lines`, which have to be sent in order.
+    /// Lines that stay the same aren't sent, lines that only moved keep their entry
+    /// and only get their score changed.
+    pub fn diff(&mut self, scoreboard: &mut Scoreboard, lines: Vec<String>) -> Vec<SetScorePacket> {
+        // The old entries by their text, texts can appear multiple times
+        let mut old: HashMap<String, Vec<(i64, usize)>> = HashMap::new();
+        for (index, (scoreboard_id, line)) in self.lines.drain(..).enumerate() {
+            old.entry(line).or_default().push((scoreboard_id, index));
+        }
+
+        let mut changed = vec![];
+
+        for (index, line) in lines.into_iter().enumerate() {
+            let reused = old.get_mut(&line).and_then(|entries| {
+                // Prefer the entry already at this index
+                let position = entries
+                    .iter()
+                    .position(|(_, old_index)| *old_index == index)
+                    .unwrap_or(0);
+
+                (!entries.is_empty()).then(|| entries.remove(position))
+            });
+
+            let scoreboard_id = match reused {
+                Some((scoreboard_id, old_index)) if old_index == index => scoreboard_id,
+                Some((scoreboard_id, _)) => {
+                    changed.push(self.entry(scoreboard_id, index, &line));
+                    scoreboard_id
+                }
+                None => {
+                    let scoreboard_id = scoreboard.allocate_scoreboard_id();
+                    changed.push(self.entry(scoreboard_id, index, &line));
+                    scoreboard_id
+                }
+            };
+
+            self.lines.push((scoreboard_id, line));
+        }
+
+        let removed: Vec<ScoreEntry> = old
+            .into_values()
+            .flatten()
+            .map(|(scoreboard_id, index)| ScoreEntry {
+                scoreboard_id,
+                objective_name: self.objective_name.clone(),
+                score: index as i32,
+                identity: None,
+            })
+            .collect();
+
+        let mut packets = vec![];
+        if !removed.is_empty() {
+            packets.push(SetScorePacket {
+                action: SetScoreAction::Remove,
+                entries: removed,
+            });
+        }
+        if !changed.is_empty() {
+            packets.push(SetScorePacket {
+                action: SetScoreAction::Change,
+                entries: changed,
+            });
+        }
+        packets
+    }
+
+    /// Changes the shown lines, sending only what changed.
+    pub async fn set_lines(
+        &mut self,
+        conn: &mut ConnectionShard,
+        scoreboard: &mut Scoreboard,
+        lines: Vec<String>,
+    ) -> Result<(), ScoreboardError> {
+        for packet in self.diff(scoreboard, lines) {
+            conn.send(GamePacket::SetScore(packet)).await?;
+        }
+
+        Ok(())
+    }
+
+    fn entry(&self, scoreboard_id: i64, index: usize, line: &str) -> ScoreEntry {
+        ScoreEntry {
+            scoreboard_id,
+            objective_name: self.objective_name.clone(),
+            score: index as i32,
+            identity: Some(ScoreboardIdentity::FakePlayer(line.to_string())),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::VecDeque;
+
+use crate::gamepacket::GamePacket;
+
+/// Decides what happens to newly queued packets once the send queue of a
+/// connection has grown past its high-water mark.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SendQueuePolicy {
+    /// Discard packets that the client can live without (see [`droppable`]),
+    /// all other packets are still queued.
+    DropDroppable,
+    /// Replace already queued packets that are superseded by the new packet
+    /// (e.g. a newer version of the same chunk), otherwise behaves like [`SendQueuePolicy::DropDroppable`].
+    ///
+    /// The superseded packet is removed and the new packet is queued at the end,
+    /// so all other packets keep their order.
+    Coalesce,
+    /// Close the connection, the client is not keeping up.
+    Disconnect,
+}
+
+/// The transport layer doesn't report how much data is still waiting to reach the client,
+/// so the queue only hands a limited amount of packets to it per flush interval.
+/// Packets beyond that stay queued, which makes the queue length the backlog of the connection.
+#[derive(Debug, Copy, Clone)]
+pub struct SendQueueSettings {
+    /// Amount of packets handed to the transport layer per flush interval.
+    /// Explicit flushes of a [`ConnectionShard`](crate::connection::ConnectionShard)
+    /// always send all queued packets.
+    pub packets_per_flush: usize,
+    /// Amount of queued packets after which the [`SendQueuePolicy`] is applied.
+    pub high_water_mark: usize,
+    /// Amount of queued packets after which the connection is closed, regardless of the policy.
+    pub hard_limit: usize,
+    pub policy: SendQueuePolicy,
+}
+
+impl Default for SendQueueSettings {
+    fn default() -> Self {
+        Self {
+            packets_per_flush: 256,
+            high_water_mark: 512,
+            hard_limit: 4096,
+            policy: SendQueuePolicy::Coalesce,
+        }
+    }
+}
+
+/// Per-connection statistics of the send queue.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct SendQueueMetrics {
+    /// Packets currently waiting to be handed to the transport layer.
+    pub queued: usize,
+    /// Highest amount of packets that were waiting at once.
+    pub peak_queued: usize,
+    /// Packets that have been handed to the transport layer.
+    pub sent: u64,
+    /// Packets discarded because the high-water mark was reached.
+    pub dropped: u64,
+    /// Packets that replaced an already queued packet.
+    pub coalesced: u64,
+    /// How often a packet was queued while the high-water mark was reached.
+    pub high_water_mark_hits: u64,
+}
+
+/// The outcome of [`SendQueue::push`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SendQueuePush {
+    Queued,
+    Dropped,
+    Coalesced,
+    /// The queue can't take any more packets, the connection should be closed.
+    Overflow,
+}
+
+/// The position mode of a [`MovePlayerPacket`](crate::packets::player_move::MovePlayerPacket)
+/// for regular movement.
+const MOVE_PLAYER_MODE_NORMAL: u8 = 0;
+
+/// Identifies packets that supersede each other.
+/// Only the newest packet with a given key needs to reach the client.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CoalesceKey {
+    Chunk { x: i32, z: i32, dimension: i32 },
+    PlayerMovement(u64),
+    ChunkRadius,
+}
+
+impl CoalesceKey {
+    pub fn of(pk: &GamePacket) -> Option<Self> {
+        match pk {
+            GamePacket::LevelChunk(pk) => Some(CoalesceKey::Chunk {
+                x: pk.chunk_position.x.into_inner(),
+                z: pk.chunk_position.z.into_inner(),
+                dimension: pk.dimension_id.into_inner(),
+            }),
+            // Teleports and resets correct the position of the client, so they are never replaced
+            GamePacket::MovePlayer(pk) if pk.position_mode == MOVE_PLAYER_MODE_NORMAL => {
+                Some(CoalesceKey::PlayerMovement(pk.player_runtime_id.0))
+            }
+            GamePacket::ChunkRadiusUpdate(_) => Some(CoalesceKey::ChunkRadius),
+            _ => None,
+        }
+    }
+}
+
+/// Returns if the given packet may be discarded when a client can't keep up.
+/// Losing these packets only skips an animation, packets carrying state like chunks
+/// and movements are never dropped, superseded ones get coalesced instead.
+pub fn droppable(pk: &GamePacket) -> bool {
+    matches!(pk, GamePacket::Animate(_))
+}
+
+/// The buffer holding all packets that are waiting to be handed to the transport layer.
+pub struct SendQueue {
+    settings: SendQueueSettings,
+    buffer: VecDeque<GamePacket>,
+    metrics: SendQueueMetrics,
+}
+
+impl SendQueue {
+    pub fn new(settings: SendQueueSettings) -> Self {
+        Self {
+            settings,
+            buffer: VecDeque::new(),
+            metrics: SendQueueMetrics::default(),
+        }
+    }
+
+    pub fn push(&mut self, pk: GamePacket) -> SendQueuePush {
+        if self.buffer.len() >= self.settings.hard_limit {
+            return SendQueuePush::Overflow;
+        }
+
+        if self.buffer.len() < self.settings.high_water_mark {
+            self.queue(pk);
+            return SendQueuePush::Queued;
+        }
+
+        self.metrics.high_water_mark_hits += 1;
+
+        if self.settings.policy == SendQueuePolicy::Disconnect {
+            return SendQueuePush::Overflow;
+        }
+
+        if self.settings.policy == SendQueuePolicy::Coalesce {
+            if let Some(key) = CoalesceKey::of(&pk) {
+                let superseded = self
+                    .buffer
+                    .iter()
+                    .position(|queued| CoalesceKey::of(queued).as_ref() == Some(&key));
+
+                if let Some(pos) = superseded {
+                    self.buffer.remove(pos);
+                    self.queue(pk);
+                    self.metrics.coalesced += 1;
+                    return SendQueuePush::Coalesced;
+                }
+            }
+        }
+
+        if droppable(&pk) {
+            self.metrics.dropped += 1;
+            return SendQueuePush::Dropped;
+        }
+
+        self.queue(pk);
+        SendQueuePush::Queued
+    }
+
+    /// Takes the packets for the next flush interval out of the queue,
+    /// they count as sent from now on.
+    pub fn take(&mut self) -> Vec<GamePacket> {
+        let count = self.settings.packets_per_flush.min(self.buffer.len());
+        self.take_count(count)
+    }
+
+    /// Takes all queued packets out of the queue, they count as sent from now on.
+    pub fn take_all(&mut self) -> Vec<GamePacket> {
+        self.take_count(self.buffer.len())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    #[inline]
+    pub fn metrics(&self) -> SendQueueMetrics {
+        self.metrics
+    }
+
+    fn take_count(&mut self, count: usize) -> Vec<GamePacket> {
+        let packets: Vec<_> = self.buffer.drain(..count).collect();
+
+        self.metrics.sent += packets.len() as u64;
+        self.metrics.queued = self.buffer.len();
+
+        packets
+    }
+
+    fn queue(&mut self, pk: GamePacket) {
+        self.buffer.push_back(pk);
+
+        self.metrics.queued = self.buffer.len();
+        self.metrics.peak_queued = self.metrics.peak_queued.max(self.buffer.len());
+    }
+}
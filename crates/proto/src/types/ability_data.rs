@@ -0,0 +1,81 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The abilities of a player, such as if it may build, fly or use commands.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AbilityData {
+    pub target_actor_unique_id: LE<i64>,
+    pub player_permissions: u8,
+    pub command_permissions: u8,
+    /// Layers later in the list override earlier ones.
+    #[len_repr(LE::<u8>)]
+    pub layers: Vec<AbilityLayer>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u16>)]
+pub enum AbilityLayerType {
+    CustomCache = 0,
+    Base = 1,
+    Spectator = 2,
+    Commands = 3,
+    Editor = 4,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AbilityLayer {
+    pub layer_type: AbilityLayerType,
+    /// A bitset of the abilities this layer sets, indexed by [`Ability`].
+    pub abilities_set: LE<u32>,
+    /// A bitset of the values of the set abilities, indexed by [`Ability`].
+    pub ability_values: LE<u32>,
+    pub fly_speed: LE<f32>,
+    pub walk_speed: LE<f32>,
+}
+
+/// The bit index of an ability in an [`AbilityLayer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Ability {
+    Build = 0,
+    Mine = 1,
+    DoorsAndSwitches = 2,
+    OpenContainers = 3,
+    AttackPlayers = 4,
+    AttackMobs = 5,
+    OperatorCommands = 6,
+    Teleport = 7,
+    Invulnerable = 8,
+    Flying = 9,
+    MayFly = 10,
+    InstantBuild = 11,
+    Lightning = 12,
+    FlySpeed = 13,
+    WalkSpeed = 14,
+    Muted = 15,
+    WorldBuilder = 16,
+    NoClip = 17,
+    PrivilegedBuilder = 18,
+}
+
+impl AbilityLayer {
+    pub fn set(&mut self, ability: Ability, value: bool) {
+        let bit = 1 << ability as u32;
+
+        self.abilities_set = LE::new(self.abilities_set.into_inner() | bit);
+
+        self.ability_values = match value {
+            true => LE::new(self.ability_values.into_inner() | bit),
+            false => LE::new(self.ability_values.into_inner() & !bit),
+        };
+    }
+
+    /// Returns the value of the ability, `None` if this layer doesn't set it.
+    pub fn get(&self, ability: Ability) -> Option<bool> {
+        let bit = 1 << ability as u32;
+
+        match self.abilities_set.into_inner() & bit {
+            0 => None,
+            _ => Some(self.ability_values.into_inner() & bit != 0),
+        }
+    }
+}
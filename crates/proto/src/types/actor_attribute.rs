@@ -0,0 +1,11 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The initial value of an attribute of a spawned actor, e.g. `minecraft:health`.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ActorAttribute {
+    pub name: String,
+    pub min: LE<f32>,
+    pub current: LE<f32>,
+    pub max: LE<f32>,
+}
@@ -0,0 +1,431 @@
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_nbt::NbtTag;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+use crate::types::actor_flags::{ActorFlag, ActorFlags};
+
+/// The IDs of the values in an [`ActorDataMap`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ActorDataKey {
+    Flags = 0,
+    StructuralIntegrity = 1,
+    Variant = 2,
+    ColorIndex = 3,
+    Name = 4,
+    Owner = 5,
+    Target = 6,
+    AirSupply = 7,
+    EffectColor = 8,
+    EffectAmbience = 9,
+    JumpDuration = 10,
+    HurtTime = 11,
+    HurtDirection = 12,
+    RowTimeLeft = 13,
+    RowTimeRight = 14,
+    Value = 15,
+    DisplayTileRuntimeId = 16,
+    DisplayOffset = 17,
+    CustomDisplay = 18,
+    Swell = 19,
+    OldSwell = 20,
+    SwellDirection = 21,
+    ChargeAmount = 22,
+    CarryBlockRuntimeId = 23,
+    ClientEvent = 24,
+    UsingItem = 25,
+    PlayerFlags = 26,
+    PlayerIndex = 27,
+    BedPosition = 28,
+    PowerX = 29,
+    PowerY = 30,
+    PowerZ = 31,
+    AuxPower = 32,
+    FishX = 33,
+    FishZ = 34,
+    FishAngle = 35,
+    AuxValueData = 36,
+    LeashHolder = 37,
+    Scale = 38,
+    HasNpc = 39,
+    NpcData = 40,
+    Actions = 41,
+    AirSupplyMax = 42,
+    MarkVariant = 43,
+    ContainerType = 44,
+    ContainerSize = 45,
+    ContainerStrengthModifier = 46,
+    BlockTarget = 47,
+    Inventory = 48,
+    TargetA = 49,
+    TargetB = 50,
+    TargetC = 51,
+    AerialAttack = 52,
+    Width = 53,
+    Height = 54,
+    FuseTime = 55,
+    SeatOffset = 56,
+    SeatLockPassengerRotation = 57,
+    SeatLockPassengerRotationDegrees = 58,
+    SeatRotationOffset = 59,
+    SeatRotationOffsetDegrees = 60,
+    DataRadius = 61,
+    DataWaiting = 62,
+    DataParticle = 63,
+    PeekId = 64,
+    AttachFace = 65,
+    Attached = 66,
+    AttachedPosition = 67,
+    TradeTarget = 68,
+    Career = 69,
+    HasCommandBlock = 70,
+    CommandName = 71,
+    LastCommandOutput = 72,
+    TrackCommandOutput = 73,
+    ControllingSeatIndex = 74,
+    Strength = 75,
+    StrengthMax = 76,
+    DataSpellCastingColor = 77,
+    DataLifetimeTicks = 78,
+    PoseIndex = 79,
+    DataTickOffset = 80,
+    AlwaysShowNameTag = 81,
+    ColorTwoIndex = 82,
+    NameAuthor = 83,
+    Score = 84,
+    BalloonAnchor = 85,
+    PuffedState = 86,
+    BubbleTime = 87,
+    Agent = 88,
+    SittingAmount = 89,
+    SittingAmountPrevious = 90,
+    EatingCounter = 91,
+    Flags2 = 92,
+    LayingAmount = 93,
+    LayingAmountPrevious = 94,
+    DataDuration = 95,
+    DataSpawnTime = 96,
+    DataChangeRate = 97,
+    DataChangeOnPickup = 98,
+    DataPickupCount = 99,
+    InteractText = 100,
+    TradeTier = 101,
+    MaxTradeTier = 102,
+    TradeExperience = 103,
+    SkinId = 104,
+    SpawningFrames = 105,
+    CommandBlockTickDelay = 106,
+    CommandBlockExecuteOnFirstTick = 107,
+    AmbientSoundInterval = 108,
+    AmbientSoundIntervalRange = 109,
+    AmbientSoundEventName = 110,
+    FallDamageMultiplier = 111,
+    NameRawText = 112,
+    CanRideTarget = 113,
+    LowTierCuredTradeDiscount = 114,
+    HighTierCuredTradeDiscount = 115,
+    NearbyCuredTradeDiscount = 116,
+    NearbyCuredDiscountTimeStamp = 117,
+    HitBox = 118,
+    IsBuoyant = 119,
+    FreezingEffectStrength = 120,
+    BuoyancyData = 121,
+    GoatHornCount = 122,
+    BaseRuntimeId = 123,
+    MovementSoundDistanceOffset = 124,
+    HeartbeatIntervalTicks = 125,
+    HeartbeatSoundEvent = 126,
+    PlayerLastDeathPosition = 127,
+    PlayerLastDeathDimension = 128,
+    PlayerHasDied = 129,
+    CollisionBox = 130,
+    VisibleMobEffects = 131,
+}
+
+impl From<ActorDataKey> for u32 {
+    fn from(value: ActorDataKey) -> Self {
+        value as u32
+    }
+}
+
+/// A single value of the [`ActorDataMap`].
+#[derive(Debug, Clone)]
+pub enum ActorDataValue {
+    Byte(u8),
+    Short(i16),
+    Int(i32),
+    Float(f32),
+    String(String),
+    CompoundTag(NbtTag),
+    BlockPos(Vec3<i32>),
+    Long(i64),
+    Vec3(Vec3<f32>),
+}
+
+impl ActorDataValue {
+    const BYTE_ID: u32 = 0;
+    const SHORT_ID: u32 = 1;
+    const INT_ID: u32 = 2;
+    const FLOAT_ID: u32 = 3;
+    const STRING_ID: u32 = 4;
+    const COMPOUND_TAG_ID: u32 = 5;
+    const BLOCK_POS_ID: u32 = 6;
+    const LONG_ID: u32 = 7;
+    const VEC3_ID: u32 = 8;
+
+    fn id(&self) -> u32 {
+        match self {
+            ActorDataValue::Byte(_) => Self::BYTE_ID,
+            ActorDataValue::Short(_) => Self::SHORT_ID,
+            ActorDataValue::Int(_) => Self::INT_ID,
+            ActorDataValue::Float(_) => Self::FLOAT_ID,
+            ActorDataValue::String(_) => Self::STRING_ID,
+            ActorDataValue::CompoundTag(_) => Self::COMPOUND_TAG_ID,
+            ActorDataValue::BlockPos(_) => Self::BLOCK_POS_ID,
+            ActorDataValue::Long(_) => Self::LONG_ID,
+            ActorDataValue::Vec3(_) => Self::VEC3_ID,
+        }
+    }
+
+    fn serialize_value(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            ActorDataValue::Byte(v) => v.proto_serialize(stream),
+            ActorDataValue::Short(v) => LE::new(*v).proto_serialize(stream),
+            ActorDataValue::Int(v) => VAR::new(*v).proto_serialize(stream),
+            ActorDataValue::Float(v) => LE::new(*v).proto_serialize(stream),
+            ActorDataValue::String(v) => v.proto_serialize(stream),
+            ActorDataValue::CompoundTag(v) => v.proto_serialize(stream),
+            ActorDataValue::BlockPos(v) => {
+                VAR::new(v.x).proto_serialize(stream)?;
+                VAR::new(v.y).proto_serialize(stream)?;
+                VAR::new(v.z).proto_serialize(stream)
+            }
+            ActorDataValue::Long(v) => VAR::new(*v).proto_serialize(stream),
+            ActorDataValue::Vec3(v) => {
+                LE::new(v.x).proto_serialize(stream)?;
+                LE::new(v.y).proto_serialize(stream)?;
+                LE::new(v.z).proto_serialize(stream)
+            }
+        }
+    }
+
+    fn deserialize_value(id: u32, stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match id {
+            Self::BYTE_ID => ActorDataValue::Byte(u8::proto_deserialize(stream)?),
+            Self::SHORT_ID => {
+                ActorDataValue::Short(LE::<i16>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::INT_ID => {
+                ActorDataValue::Int(VAR::<i32>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::FLOAT_ID => {
+                ActorDataValue::Float(LE::<f32>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::STRING_ID => ActorDataValue::String(String::proto_deserialize(stream)?),
+            Self::COMPOUND_TAG_ID => {
+                ActorDataValue::CompoundTag(NbtTag::proto_deserialize(stream)?)
+            }
+            Self::BLOCK_POS_ID => ActorDataValue::BlockPos(Vec3 {
+                x: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+                y: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+                z: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+            }),
+            Self::LONG_ID => {
+                ActorDataValue::Long(VAR::<i64>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::VEC3_ID => ActorDataValue::Vec3(Vec3 {
+                x: LE::<f32>::proto_deserialize(stream)?.into_inner(),
+                y: LE::<f32>::proto_deserialize(stream)?.into_inner(),
+                z: LE::<f32>::proto_deserialize(stream)?.into_inner(),
+            }),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ActorDataValue"),
+                ))
+            }
+        })
+    }
+}
+
+/// The synced data of an actor like its flags, name tag, scale or hitbox, keyed by the data ID.
+#[derive(Debug, Clone, Default)]
+pub struct ActorDataMap {
+    data: BTreeMap<u32, ActorDataValue>,
+}
+
+macro_rules! typed_accessors {
+    ($($get:ident, $set:ident, $variant:ident, $ty:ty;)*) => {
+        $(
+            pub fn $get(&self, key: ActorDataKey) -> Option<$ty> {
+                match self.get(key)? {
+                    ActorDataValue::$variant(v) => Some(v.clone()),
+                    _ => None,
+                }
+            }
+
+            pub fn $set(&mut self, key: ActorDataKey, value: $ty) {
+                self.insert(key, ActorDataValue::$variant(value));
+            }
+        )*
+    };
+}
+
+impl ActorDataMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: impl Into<u32>, value: ActorDataValue) -> Option<ActorDataValue> {
+        self.data.insert(key.into(), value)
+    }
+
+    pub fn get(&self, key: impl Into<u32>) -> Option<&ActorDataValue> {
+        self.data.get(&key.into())
+    }
+
+    pub fn remove(&mut self, key: impl Into<u32>) -> Option<ActorDataValue> {
+        self.data.remove(&key.into())
+    }
+
+    pub fn contains(&self, key: impl Into<u32>) -> bool {
+        self.data.contains_key(&key.into())
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &ActorDataValue)> {
+        self.data.iter().map(|(key, value)| (*key, value))
+    }
+
+    /// Returns a map with only the values of `keys`, used to send only changed values.
+    pub fn subset(&self, keys: impl IntoIterator<Item = impl Into<u32>>) -> Self {
+        let data = keys
+            .into_iter()
+            .filter_map(|key| {
+                let key = key.into();
+                self.data.get(&key).map(|value| (key, value.clone()))
+            })
+            .collect();
+
+        Self { data }
+    }
+
+    typed_accessors! {
+        get_byte, set_byte, Byte, u8;
+        get_short, set_short, Short, i16;
+        get_int, set_int, Int, i32;
+        get_float, set_float, Float, f32;
+        get_string, set_string, String, String;
+        get_nbt, set_nbt, CompoundTag, NbtTag;
+        get_block_pos, set_block_pos, BlockPos, Vec3<i32>;
+        get_long, set_long, Long, i64;
+        get_vec3, set_vec3, Vec3, Vec3<f32>;
+    }
+
+    /// Returns the flags, unset flags are `false`.
+    pub fn flags(&self) -> ActorFlags {
+        ActorFlags::from_longs(
+            self.get_long(ActorDataKey::Flags).unwrap_or(0),
+            self.get_long(ActorDataKey::Flags2).unwrap_or(0),
+        )
+    }
+
+    pub fn set_flags(&mut self, flags: ActorFlags) {
+        let (flags, flags2) = flags.to_longs();
+
+        self.set_long(ActorDataKey::Flags, flags);
+        self.set_long(ActorDataKey::Flags2, flags2);
+    }
+
+    pub fn flag(&self, flag: ActorFlag) -> bool {
+        self.flags().get(flag)
+    }
+
+    pub fn set_flag(&mut self, flag: ActorFlag, value: bool) {
+        self.set_flags(self.flags().with(flag, value));
+    }
+
+    pub fn name_tag(&self) -> Option<String> {
+        self.get_string(ActorDataKey::Name)
+    }
+
+    pub fn set_name_tag(&mut self, name: impl Into<String>) {
+        self.set_string(ActorDataKey::Name, name.into());
+    }
+
+    /// Shows the name tag only when looking at the actor, or always if `always` is set.
+    pub fn set_name_tag_visible(&mut self, visible: bool, always: bool) {
+        self.set_flag(ActorFlag::ShowName, visible);
+        self.set_flag(ActorFlag::AlwaysShowName, visible && always);
+        self.set_byte(ActorDataKey::AlwaysShowNameTag, (visible && always) as u8);
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.get_float(ActorDataKey::Scale).unwrap_or(1.0)
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.set_float(ActorDataKey::Scale, scale);
+    }
+
+    /// Sets the size of the hitbox of the actor.
+    pub fn set_bounding_box(&mut self, width: f32, height: f32) {
+        self.set_float(ActorDataKey::Width, width);
+        self.set_float(ActorDataKey::Height, height);
+    }
+
+    pub fn set_on_fire(&mut self, on_fire: bool) {
+        self.set_flag(ActorFlag::OnFire, on_fire);
+    }
+
+    pub fn set_sneaking(&mut self, sneaking: bool) {
+        self.set_flag(ActorFlag::Sneaking, sneaking);
+    }
+
+    pub fn set_invisible(&mut self, invisible: bool) {
+        self.set_flag(ActorFlag::Invisible, invisible);
+    }
+
+    pub fn set_variant(&mut self, variant: i32) {
+        self.set_int(ActorDataKey::Variant, variant);
+    }
+}
+
+impl ProtoCodec for ActorDataMap {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<u32>::new(self.data.len().try_into()?).proto_serialize(stream)?;
+
+        for (key, value) in &self.data {
+            VAR::<u32>::new(*key).proto_serialize(stream)?;
+            VAR::<u32>::new(value.id()).proto_serialize(stream)?;
+            value.serialize_value(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        let mut data = BTreeMap::new();
+        for _ in 0..len {
+            let key = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+            let id = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+            data.insert(key, ActorDataValue::deserialize_value(id, stream)?);
+        }
+
+        Ok(Self { data })
+    }
+}
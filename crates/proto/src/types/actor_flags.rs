@@ -0,0 +1,173 @@
+/// A single flag of the [`ActorFlags`] bitset.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ActorFlag {
+    OnFire = 0,
+    Sneaking = 1,
+    Riding = 2,
+    Sprinting = 3,
+    UsingItem = 4,
+    Invisible = 5,
+    Tempted = 6,
+    InLove = 7,
+    Saddled = 8,
+    Powered = 9,
+    Ignited = 10,
+    Baby = 11,
+    Converting = 12,
+    Critical = 13,
+    ShowName = 14,
+    AlwaysShowName = 15,
+    NoAi = 16,
+    Silent = 17,
+    WallClimbing = 18,
+    CanClimb = 19,
+    CanSwim = 20,
+    CanFly = 21,
+    CanWalk = 22,
+    Resting = 23,
+    Sitting = 24,
+    Angry = 25,
+    Interested = 26,
+    Charged = 27,
+    Tamed = 28,
+    Orphaned = 29,
+    Leashed = 30,
+    Sheared = 31,
+    Gliding = 32,
+    Elder = 33,
+    Moving = 34,
+    Breathing = 35,
+    Chested = 36,
+    Stackable = 37,
+    ShowBottom = 38,
+    Standing = 39,
+    Shaking = 40,
+    Idling = 41,
+    Casting = 42,
+    Charging = 43,
+    KeyboardControlled = 44,
+    PowerJump = 45,
+    Dash = 46,
+    Lingering = 47,
+    HasCollision = 48,
+    HasGravity = 49,
+    FireImmune = 50,
+    Dancing = 51,
+    Enchanted = 52,
+    ReturnTrident = 53,
+    ContainerPrivate = 54,
+    Transforming = 55,
+    DamageNearbyMobs = 56,
+    Swimming = 57,
+    Bribed = 58,
+    Pregnant = 59,
+    LayingEgg = 60,
+    PassengerCanPick = 61,
+    TransitionSitting = 62,
+    Eating = 63,
+    LayingDown = 64,
+    Sneezing = 65,
+    Trusting = 66,
+    Rolling = 67,
+    Scared = 68,
+    InScaffolding = 69,
+    OverScaffolding = 70,
+    DescendThroughBlock = 71,
+    Blocking = 72,
+    TransitionBlocking = 73,
+    BlockedUsingShield = 74,
+    BlockedUsingDamagedShield = 75,
+    Sleeping = 76,
+    WantsToWake = 77,
+    TradeInterest = 78,
+    DoorBreaker = 79,
+    BreakingObstruction = 80,
+    DoorOpener = 81,
+    Captain = 82,
+    Stunned = 83,
+    Roaring = 84,
+    DelayedAttack = 85,
+    AvoidingMobs = 86,
+    AvoidingBlock = 87,
+    FacingTargetToRangeAttack = 88,
+    HiddenWhenInvisible = 89,
+    InUi = 90,
+    Stalking = 91,
+    Emoting = 92,
+    Celebrating = 93,
+    Admiring = 94,
+    CelebratingSpecial = 95,
+    OutOfControl = 96,
+    RamAttack = 97,
+    PlayingDead = 98,
+    InAscendingBlock = 99,
+    OverDescendingBlock = 100,
+    Croaking = 101,
+    DigestMob = 102,
+    JumpGoal = 103,
+    Emerging = 104,
+    Sniffing = 105,
+    Digging = 106,
+    SonicBoom = 107,
+    HasDashTimeout = 108,
+    PushTowardsClosestSpace = 109,
+    Scenting = 110,
+    Rising = 111,
+    FeelingHappy = 112,
+    Searching = 113,
+    Crawling = 114,
+    TimerFlag1 = 115,
+    TimerFlag2 = 116,
+    TimerFlag3 = 117,
+    BodyRotationBlocked = 118,
+    RenderWhenInvisible = 119,
+    BodyRotationAxisAligned = 120,
+    Collidable = 121,
+    WasdAirControlled = 122,
+}
+
+/// The flags of an actor, sent as the [`ActorDataKey::Flags`](crate::types::actor_data::ActorDataKey::Flags)
+/// and [`ActorDataKey::Flags2`](crate::types::actor_data::ActorDataKey::Flags2) longs.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ActorFlags {
+    bits: [u64; 2],
+}
+
+impl ActorFlags {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_longs(flags: i64, flags2: i64) -> Self {
+        Self {
+            bits: [flags as u64, flags2 as u64],
+        }
+    }
+
+    /// Returns the flags as the values of the `Flags` and `Flags2` actor data.
+    pub fn to_longs(&self) -> (i64, i64) {
+        (self.bits[0] as i64, self.bits[1] as i64)
+    }
+
+    #[inline]
+    pub fn get(&self, flag: ActorFlag) -> bool {
+        let index = flag as usize;
+
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    #[inline]
+    pub fn set(&mut self, flag: ActorFlag, value: bool) {
+        let index = flag as usize;
+
+        match value {
+            true => self.bits[index / 64] |= 1 << (index % 64),
+            false => self.bits[index / 64] &= !(1 << (index % 64)),
+        }
+    }
+
+    pub fn with(mut self, flag: ActorFlag, value: bool) -> Self {
+        self.set(flag, value);
+        self
+    }
+}
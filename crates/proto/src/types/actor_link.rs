@@ -0,0 +1,25 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ActorLinkType {
+    /// Removes the link.
+    None = 0,
+    /// The rider controls the ridden actor.
+    Riding = 1,
+    Passenger = 2,
+}
+
+/// Links a rider to the actor it rides, e.g. a player to a horse or boat.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ActorLink {
+    pub ridden_actor_id: ActorUniqueID,
+    pub rider_actor_id: ActorUniqueID,
+    pub link_type: ActorLinkType,
+    /// If the rider is put in its seat right away instead of being animated.
+    pub immediate: bool,
+    pub rider_initiated: bool,
+    pub vehicle_angular_velocity: LE<f32>,
+}
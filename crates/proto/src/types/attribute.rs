@@ -0,0 +1,172 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The vanilla attributes, with the bounds and defaults the client uses for players.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Attribute {
+    Health,
+    Absorption,
+    Hunger,
+    Saturation,
+    Exhaustion,
+    Experience,
+    ExperienceLevel,
+    MovementSpeed,
+    UnderwaterMovementSpeed,
+    LavaMovementSpeed,
+    AttackDamage,
+    KnockbackResistance,
+    FollowRange,
+    Luck,
+    HorseJumpStrength,
+    ZombieSpawnReinforcements,
+}
+
+impl Attribute {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Attribute::Health => "minecraft:health",
+            Attribute::Absorption => "minecraft:absorption",
+            Attribute::Hunger => "minecraft:player.hunger",
+            Attribute::Saturation => "minecraft:player.saturation",
+            Attribute::Exhaustion => "minecraft:player.exhaustion",
+            Attribute::Experience => "minecraft:player.experience",
+            Attribute::ExperienceLevel => "minecraft:player.level",
+            Attribute::MovementSpeed => "minecraft:movement",
+            Attribute::UnderwaterMovementSpeed => "minecraft:underwater_movement",
+            Attribute::LavaMovementSpeed => "minecraft:lava_movement",
+            Attribute::AttackDamage => "minecraft:attack_damage",
+            Attribute::KnockbackResistance => "minecraft:knockback_resistance",
+            Attribute::FollowRange => "minecraft:follow_range",
+            Attribute::Luck => "minecraft:luck",
+            Attribute::HorseJumpStrength => "minecraft:horse.jump_strength",
+            Attribute::ZombieSpawnReinforcements => "minecraft:zombie.spawn_reinforcements",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "minecraft:health" => Attribute::Health,
+            "minecraft:absorption" => Attribute::Absorption,
+            "minecraft:player.hunger" => Attribute::Hunger,
+            "minecraft:player.saturation" => Attribute::Saturation,
+            "minecraft:player.exhaustion" => Attribute::Exhaustion,
+            "minecraft:player.experience" => Attribute::Experience,
+            "minecraft:player.level" => Attribute::ExperienceLevel,
+            "minecraft:movement" => Attribute::MovementSpeed,
+            "minecraft:underwater_movement" => Attribute::UnderwaterMovementSpeed,
+            "minecraft:lava_movement" => Attribute::LavaMovementSpeed,
+            "minecraft:attack_damage" => Attribute::AttackDamage,
+            "minecraft:knockback_resistance" => Attribute::KnockbackResistance,
+            "minecraft:follow_range" => Attribute::FollowRange,
+            "minecraft:luck" => Attribute::Luck,
+            "minecraft:horse.jump_strength" => Attribute::HorseJumpStrength,
+            "minecraft:zombie.spawn_reinforcements" => Attribute::ZombieSpawnReinforcements,
+            _ => return None,
+        })
+    }
+
+    /// Returns the `(min, max, default)` values of the attribute.
+    pub fn bounds(&self) -> (f32, f32, f32) {
+        match self {
+            Attribute::Health => (0.0, 20.0, 20.0),
+            Attribute::Absorption => (0.0, 16.0, 0.0),
+            Attribute::Hunger => (0.0, 20.0, 20.0),
+            Attribute::Saturation => (0.0, 20.0, 5.0),
+            Attribute::Exhaustion => (0.0, 5.0, 0.0),
+            Attribute::Experience => (0.0, 1.0, 0.0),
+            Attribute::ExperienceLevel => (0.0, 24791.0, 0.0),
+            Attribute::MovementSpeed => (0.0, f32::MAX, 0.1),
+            Attribute::UnderwaterMovementSpeed => (0.0, f32::MAX, 0.02),
+            Attribute::LavaMovementSpeed => (0.0, f32::MAX, 0.02),
+            Attribute::AttackDamage => (0.0, f32::MAX, 1.0),
+            Attribute::KnockbackResistance => (0.0, 1.0, 0.0),
+            Attribute::FollowRange => (0.0, 2048.0, 16.0),
+            Attribute::Luck => (-1024.0, 1024.0, 0.0),
+            Attribute::HorseJumpStrength => (0.0, 2.0, 0.7),
+            Attribute::ZombieSpawnReinforcements => (0.0, 1.0, 0.0),
+        }
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<i32>)]
+pub enum AttributeModifierOperation {
+    Addition = 0,
+    MultiplyBase = 1,
+    MultiplyTotal = 2,
+    Cap = 3,
+}
+
+/// The value of the attribute a modifier applies to.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<i32>)]
+pub enum AttributeModifierOperand {
+    Min = 0,
+    Max = 1,
+    Current = 2,
+}
+
+/// Modifies an attribute on the client, e.g. the speed boost of sprinting,
+/// which also changes the field of view.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AttributeModifier {
+    /// A unique ID, usually a UUID.
+    pub id: String,
+    pub name: String,
+    pub amount: LE<f32>,
+    pub operation: AttributeModifierOperation,
+    pub operand: AttributeModifierOperand,
+    pub serializable: bool,
+}
+
+/// The state of an attribute sent in the
+/// [`UpdateAttributesPacket`](crate::packets::update_attributes::UpdateAttributesPacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct AttributeData {
+    pub min: LE<f32>,
+    pub max: LE<f32>,
+    pub current: LE<f32>,
+    pub default: LE<f32>,
+    pub name: String,
+    #[len_repr(VAR::<u32>)]
+    pub modifiers: Vec<AttributeModifier>,
+}
+
+impl AttributeData {
+    /// Creates the data of a vanilla attribute with its default bounds,
+    /// the value is clamped to them.
+    pub fn new(attribute: Attribute, current: f32) -> Self {
+        let (min, max, default) = attribute.bounds();
+
+        Self {
+            min: LE::new(min),
+            max: LE::new(max),
+            current: LE::new(current.clamp(min, max)),
+            default: LE::new(default),
+            name: attribute.name().to_string(),
+            modifiers: vec![],
+        }
+    }
+
+    /// Creates the data of a vanilla attribute set to its default value.
+    pub fn default_of(attribute: Attribute) -> Self {
+        Self::new(attribute, attribute.bounds().2)
+    }
+
+    pub fn attribute(&self) -> Option<Attribute> {
+        Attribute::from_name(&self.name)
+    }
+
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Self {
+        self.min = LE::new(min);
+        self.max = LE::new(max);
+        self.current = LE::new(self.current.into_inner().clamp(min, max));
+        self
+    }
+
+    pub fn with_modifier(mut self, modifier: AttributeModifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+}
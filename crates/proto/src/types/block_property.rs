@@ -0,0 +1,11 @@
+use bedrockrs_nbt::NbtTag;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// A custom block sent in the [`StartGamePacket`](crate::packets::start_game::StartGamePacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct BlockProperty {
+    /// The identifier of the block, e.g. `"custom:ruby_ore"`.
+    pub name: String,
+    /// The block definition, containing its components, menu category, states and permutations.
+    pub nbt: NbtTag,
+}
@@ -0,0 +1,173 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum BossBarColor {
+    Pink = 0,
+    Blue = 1,
+    Red = 2,
+    Green = 3,
+    Yellow = 4,
+    Purple = 5,
+    RebeccaPurple = 6,
+    White = 7,
+}
+
+/// The segmentation of the boss bar.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum BossBarOverlay {
+    Progress = 0,
+    Notched6 = 1,
+    Notched10 = 2,
+    Notched12 = 3,
+    Notched20 = 4,
+}
+
+#[derive(Debug, Clone)]
+pub enum BossEventType {
+    /// Shows the boss bar.
+    Add {
+        title: String,
+        /// From `0.0` to `1.0`.
+        health_percent: f32,
+        darken_screen: i16,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    PlayerAdded(ActorUniqueID),
+    /// Hides the boss bar.
+    Remove,
+    PlayerRemoved(ActorUniqueID),
+    UpdatePercent(f32),
+    UpdateName(String),
+    UpdateProperties {
+        darken_screen: i16,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    UpdateStyle {
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    /// Sent by the client to request the boss bar.
+    Query(ActorUniqueID),
+}
+
+impl BossEventType {
+    const ADD_ID: u32 = 0;
+    const PLAYER_ADDED_ID: u32 = 1;
+    const REMOVE_ID: u32 = 2;
+    const PLAYER_REMOVED_ID: u32 = 3;
+    const UPDATE_PERCENT_ID: u32 = 4;
+    const UPDATE_NAME_ID: u32 = 5;
+    const UPDATE_PROPERTIES_ID: u32 = 6;
+    const UPDATE_STYLE_ID: u32 = 7;
+    const QUERY_ID: u32 = 8;
+}
+
+impl ProtoCodec for BossEventType {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            BossEventType::Add {
+                title,
+                health_percent,
+                darken_screen,
+                color,
+                overlay,
+            } => {
+                VAR::new(Self::ADD_ID).proto_serialize(stream)?;
+                title.proto_serialize(stream)?;
+                LE::new(*health_percent).proto_serialize(stream)?;
+                LE::new(*darken_screen).proto_serialize(stream)?;
+                color.proto_serialize(stream)?;
+                overlay.proto_serialize(stream)?;
+            }
+            BossEventType::PlayerAdded(id) => {
+                VAR::new(Self::PLAYER_ADDED_ID).proto_serialize(stream)?;
+                id.proto_serialize(stream)?;
+            }
+            BossEventType::Remove => {
+                VAR::new(Self::REMOVE_ID).proto_serialize(stream)?;
+            }
+            BossEventType::PlayerRemoved(id) => {
+                VAR::new(Self::PLAYER_REMOVED_ID).proto_serialize(stream)?;
+                id.proto_serialize(stream)?;
+            }
+            BossEventType::UpdatePercent(health_percent) => {
+                VAR::new(Self::UPDATE_PERCENT_ID).proto_serialize(stream)?;
+                LE::new(*health_percent).proto_serialize(stream)?;
+            }
+            BossEventType::UpdateName(title) => {
+                VAR::new(Self::UPDATE_NAME_ID).proto_serialize(stream)?;
+                title.proto_serialize(stream)?;
+            }
+            BossEventType::UpdateProperties {
+                darken_screen,
+                color,
+                overlay,
+            } => {
+                VAR::new(Self::UPDATE_PROPERTIES_ID).proto_serialize(stream)?;
+                LE::new(*darken_screen).proto_serialize(stream)?;
+                color.proto_serialize(stream)?;
+                overlay.proto_serialize(stream)?;
+            }
+            BossEventType::UpdateStyle { color, overlay } => {
+                VAR::new(Self::UPDATE_STYLE_ID).proto_serialize(stream)?;
+                color.proto_serialize(stream)?;
+                overlay.proto_serialize(stream)?;
+            }
+            BossEventType::Query(id) => {
+                VAR::new(Self::QUERY_ID).proto_serialize(stream)?;
+                id.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            Self::ADD_ID => BossEventType::Add {
+                title: String::proto_deserialize(stream)?,
+                health_percent: LE::<f32>::proto_deserialize(stream)?.into_inner(),
+                darken_screen: LE::<i16>::proto_deserialize(stream)?.into_inner(),
+                color: BossBarColor::proto_deserialize(stream)?,
+                overlay: BossBarOverlay::proto_deserialize(stream)?,
+            },
+            Self::PLAYER_ADDED_ID => {
+                BossEventType::PlayerAdded(ActorUniqueID::proto_deserialize(stream)?)
+            }
+            Self::REMOVE_ID => BossEventType::Remove,
+            Self::PLAYER_REMOVED_ID => {
+                BossEventType::PlayerRemoved(ActorUniqueID::proto_deserialize(stream)?)
+            }
+            Self::UPDATE_PERCENT_ID => {
+                BossEventType::UpdatePercent(LE::<f32>::proto_deserialize(stream)?.into_inner())
+            }
+            Self::UPDATE_NAME_ID => BossEventType::UpdateName(String::proto_deserialize(stream)?),
+            Self::UPDATE_PROPERTIES_ID => BossEventType::UpdateProperties {
+                darken_screen: LE::<i16>::proto_deserialize(stream)?.into_inner(),
+                color: BossBarColor::proto_deserialize(stream)?,
+                overlay: BossBarOverlay::proto_deserialize(stream)?,
+            },
+            Self::UPDATE_STYLE_ID => BossEventType::UpdateStyle {
+                color: BossBarColor::proto_deserialize(stream)?,
+                overlay: BossBarOverlay::proto_deserialize(stream)?,
+            },
+            Self::QUERY_ID => BossEventType::Query(ActorUniqueID::proto_deserialize(stream)?),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("BossEventType"),
+                ))
+            }
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Marks the type info of a parameter as a basic type, see [`CommandParameterType`].
+pub const COMMAND_PARAMETER_FLAG_VALID: u32 = 0x100000;
+/// Marks the type info of a parameter as the index of an enum.
+pub const COMMAND_PARAMETER_FLAG_ENUM: u32 = 0x200000;
+/// Marks the type info of a parameter as the index of a suffix.
+pub const COMMAND_PARAMETER_FLAG_SUFFIXED: u32 = 0x1000000;
+/// Marks the type info of a parameter as the index of a soft enum.
+pub const COMMAND_PARAMETER_FLAG_SOFT_ENUM: u32 = 0x4000000;
+
+/// The basic types a command parameter can have.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CommandParameterType {
+    Int = 1,
+    Float = 3,
+    Value = 4,
+    WildcardInt = 5,
+    Operator = 6,
+    CompareOperator = 7,
+    Target = 8,
+    WildcardTarget = 10,
+    Filepath = 17,
+    IntegerRange = 23,
+    EquipmentSlots = 47,
+    String = 56,
+    BlockPosition = 64,
+    Position = 65,
+    Message = 67,
+    RawText = 70,
+    Json = 74,
+    BlockStates = 84,
+    Command = 87,
+}
+
+pub const COMMAND_FLAG_TEST_USAGE: u16 = 0x01;
+pub const COMMAND_FLAG_HIDDEN_FROM_COMMAND_BLOCK: u16 = 0x02;
+pub const COMMAND_FLAG_HIDDEN_FROM_PLAYER: u16 = 0x04;
+pub const COMMAND_FLAG_HIDDEN_FROM_AUTOMATION: u16 = 0x08;
+pub const COMMAND_FLAG_LOCAL_SYNC: u16 = 0x10;
+pub const COMMAND_FLAG_EXECUTE_DISALLOWED: u16 = 0x20;
+pub const COMMAND_FLAG_MESSAGE_TYPE: u16 = 0x40;
+pub const COMMAND_FLAG_NOT_CHEAT: u16 = 0x80;
+pub const COMMAND_FLAG_ASYNC: u16 = 0x100;
+
+/// Shows the enum values in the autocompletion instead of the enum name.
+pub const COMMAND_PARAMETER_OPTION_COLLAPSE_ENUM: u8 = 0x01;
+pub const COMMAND_PARAMETER_OPTION_HAS_SEMANTIC_CONSTRAINT: u8 = 0x02;
+pub const COMMAND_PARAMETER_OPTION_AS_CHAINED_COMMAND: u8 = 0x04;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum CommandPermissionLevel {
+    Any = 0,
+    GameDirectors = 1,
+    Admin = 2,
+    Host = 3,
+    Owner = 4,
+    Internal = 5,
+}
+
+/// An enum of the [`AvailableCommandsPacket`](crate::packets::available_commands::AvailableCommandsPacket),
+/// its values are indices into the enum value table of the packet.
+#[derive(Debug, Clone)]
+pub struct CommandEnum {
+    pub name: String,
+    pub value_indices: Vec<u32>,
+}
+
+/// An enum whose values can be changed after the commands were sent,
+/// see [`UpdateSoftEnumPacket`](crate::packets::update_soft_enum::UpdateSoftEnumPacket).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandSoftEnum {
+    pub name: String,
+    #[len_repr(VAR::<u32>)]
+    pub values: Vec<String>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ChainedSubcommandValue {
+    /// The index into the chained subcommand value table.
+    pub index: LE<u16>,
+    pub value: LE<u16>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ChainedSubcommand {
+    pub name: String,
+    #[len_repr(VAR::<u32>)]
+    pub values: Vec<ChainedSubcommandValue>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum CommandEnumConstraintType {
+    CheatsEnabled = 0,
+    OperatorPermissions = 1,
+    HostPermissions = 2,
+}
+
+/// Limits the usage of an enum value.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandEnumConstraint {
+    pub enum_value_index: LE<u32>,
+    pub enum_index: LE<u32>,
+    #[len_repr(VAR::<u32>)]
+    pub constraints: Vec<CommandEnumConstraintType>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandParameterData {
+    pub name: String,
+    /// A [`CommandParameterType`] or an enum, suffix or soft enum index,
+    /// combined with one of the `COMMAND_PARAMETER_FLAG_*` flags.
+    pub type_info: LE<u32>,
+    pub optional: bool,
+    /// A combination of the `COMMAND_PARAMETER_OPTION_*` flags.
+    pub options: u8,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandOverload {
+    pub chaining: bool,
+    #[len_repr(VAR::<u32>)]
+    pub parameters: Vec<CommandParameterData>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandData {
+    pub name: String,
+    pub description: String,
+    /// A combination of the `COMMAND_FLAG_*` flags.
+    pub flags: LE<u16>,
+    pub permission_level: CommandPermissionLevel,
+    /// The index of the enum containing the aliases of the command, `-1` if it has none.
+    pub alias_enum_index: LE<i32>,
+    #[len_repr(VAR::<u32>)]
+    pub chained_subcommand_indices: Vec<LE<u16>>,
+    #[len_repr(VAR::<u32>)]
+    pub overloads: Vec<CommandOverload>,
+}
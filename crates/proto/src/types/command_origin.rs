@@ -0,0 +1,72 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use uuid::Uuid;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum CommandOriginType {
+    Player = 0,
+    Block = 1,
+    MinecartBlock = 2,
+    DevConsole = 3,
+    Test = 4,
+    AutomationPlayer = 5,
+    ClientAutomation = 6,
+    DedicatedServer = 7,
+    Entity = 8,
+    Virtual = 9,
+    GameArgument = 10,
+    EntityServer = 11,
+    Precompiled = 12,
+    GameDirectorEntityServer = 13,
+    Script = 14,
+    Executor = 15,
+}
+
+/// Identifies who ran a command, output is sent back with the same origin.
+#[derive(Debug, Clone)]
+pub struct CommandOriginData {
+    pub origin_type: CommandOriginType,
+    pub uuid: Uuid,
+    pub request_id: String,
+    /// Only sent for [`CommandOriginType::DevConsole`] and [`CommandOriginType::Test`].
+    pub player_actor_unique_id: i64,
+}
+
+impl ProtoCodec for CommandOriginData {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.origin_type.proto_serialize(stream)?;
+        self.uuid.proto_serialize(stream)?;
+        self.request_id.proto_serialize(stream)?;
+
+        if let CommandOriginType::DevConsole | CommandOriginType::Test = self.origin_type {
+            VAR::new(self.player_actor_unique_id).proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let origin_type = CommandOriginType::proto_deserialize(stream)?;
+        let uuid = Uuid::proto_deserialize(stream)?;
+        let request_id = String::proto_deserialize(stream)?;
+
+        let player_actor_unique_id = match origin_type {
+            CommandOriginType::DevConsole | CommandOriginType::Test => {
+                VAR::<i64>::proto_deserialize(stream)?.into_inner()
+            }
+            _ => 0,
+        };
+
+        Ok(Self {
+            origin_type,
+            uuid,
+            request_id,
+            player_actor_unique_id,
+        })
+    }
+}
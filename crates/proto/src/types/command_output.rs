@@ -0,0 +1,22 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum CommandOutputType {
+    None = 0,
+    LastOutput = 1,
+    Silent = 2,
+    AllOutput = 3,
+    DataSet = 4,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct CommandOutputMessage {
+    pub success: bool,
+    /// A translation key or a plain message.
+    pub message_id: String,
+    /// The parameters of the translation.
+    #[len_repr(VAR::<u32>)]
+    pub parameters: Vec<String>,
+}
@@ -10,6 +10,9 @@ use bedrockrs_proto_core::ProtoCodec;
 use jsonwebtoken::{DecodingKey, Validation};
 use serde_json::Value;
 
+use crate::error::SkinError;
+use crate::types::skin::Skin;
+
 #[derive(Debug, Clone)]
 pub struct ConnectionRequest {
     /// Array of Base64 encoded JSON Web Token certificates to authenticate the player.
@@ -80,6 +83,13 @@ pub struct ConnectionRequest {
     pub raw_token: BTreeMap<String, Value>,
 }
 
+impl ConnectionRequest {
+    /// Reads the skin from the client properties.
+    pub fn skin(&self) -> Result<Skin, SkinError> {
+        Skin::from_client_data(&self.raw_token)
+    }
+}
+
 impl ProtoCodec for ConnectionRequest {
     fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError>
     where
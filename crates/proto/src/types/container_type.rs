@@ -0,0 +1,47 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The kind of container screen the client opens.
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<i8>)]
+pub enum ContainerType {
+    None = -9,
+    Inventory = -1,
+    Container = 0,
+    Workbench = 1,
+    Furnace = 2,
+    Enchantment = 3,
+    BrewingStand = 4,
+    Anvil = 5,
+    Dispenser = 6,
+    Dropper = 7,
+    Hopper = 8,
+    Cauldron = 9,
+    MinecartChest = 10,
+    MinecartHopper = 11,
+    Horse = 12,
+    Beacon = 13,
+    StructureEditor = 14,
+    Trade = 15,
+    CommandBlock = 16,
+    Jukebox = 17,
+    Armor = 18,
+    Hand = 19,
+    CompoundCreator = 20,
+    ElementConstructor = 21,
+    MaterialReducer = 22,
+    LabTable = 23,
+    Loom = 24,
+    Lectern = 25,
+    Grindstone = 26,
+    BlastFurnace = 27,
+    Smoker = 28,
+    Stonecutter = 29,
+    Cartography = 30,
+    Hud = 31,
+    JigsawEditor = 32,
+    SmithingTable = 33,
+    ChestBoat = 34,
+    DecoratedPot = 35,
+    Crafter = 36,
+}
@@ -0,0 +1,35 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::world::generator_type::GeneratorType;
+
+/// Overrides the height range of a vanilla dimension, like `minecraft:overworld`.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct DimensionDefinition {
+    pub name: String,
+    /// The exclusive upper block limit.
+    pub max_height: VAR<i32>,
+    pub min_height: VAR<i32>,
+    pub generator_type: GeneratorType,
+}
+
+impl DimensionDefinition {
+    pub fn new(
+        name: impl Into<String>,
+        min_height: i32,
+        max_height: i32,
+        generator_type: GeneratorType,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            max_height: VAR::new(max_height),
+            min_height: VAR::new(min_height),
+            generator_type,
+        }
+    }
+
+    /// The number of sub chunks between the min and max height.
+    pub fn sub_chunk_count(&self) -> u32 {
+        let height = self.max_height.into_inner() - self.min_height.into_inner();
+        (height.max(0) as u32).div_ceil(16)
+    }
+}
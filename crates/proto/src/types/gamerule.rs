@@ -3,13 +3,34 @@ use bedrockrs_proto_core::error::ProtoCodecError;
 use bedrockrs_proto_core::ProtoCodec;
 use bedrockrs_proto_derive::ProtoCodec;
 use std::io::Cursor;
-use std::sync::Arc;
 
 #[derive(ProtoCodec, Debug, Clone)]
 pub struct GameRule {
-    name: String,
-    editable: bool,
-    value: GameRuleValue,
+    pub name: String,
+    /// If the player can change the gamerule in the world settings.
+    pub editable: bool,
+    pub value: GameRuleValue,
+}
+
+impl GameRule {
+    pub fn new(name: impl Into<String>, value: GameRuleValue) -> Self {
+        Self {
+            name: name.into(),
+            editable: false,
+            value,
+        }
+    }
+
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+}
+
+impl From<VanillaGameRule> for GameRule {
+    fn from(value: VanillaGameRule) -> Self {
+        GameRule::new(value.name(), value.value())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -19,47 +40,238 @@ pub enum GameRuleValue {
     ValF32(f32),
 }
 
+/// The vanilla gamerules with the value type the client expects.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VanillaGameRule {
+    CommandBlockOutput(bool),
+    CommandBlocksEnabled(bool),
+    DoDaylightCycle(bool),
+    DoEntityDrops(bool),
+    DoFireTick(bool),
+    /// Skips the death screen.
+    DoImmediateRespawn(bool),
+    DoInsomnia(bool),
+    DoLimitedCrafting(bool),
+    DoMobLoot(bool),
+    DoMobSpawning(bool),
+    DoTileDrops(bool),
+    DoWeatherCycle(bool),
+    DrowningDamage(bool),
+    FallDamage(bool),
+    FireDamage(bool),
+    FreezeDamage(bool),
+    FunctionCommandLimit(u32),
+    KeepInventory(bool),
+    MaxCommandChainLength(u32),
+    MobGriefing(bool),
+    NaturalRegeneration(bool),
+    /// The percentage of players that must sleep to skip the night.
+    PlayersSleepingPercentage(u32),
+    ProjectilesCanBreakBlocks(bool),
+    Pvp(bool),
+    /// The number of random block ticks per sub chunk and tick.
+    RandomTickSpeed(u32),
+    /// Only unlocked recipes are shown in the recipe book.
+    RecipesUnlock(bool),
+    RespawnBlocksExplode(bool),
+    SendCommandFeedback(bool),
+    ShowBorderEffect(bool),
+    ShowCoordinates(bool),
+    /// Shows the played days next to the coordinates.
+    ShowDaysPlayed(bool),
+    ShowDeathMessages(bool),
+    ShowRecipeMessages(bool),
+    ShowTags(bool),
+    SpawnRadius(u32),
+    TntExplodes(bool),
+}
+
+impl VanillaGameRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VanillaGameRule::CommandBlockOutput(_) => "commandBlockOutput",
+            VanillaGameRule::CommandBlocksEnabled(_) => "commandBlocksEnabled",
+            VanillaGameRule::DoDaylightCycle(_) => "doDaylightCycle",
+            VanillaGameRule::DoEntityDrops(_) => "doEntityDrops",
+            VanillaGameRule::DoFireTick(_) => "doFireTick",
+            VanillaGameRule::DoImmediateRespawn(_) => "doImmediateRespawn",
+            VanillaGameRule::DoInsomnia(_) => "doInsomnia",
+            VanillaGameRule::DoLimitedCrafting(_) => "doLimitedCrafting",
+            VanillaGameRule::DoMobLoot(_) => "doMobLoot",
+            VanillaGameRule::DoMobSpawning(_) => "doMobSpawning",
+            VanillaGameRule::DoTileDrops(_) => "doTileDrops",
+            VanillaGameRule::DoWeatherCycle(_) => "doWeatherCycle",
+            VanillaGameRule::DrowningDamage(_) => "drowningDamage",
+            VanillaGameRule::FallDamage(_) => "fallDamage",
+            VanillaGameRule::FireDamage(_) => "fireDamage",
+            VanillaGameRule::FreezeDamage(_) => "freezeDamage",
+            VanillaGameRule::FunctionCommandLimit(_) => "functionCommandLimit",
+            VanillaGameRule::KeepInventory(_) => "keepInventory",
+            VanillaGameRule::MaxCommandChainLength(_) => "maxCommandChainLength",
+            VanillaGameRule::MobGriefing(_) => "mobGriefing",
+            VanillaGameRule::NaturalRegeneration(_) => "naturalRegeneration",
+            VanillaGameRule::PlayersSleepingPercentage(_) => "playersSleepingPercentage",
+            VanillaGameRule::ProjectilesCanBreakBlocks(_) => "projectilesCanBreakBlocks",
+            VanillaGameRule::Pvp(_) => "pvp",
+            VanillaGameRule::RandomTickSpeed(_) => "randomTickSpeed",
+            VanillaGameRule::RecipesUnlock(_) => "recipesUnlock",
+            VanillaGameRule::RespawnBlocksExplode(_) => "respawnBlocksExplode",
+            VanillaGameRule::SendCommandFeedback(_) => "sendCommandFeedback",
+            VanillaGameRule::ShowBorderEffect(_) => "showBorderEffect",
+            VanillaGameRule::ShowCoordinates(_) => "showCoordinates",
+            VanillaGameRule::ShowDaysPlayed(_) => "showDaysPlayed",
+            VanillaGameRule::ShowDeathMessages(_) => "showDeathMessages",
+            VanillaGameRule::ShowRecipeMessages(_) => "showRecipeMessages",
+            VanillaGameRule::ShowTags(_) => "showTags",
+            VanillaGameRule::SpawnRadius(_) => "spawnRadius",
+            VanillaGameRule::TntExplodes(_) => "tntExplodes",
+        }
+    }
+
+    pub fn value(&self) -> GameRuleValue {
+        match self {
+            VanillaGameRule::CommandBlockOutput(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::CommandBlocksEnabled(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoDaylightCycle(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoEntityDrops(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoFireTick(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoImmediateRespawn(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoInsomnia(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoLimitedCrafting(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoMobLoot(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoMobSpawning(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoTileDrops(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DoWeatherCycle(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::DrowningDamage(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::FallDamage(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::FireDamage(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::FreezeDamage(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::FunctionCommandLimit(v) => GameRuleValue::ValVarU32(*v),
+            VanillaGameRule::KeepInventory(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::MaxCommandChainLength(v) => GameRuleValue::ValVarU32(*v),
+            VanillaGameRule::MobGriefing(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::NaturalRegeneration(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::PlayersSleepingPercentage(v) => GameRuleValue::ValVarU32(*v),
+            VanillaGameRule::ProjectilesCanBreakBlocks(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::Pvp(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::RandomTickSpeed(v) => GameRuleValue::ValVarU32(*v),
+            VanillaGameRule::RecipesUnlock(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::RespawnBlocksExplode(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::SendCommandFeedback(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::ShowBorderEffect(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::ShowCoordinates(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::ShowDaysPlayed(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::ShowDeathMessages(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::ShowRecipeMessages(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::ShowTags(v) => GameRuleValue::ValBool(*v),
+            VanillaGameRule::SpawnRadius(v) => GameRuleValue::ValVarU32(*v),
+            VanillaGameRule::TntExplodes(v) => GameRuleValue::ValBool(*v),
+        }
+    }
+
+    /// Returns the vanilla gamerule if the name is known and the value has the expected type.
+    pub fn from_game_rule(game_rule: &GameRule) -> Option<Self> {
+        Some(match (game_rule.name.as_str(), &game_rule.value) {
+            ("commandBlockOutput", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::CommandBlockOutput(*v)
+            }
+            ("commandBlocksEnabled", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::CommandBlocksEnabled(*v)
+            }
+            ("doDaylightCycle", GameRuleValue::ValBool(v)) => VanillaGameRule::DoDaylightCycle(*v),
+            ("doEntityDrops", GameRuleValue::ValBool(v)) => VanillaGameRule::DoEntityDrops(*v),
+            ("doFireTick", GameRuleValue::ValBool(v)) => VanillaGameRule::DoFireTick(*v),
+            ("doImmediateRespawn", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::DoImmediateRespawn(*v)
+            }
+            ("doInsomnia", GameRuleValue::ValBool(v)) => VanillaGameRule::DoInsomnia(*v),
+            ("doLimitedCrafting", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::DoLimitedCrafting(*v)
+            }
+            ("doMobLoot", GameRuleValue::ValBool(v)) => VanillaGameRule::DoMobLoot(*v),
+            ("doMobSpawning", GameRuleValue::ValBool(v)) => VanillaGameRule::DoMobSpawning(*v),
+            ("doTileDrops", GameRuleValue::ValBool(v)) => VanillaGameRule::DoTileDrops(*v),
+            ("doWeatherCycle", GameRuleValue::ValBool(v)) => VanillaGameRule::DoWeatherCycle(*v),
+            ("drowningDamage", GameRuleValue::ValBool(v)) => VanillaGameRule::DrowningDamage(*v),
+            ("fallDamage", GameRuleValue::ValBool(v)) => VanillaGameRule::FallDamage(*v),
+            ("fireDamage", GameRuleValue::ValBool(v)) => VanillaGameRule::FireDamage(*v),
+            ("freezeDamage", GameRuleValue::ValBool(v)) => VanillaGameRule::FreezeDamage(*v),
+            ("functionCommandLimit", GameRuleValue::ValVarU32(v)) => {
+                VanillaGameRule::FunctionCommandLimit(*v)
+            }
+            ("keepInventory", GameRuleValue::ValBool(v)) => VanillaGameRule::KeepInventory(*v),
+            ("maxCommandChainLength", GameRuleValue::ValVarU32(v)) => {
+                VanillaGameRule::MaxCommandChainLength(*v)
+            }
+            ("mobGriefing", GameRuleValue::ValBool(v)) => VanillaGameRule::MobGriefing(*v),
+            ("naturalRegeneration", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::NaturalRegeneration(*v)
+            }
+            ("playersSleepingPercentage", GameRuleValue::ValVarU32(v)) => {
+                VanillaGameRule::PlayersSleepingPercentage(*v)
+            }
+            ("projectilesCanBreakBlocks", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::ProjectilesCanBreakBlocks(*v)
+            }
+            ("pvp", GameRuleValue::ValBool(v)) => VanillaGameRule::Pvp(*v),
+            ("randomTickSpeed", GameRuleValue::ValVarU32(v)) => {
+                VanillaGameRule::RandomTickSpeed(*v)
+            }
+            ("recipesUnlock", GameRuleValue::ValBool(v)) => VanillaGameRule::RecipesUnlock(*v),
+            ("respawnBlocksExplode", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::RespawnBlocksExplode(*v)
+            }
+            ("sendCommandFeedback", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::SendCommandFeedback(*v)
+            }
+            ("showBorderEffect", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::ShowBorderEffect(*v)
+            }
+            ("showCoordinates", GameRuleValue::ValBool(v)) => VanillaGameRule::ShowCoordinates(*v),
+            ("showDaysPlayed", GameRuleValue::ValBool(v)) => VanillaGameRule::ShowDaysPlayed(*v),
+            ("showDeathMessages", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::ShowDeathMessages(*v)
+            }
+            ("showRecipeMessages", GameRuleValue::ValBool(v)) => {
+                VanillaGameRule::ShowRecipeMessages(*v)
+            }
+            ("showTags", GameRuleValue::ValBool(v)) => VanillaGameRule::ShowTags(*v),
+            ("spawnRadius", GameRuleValue::ValVarU32(v)) => VanillaGameRule::SpawnRadius(*v),
+            ("tntExplodes", GameRuleValue::ValBool(v)) => VanillaGameRule::TntExplodes(*v),
+            _ => return None,
+        })
+    }
+}
+
 impl ProtoCodec for GameRuleValue {
     fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
-        let int = VAR::<i32>::new(match self {
+        match self {
             GameRuleValue::ValBool(v) => {
-                v.proto_serialize(stream)?;
-                1
+                VAR::<u32>::new(1).proto_serialize(stream)?;
+                v.proto_serialize(stream)
             }
             GameRuleValue::ValVarU32(v) => {
-                VAR::<u32>::new(*v)
-                    .write(stream)
-                    .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
-                2
+                VAR::<u32>::new(2).proto_serialize(stream)?;
+                VAR::<u32>::new(*v).proto_serialize(stream)
             }
             GameRuleValue::ValF32(v) => {
-                LE::<f32>::new(*v)
-                    .write(stream)
-                    .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
-                3
+                VAR::<u32>::new(3).proto_serialize(stream)?;
+                LE::<f32>::new(*v).proto_serialize(stream)
             }
-        });
-
-        int.write(stream)
-            .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))
+        }
     }
 
     fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
-        Ok(
-            match VAR::<i32>::read(stream)
-                .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?
-                .into_inner()
-            {
-                1 => GameRuleValue::ValBool(bool::proto_deserialize(stream)?),
-                2 => GameRuleValue::ValVarU32(VAR::<u32>::proto_deserialize(stream)?.into_inner()),
-                3 => GameRuleValue::ValF32(LE::<f32>::proto_deserialize(stream)?.into_inner()),
-                other => {
-                    return Err(ProtoCodecError::InvalidEnumID(
-                        format!("{other:?}"),
-                        String::from("GameRuleValue"),
-                    ));
-                }
-            },
-        )
+        Ok(match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            1 => GameRuleValue::ValBool(bool::proto_deserialize(stream)?),
+            2 => GameRuleValue::ValVarU32(VAR::<u32>::proto_deserialize(stream)?.into_inner()),
+            3 => GameRuleValue::ValF32(LE::<f32>::proto_deserialize(stream)?.into_inner()),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("GameRuleValue"),
+                ));
+            }
+        })
     }
 }
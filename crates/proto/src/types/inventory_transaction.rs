@@ -1,12 +1,230 @@
-use bedrockrs_core::int::LE;
+use std::io::Cursor;
 
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_runtime_id::ActorRuntimeID;
+
+use crate::types::item_stack::ItemStack;
+use crate::types::network_block_pos::NetworkBlockPos;
+
+/// The slots of a container the client changed, sent together with a legacy request ID.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct LegacySetItemSlot {
+    pub container_id: u8,
+    #[len_repr(VAR::<u32>)]
+    pub slots: Vec<u8>,
+}
+
+/// The inventory the item of an [`InventoryAction`] comes from or goes to.
+#[derive(Debug, Clone)]
+pub enum InventoryActionSource {
+    Container {
+        window_id: i32,
+    },
+    Global,
+    /// Dropping or picking up items in the world.
+    World {
+        flags: u32,
+    },
+    Creative,
+    /// Crafting and other actions not moved to item stack requests yet.
+    Todo {
+        window_id: i32,
+    },
+}
+
+impl InventoryActionSource {
+    const CONTAINER_ID: u32 = 0;
+    const GLOBAL_ID: u32 = 1;
+    const WORLD_ID: u32 = 2;
+    const CREATIVE_ID: u32 = 3;
+    const TODO_ID: u32 = 99999;
+}
+
+impl ProtoCodec for InventoryActionSource {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            InventoryActionSource::Container { window_id } => {
+                VAR::<u32>::new(Self::CONTAINER_ID).proto_serialize(stream)?;
+                VAR::<i32>::new(*window_id).proto_serialize(stream)?;
+            }
+            InventoryActionSource::Global => {
+                VAR::<u32>::new(Self::GLOBAL_ID).proto_serialize(stream)?;
+            }
+            InventoryActionSource::World { flags } => {
+                VAR::<u32>::new(Self::WORLD_ID).proto_serialize(stream)?;
+                VAR::<u32>::new(*flags).proto_serialize(stream)?;
+            }
+            InventoryActionSource::Creative => {
+                VAR::<u32>::new(Self::CREATIVE_ID).proto_serialize(stream)?;
+            }
+            InventoryActionSource::Todo { window_id } => {
+                VAR::<u32>::new(Self::TODO_ID).proto_serialize(stream)?;
+                VAR::<i32>::new(*window_id).proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<u32>::proto_deserialize(stream)?.into_inner() {
+            Self::CONTAINER_ID => InventoryActionSource::Container {
+                window_id: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::GLOBAL_ID => InventoryActionSource::Global,
+            Self::WORLD_ID => InventoryActionSource::World {
+                flags: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::CREATIVE_ID => InventoryActionSource::Creative,
+            Self::TODO_ID => InventoryActionSource::Todo {
+                window_id: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+            },
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("InventoryActionSource"),
+                ))
+            }
+        })
+    }
+}
+
+/// A single change of a slot, the client sends the item it expects
+/// in the slot before and after the action.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct InventoryAction {
+    pub source: InventoryActionSource,
+    pub slot: VAR<u32>,
+    pub old_item: ItemStack,
+    pub new_item: ItemStack,
+}
+
+/// The kind of transaction and its data.
 #[derive(Debug, Clone)]
+pub enum InventoryTransactionType {
+    /// A plain move of items, described by the actions.
+    Normal,
+    /// Sent by the client when its inventory got out of sync,
+    /// the server should resend the inventory contents.
+    Mismatch,
+    UseItem(UseItemTransactionData),
+    UseItemOnEntity(UseItemOnEntityTransactionData),
+    ReleaseItem(ReleaseItemTransactionData),
+}
+
+impl InventoryTransactionType {
+    pub(crate) const NORMAL_ID: u32 = 0;
+    pub(crate) const MISMATCH_ID: u32 = 1;
+    pub(crate) const USE_ITEM_ID: u32 = 2;
+    pub(crate) const USE_ITEM_ON_ENTITY_ID: u32 = 3;
+    pub(crate) const RELEASE_ITEM_ID: u32 = 4;
+
+    pub(crate) fn id(&self) -> u32 {
+        match self {
+            InventoryTransactionType::Normal => Self::NORMAL_ID,
+            InventoryTransactionType::Mismatch => Self::MISMATCH_ID,
+            InventoryTransactionType::UseItem(_) => Self::USE_ITEM_ID,
+            InventoryTransactionType::UseItemOnEntity(_) => Self::USE_ITEM_ON_ENTITY_ID,
+            InventoryTransactionType::ReleaseItem(_) => Self::RELEASE_ITEM_ID,
+        }
+    }
+
+    /// Writes the data of the transaction, the ID is written separately in front of the actions.
+    pub(crate) fn serialize_data(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            InventoryTransactionType::Normal | InventoryTransactionType::Mismatch => Ok(()),
+            InventoryTransactionType::UseItem(data) => data.proto_serialize(stream),
+            InventoryTransactionType::UseItemOnEntity(data) => data.proto_serialize(stream),
+            InventoryTransactionType::ReleaseItem(data) => data.proto_serialize(stream),
+        }
+    }
+
+    pub(crate) fn deserialize_data(
+        id: u32,
+        stream: &mut Cursor<&[u8]>,
+    ) -> Result<Self, ProtoCodecError> {
+        Ok(match id {
+            Self::NORMAL_ID => InventoryTransactionType::Normal,
+            Self::MISMATCH_ID => InventoryTransactionType::Mismatch,
+            Self::USE_ITEM_ID => InventoryTransactionType::UseItem(
+                UseItemTransactionData::proto_deserialize(stream)?,
+            ),
+            Self::USE_ITEM_ON_ENTITY_ID => InventoryTransactionType::UseItemOnEntity(
+                UseItemOnEntityTransactionData::proto_deserialize(stream)?,
+            ),
+            Self::RELEASE_ITEM_ID => InventoryTransactionType::ReleaseItem(
+                ReleaseItemTransactionData::proto_deserialize(stream)?,
+            ),
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("InventoryTransactionType"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum UseItemAction {
+    ClickBlock = 0,
+    ClickAir = 1,
+    BreakBlock = 2,
+}
+
+/// Placing blocks, interacting with blocks or using an item in the air.
+#[derive(ProtoCodec, Debug, Clone)]
 pub struct UseItemTransactionData {
-    /// legacy_request_id is an ID that is only non-zero at times when sent by the client. The server should
-    /// always send 0 for this. When this field is not 0, the LegacySetItemSlots slice below will have values
-    /// in it.
-    /// legacy_request_id ties in with the ItemStackResponse packet. If this field is non-0, the server should
-    /// respond with an ItemStackResponse packet. Some inventory actions such as dropping an item out of the
-    /// hotbar are still one using this packet, and the ItemStackResponse packet needs to tie in with it.
-    legacy_request_id: LE<i32>,
+    pub action: UseItemAction,
+    pub block_position: NetworkBlockPos,
+    pub block_face: VAR<i32>,
+    pub hotbar_slot: VAR<i32>,
+    pub held_item: ItemStack,
+    pub player_position: Vec3<LE<f32>>,
+    /// The position on the block face that was clicked, relative to the block.
+    pub click_position: Vec3<LE<f32>>,
+    /// The network ID of the block that was clicked.
+    pub block_runtime_id: VAR<u32>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum UseItemOnEntityAction {
+    Interact = 0,
+    Attack = 1,
+}
+
+/// Attacking or interacting with an entity.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct UseItemOnEntityTransactionData {
+    pub target_runtime_id: ActorRuntimeID,
+    pub action: UseItemOnEntityAction,
+    pub hotbar_slot: VAR<i32>,
+    pub held_item: ItemStack,
+    pub player_position: Vec3<LE<f32>>,
+    /// The position on the entity that was clicked, relative to the entity.
+    pub click_position: Vec3<LE<f32>>,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<u32>)]
+pub enum ReleaseItemAction {
+    /// Releasing a bow or a trident.
+    Release = 0,
+    /// Finishing eating or drinking.
+    Consume = 1,
+}
+
+/// Releasing a used item, like shooting a bow.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ReleaseItemTransactionData {
+    pub action: ReleaseItemAction,
+    pub hotbar_slot: VAR<i32>,
+    pub held_item: ItemStack,
+    pub head_position: Vec3<LE<f32>>,
 }
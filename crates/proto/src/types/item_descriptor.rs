@@ -0,0 +1,129 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// Describes which items are accepted, used for recipe ingredients.
+#[derive(Debug, Clone)]
+pub enum ItemDescriptor {
+    Invalid,
+    /// A specific item, a metadata of `i16::MAX` accepts any metadata.
+    Default {
+        network_id: i16,
+        metadata: i16,
+    },
+    MoLang {
+        expression: String,
+        version: u8,
+    },
+    /// Any item with the given item tag, e.g. `"minecraft:planks"`.
+    ItemTag {
+        tag: String,
+    },
+    /// An item referenced by its identifier.
+    Deferred {
+        name: String,
+        metadata: i16,
+    },
+    ComplexAlias {
+        name: String,
+    },
+}
+
+impl ItemDescriptor {
+    const INVALID_ID: u8 = 0;
+    const DEFAULT_ID: u8 = 1;
+    const MOLANG_ID: u8 = 2;
+    const ITEM_TAG_ID: u8 = 3;
+    const DEFERRED_ID: u8 = 4;
+    const COMPLEX_ALIAS_ID: u8 = 5;
+}
+
+impl ProtoCodec for ItemDescriptor {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            ItemDescriptor::Invalid => {
+                Self::INVALID_ID.proto_serialize(stream)?;
+            }
+            ItemDescriptor::Default {
+                network_id,
+                metadata,
+            } => {
+                Self::DEFAULT_ID.proto_serialize(stream)?;
+                LE::<i16>::new(*network_id).proto_serialize(stream)?;
+                if *network_id != 0 {
+                    LE::<i16>::new(*metadata).proto_serialize(stream)?;
+                }
+            }
+            ItemDescriptor::MoLang {
+                expression,
+                version,
+            } => {
+                Self::MOLANG_ID.proto_serialize(stream)?;
+                expression.proto_serialize(stream)?;
+                version.proto_serialize(stream)?;
+            }
+            ItemDescriptor::ItemTag { tag } => {
+                Self::ITEM_TAG_ID.proto_serialize(stream)?;
+                tag.proto_serialize(stream)?;
+            }
+            ItemDescriptor::Deferred { name, metadata } => {
+                Self::DEFERRED_ID.proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+                LE::<i16>::new(*metadata).proto_serialize(stream)?;
+            }
+            ItemDescriptor::ComplexAlias { name } => {
+                Self::COMPLEX_ALIAS_ID.proto_serialize(stream)?;
+                name.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            Self::INVALID_ID => ItemDescriptor::Invalid,
+            Self::DEFAULT_ID => {
+                let network_id = LE::<i16>::proto_deserialize(stream)?.into_inner();
+                let metadata = match network_id {
+                    0 => 0,
+                    _ => LE::<i16>::proto_deserialize(stream)?.into_inner(),
+                };
+
+                ItemDescriptor::Default {
+                    network_id,
+                    metadata,
+                }
+            }
+            Self::MOLANG_ID => ItemDescriptor::MoLang {
+                expression: String::proto_deserialize(stream)?,
+                version: u8::proto_deserialize(stream)?,
+            },
+            Self::ITEM_TAG_ID => ItemDescriptor::ItemTag {
+                tag: String::proto_deserialize(stream)?,
+            },
+            Self::DEFERRED_ID => ItemDescriptor::Deferred {
+                name: String::proto_deserialize(stream)?,
+                metadata: LE::<i16>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::COMPLEX_ALIAS_ID => ItemDescriptor::ComplexAlias {
+                name: String::proto_deserialize(stream)?,
+            },
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ItemDescriptor"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemDescriptorCount {
+    pub descriptor: ItemDescriptor,
+    pub count: VAR<i32>,
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_nbt::NbtTag;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<i32>)]
+pub enum ItemVersion {
+    Legacy = 0,
+    DataDriven = 1,
+    None = 2,
+}
+
+/// An item sent in the [`StartGamePacket`](crate::packets::start_game::StartGamePacket),
+/// maps an item identifier to the runtime ID used by all item-carrying packets.
+#[derive(Debug, Clone)]
+pub struct ItemEntry {
+    /// The identifier of the item, e.g. `"minecraft:diamond"`.
+    pub name: String,
+    pub runtime_id: i16,
+    /// If the item is defined by components, these get sent to the client
+    /// via the item component packet.
+    pub component_based: bool,
+    pub version: ItemVersion,
+    /// The network component data of the item, only used by component based items.
+    pub nbt: NbtTag,
+}
+
+impl ItemEntry {
+    pub fn new(name: impl Into<String>, runtime_id: i16, component_based: bool) -> Self {
+        Self {
+            name: name.into(),
+            runtime_id,
+            component_based,
+            version: match component_based {
+                true => ItemVersion::DataDriven,
+                false => ItemVersion::Legacy,
+            },
+            nbt: NbtTag::Compound(HashMap::new()),
+        }
+    }
+}
+
+impl ProtoCodec for ItemEntry {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.name.proto_serialize(stream)?;
+        LE::<i16>::new(self.runtime_id).proto_serialize(stream)?;
+        self.component_based.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let name = String::proto_deserialize(stream)?;
+        let runtime_id = LE::<i16>::proto_deserialize(stream)?.into_inner();
+        let component_based = bool::proto_deserialize(stream)?;
+
+        // The version and component data aren't part of the start game data
+        Ok(Self::new(name, runtime_id, component_based))
+    }
+}
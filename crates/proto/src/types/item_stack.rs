@@ -0,0 +1,313 @@
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_nbt::endian::little_endian::NbtLittleEndian;
+use bedrockrs_nbt::NbtTag;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+
+/// The marker written in front of the user data nbt of an item.
+const USER_DATA_NBT_MARKER: i16 = -1;
+/// The version of the user data nbt serialization.
+const USER_DATA_NBT_VERSION: u8 = 1;
+
+/// An item as it is stored in inventories, containers and transactions.
+/// Air is represented by a network ID of `0`, in which case all other fields are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ItemStack {
+    /// The runtime ID of the item, see [`ItemEntry`](crate::types::item_entry::ItemEntry).
+    pub network_id: i32,
+    pub count: u16,
+    /// The aux value of the item, e.g. the damage of tools or the color of wool.
+    pub metadata: u32,
+    /// The server side ID of this stack, used by item stack requests.
+    pub stack_network_id: Option<i32>,
+    /// The network ID of the block state this item places, `0` if it isn't a block item.
+    pub block_runtime_id: i32,
+    pub extra_data: ItemStackExtraData,
+}
+
+/// An item without a stack network ID, used by recipes and the creative inventory.
+/// Air is represented by a network ID of `0`, in which case all other fields are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ItemStackDescriptor {
+    /// The runtime ID of the item, see [`ItemEntry`](crate::types::item_entry::ItemEntry).
+    pub network_id: i32,
+    pub count: u16,
+    /// The aux value of the item, e.g. the damage of tools or the color of wool.
+    pub metadata: u32,
+    /// The network ID of the block state this item places, `0` if it isn't a block item.
+    pub block_runtime_id: i32,
+    pub extra_data: ItemStackExtraData,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ItemStackExtraData {
+    /// The user data of the item like its custom name, lore or enchantments.
+    pub nbt: Option<NbtTag>,
+    /// The block identifiers this item can be placed on in adventure mode.
+    pub can_place_on: Vec<String>,
+    /// The block identifiers this item can destroy in adventure mode.
+    pub can_destroy: Vec<String>,
+    /// The tick the shield started blocking at, must only be set for shields.
+    pub shield_blocking_tick: Option<i64>,
+}
+
+impl ItemStack {
+    pub fn air() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn is_air(&self) -> bool {
+        self.network_id == 0
+    }
+}
+
+impl ItemStackDescriptor {
+    pub fn air() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn is_air(&self) -> bool {
+        self.network_id == 0
+    }
+}
+
+impl From<ItemStack> for ItemStackDescriptor {
+    fn from(value: ItemStack) -> Self {
+        Self {
+            network_id: value.network_id,
+            count: value.count,
+            metadata: value.metadata,
+            block_runtime_id: value.block_runtime_id,
+            extra_data: value.extra_data,
+        }
+    }
+}
+
+impl From<ItemStackDescriptor> for ItemStack {
+    fn from(value: ItemStackDescriptor) -> Self {
+        Self {
+            network_id: value.network_id,
+            count: value.count,
+            metadata: value.metadata,
+            stack_network_id: None,
+            block_runtime_id: value.block_runtime_id,
+            extra_data: value.extra_data,
+        }
+    }
+}
+
+impl ProtoCodec for ItemStack {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<i32>::new(self.network_id).proto_serialize(stream)?;
+
+        if self.is_air() {
+            return Ok(());
+        }
+
+        LE::<u16>::new(self.count).proto_serialize(stream)?;
+        VAR::<u32>::new(self.metadata).proto_serialize(stream)?;
+
+        match self.stack_network_id {
+            None => false.proto_serialize(stream)?,
+            Some(id) => {
+                true.proto_serialize(stream)?;
+                VAR::<i32>::new(id).proto_serialize(stream)?;
+            }
+        }
+
+        VAR::<i32>::new(self.block_runtime_id).proto_serialize(stream)?;
+        self.extra_data.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let network_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+
+        if network_id == 0 {
+            return Ok(Self::air());
+        }
+
+        let count = LE::<u16>::proto_deserialize(stream)?.into_inner();
+        let metadata = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        let stack_network_id = match bool::proto_deserialize(stream)? {
+            false => None,
+            true => Some(VAR::<i32>::proto_deserialize(stream)?.into_inner()),
+        };
+
+        let block_runtime_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        let extra_data = ItemStackExtraData::proto_deserialize(stream)?;
+
+        Ok(Self {
+            network_id,
+            count,
+            metadata,
+            stack_network_id,
+            block_runtime_id,
+            extra_data,
+        })
+    }
+}
+
+impl ProtoCodec for ItemStackDescriptor {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        VAR::<i32>::new(self.network_id).proto_serialize(stream)?;
+
+        if self.is_air() {
+            return Ok(());
+        }
+
+        LE::<u16>::new(self.count).proto_serialize(stream)?;
+        VAR::<u32>::new(self.metadata).proto_serialize(stream)?;
+        VAR::<i32>::new(self.block_runtime_id).proto_serialize(stream)?;
+        self.extra_data.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let network_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+
+        if network_id == 0 {
+            return Ok(Self::air());
+        }
+
+        let count = LE::<u16>::proto_deserialize(stream)?.into_inner();
+        let metadata = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let block_runtime_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        let extra_data = ItemStackExtraData::proto_deserialize(stream)?;
+
+        Ok(Self {
+            network_id,
+            count,
+            metadata,
+            block_runtime_id,
+            extra_data,
+        })
+    }
+}
+
+/// The extra data is sent as a length prefixed blob, its content uses
+/// little endian integers and little endian (non network) nbt.
+impl ProtoCodec for ItemStackExtraData {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let mut buf = vec![];
+
+        match self.nbt {
+            None => LE::<i16>::new(0).proto_serialize(&mut buf)?,
+            Some(ref nbt) => {
+                LE::<i16>::new(USER_DATA_NBT_MARKER).proto_serialize(&mut buf)?;
+                USER_DATA_NBT_VERSION.proto_serialize(&mut buf)?;
+                nbt.nbt_serialize::<NbtLittleEndian>("", &mut buf)?;
+            }
+        }
+
+        write_string_list(&self.can_place_on, &mut buf)?;
+        write_string_list(&self.can_destroy, &mut buf)?;
+
+        if let Some(tick) = self.shield_blocking_tick {
+            LE::<i64>::new(tick).proto_serialize(&mut buf)?;
+        }
+
+        let len = buf.len().try_into()?;
+        VAR::<u32>::new(len).proto_serialize(stream)?;
+        stream.extend_from_slice(&buf);
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let len = VAR::<u32>::proto_deserialize(stream)?
+            .into_inner()
+            .try_into()?;
+
+        let remaining = stream
+            .get_ref()
+            .len()
+            .saturating_sub(stream.position() as usize);
+        if len > remaining {
+            return Err(ProtoCodecError::FormatMismatch(format!(
+                "Item extra data of length {len} is longer than the {remaining} remaining bytes"
+            )));
+        }
+
+        let mut buf = vec![0u8; len];
+        stream
+            .read_exact(&mut buf)
+            .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
+        let mut buf = Cursor::new(buf.as_slice());
+
+        let nbt = match LE::<i16>::proto_deserialize(&mut buf)?.into_inner() {
+            0 => None,
+            USER_DATA_NBT_MARKER => {
+                let version = u8::proto_deserialize(&mut buf)?;
+                if version != USER_DATA_NBT_VERSION {
+                    return Err(ProtoCodecError::FormatMismatch(format!(
+                        "Unsupported item user data version: {version}"
+                    )));
+                }
+
+                Some(NbtTag::nbt_deserialize::<NbtLittleEndian>(&mut buf)?.1)
+            }
+            other => {
+                return Err(ProtoCodecError::FormatMismatch(format!(
+                    "Invalid item user data marker: {other}"
+                )))
+            }
+        };
+
+        let can_place_on = read_string_list(&mut buf)?;
+        let can_destroy = read_string_list(&mut buf)?;
+
+        // Only shields have a blocking tick at the end of their extra data
+        let shield_blocking_tick = match (buf.position() as usize) < len {
+            true => Some(LE::<i64>::proto_deserialize(&mut buf)?.into_inner()),
+            false => None,
+        };
+
+        Ok(Self {
+            nbt,
+            can_place_on,
+            can_destroy,
+            shield_blocking_tick,
+        })
+    }
+}
+
+fn write_string_list(list: &[String], stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+    LE::<i32>::new(list.len().try_into()?).proto_serialize(stream)?;
+
+    for string in list {
+        LE::<i16>::new(string.len().try_into()?).proto_serialize(stream)?;
+        stream.extend_from_slice(string.as_bytes());
+    }
+
+    Ok(())
+}
+
+fn read_string_list(stream: &mut Cursor<&[u8]>) -> Result<Vec<String>, ProtoCodecError> {
+    let len = LE::<i32>::proto_deserialize(stream)?.into_inner();
+
+    let mut list = Vec::with_capacity(len.clamp(0, 64) as usize);
+
+    for _ in 0..len {
+        let string_len = LE::<i16>::proto_deserialize(stream)?
+            .into_inner()
+            .try_into()?;
+
+        let mut string_buf = vec![0u8; string_len];
+        stream
+            .read_exact(&mut string_buf)
+            .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
+
+        list.push(String::from_utf8(string_buf)?);
+    }
+
+    Ok(list)
+}
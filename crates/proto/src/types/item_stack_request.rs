@@ -0,0 +1,442 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::info::PROTOCOL_VERSION_1_20_80;
+use crate::types::container_name::ContainerName;
+use crate::types::item_descriptor::ItemDescriptorCount;
+use crate::types::item_stack::ItemStackDescriptor;
+
+/// A request of the client to change its inventory, the server answers it with an
+/// [`ItemStackResponse`](crate::types::item_stack_response::ItemStackResponse).
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct ItemStackRequest {
+    /// A unique ID for the request, negative and decreasing with every request.
+    pub request_id: VAR<i32>,
+    #[len_repr(VAR::<u32>)]
+    pub actions: Vec<ItemStackRequestAction>,
+    /// Strings that need to be filtered by the server, e.g. the new name in an anvil.
+    #[len_repr(VAR::<u32>)]
+    pub filter_strings: Vec<String>,
+    pub filter_cause: LE<i32>,
+}
+
+/// A slot referenced by an [`ItemStackRequestAction`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ItemStackRequestSlotInfo {
+    pub container: ContainerName,
+    /// The ID of dynamic containers like bundles, sent since 1.20.80.
+    pub dynamic_id: LE<u32>,
+    pub slot: u8,
+    /// The stack network ID of the item the client expects in the slot,
+    /// negative IDs refer to items created earlier in the same request.
+    pub stack_network_id: VAR<i32>,
+}
+
+impl ProtoCodec for ItemStackRequestSlotInfo {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.container.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.dynamic_id.proto_serialize(stream)?;
+        }
+        self.slot.proto_serialize(stream)?;
+        self.stack_network_id.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let container = ContainerName::proto_deserialize(stream)?;
+        let dynamic_id = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => LE::<u32>::proto_deserialize(stream)?,
+            false => LE::new(0),
+        };
+
+        Ok(Self {
+            container,
+            dynamic_id,
+            slot: u8::proto_deserialize(stream)?,
+            stack_network_id: VAR::<i32>::proto_deserialize(stream)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ItemStackRequestAction {
+    Take {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    Place {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    Swap {
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    Drop {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        randomly: bool,
+    },
+    /// Destroying items in creative mode.
+    Destroy {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+    },
+    /// Consuming an input of a crafting action.
+    Consume {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+    },
+    /// Chooses which of the results of a crafting action is created.
+    Create {
+        results_slot: u8,
+    },
+    PlaceInContainer {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    TakeOutContainer {
+        count: u8,
+        source: ItemStackRequestSlotInfo,
+        destination: ItemStackRequestSlotInfo,
+    },
+    LabTableCombine,
+    BeaconPayment {
+        primary_effect: i32,
+        secondary_effect: i32,
+    },
+    MineBlock {
+        hotbar_slot: i32,
+        predicted_durability: i32,
+        stack_network_id: i32,
+    },
+    CraftRecipe {
+        recipe_network_id: u32,
+    },
+    CraftRecipeAuto {
+        recipe_network_id: u32,
+        times_crafted: u8,
+        ingredients: Vec<ItemDescriptorCount>,
+    },
+    CraftCreative {
+        creative_item_network_id: u32,
+    },
+    CraftRecipeOptional {
+        recipe_network_id: u32,
+        filter_string_index: i32,
+    },
+    CraftGrindstone {
+        recipe_network_id: u32,
+        cost: i32,
+    },
+    CraftLoom {
+        pattern: String,
+    },
+    CraftNonImplemented,
+    /// Sent after crafting actions with the results the client expects,
+    /// shouldn't be trusted by the server.
+    CraftResultsDeprecated {
+        result_items: Vec<ItemStackDescriptor>,
+        times_crafted: u8,
+    },
+}
+
+impl ItemStackRequestAction {
+    const TAKE_ID: u8 = 0;
+    const PLACE_ID: u8 = 1;
+    const SWAP_ID: u8 = 2;
+    const DROP_ID: u8 = 3;
+    const DESTROY_ID: u8 = 4;
+    const CONSUME_ID: u8 = 5;
+    const CREATE_ID: u8 = 6;
+    const PLACE_IN_CONTAINER_ID: u8 = 7;
+    const TAKE_OUT_CONTAINER_ID: u8 = 8;
+    const LAB_TABLE_COMBINE_ID: u8 = 9;
+    const BEACON_PAYMENT_ID: u8 = 10;
+    const MINE_BLOCK_ID: u8 = 11;
+    const CRAFT_RECIPE_ID: u8 = 12;
+    const CRAFT_RECIPE_AUTO_ID: u8 = 13;
+    const CRAFT_CREATIVE_ID: u8 = 14;
+    const CRAFT_RECIPE_OPTIONAL_ID: u8 = 15;
+    const CRAFT_GRINDSTONE_ID: u8 = 16;
+    const CRAFT_LOOM_ID: u8 = 17;
+    const CRAFT_NON_IMPLEMENTED_ID: u8 = 18;
+    const CRAFT_RESULTS_DEPRECATED_ID: u8 = 19;
+}
+
+impl ProtoCodec for ItemStackRequestAction {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            ItemStackRequestAction::Take {
+                count,
+                source,
+                destination,
+            } => {
+                Self::TAKE_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Place {
+                count,
+                source,
+                destination,
+            } => {
+                Self::PLACE_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Swap {
+                source,
+                destination,
+            } => {
+                Self::SWAP_ID.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Drop {
+                count,
+                source,
+                randomly,
+            } => {
+                Self::DROP_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                randomly.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Destroy { count, source } => {
+                Self::DESTROY_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Consume { count, source } => {
+                Self::CONSUME_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::Create { results_slot } => {
+                Self::CREATE_ID.proto_serialize(stream)?;
+                results_slot.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::PlaceInContainer {
+                count,
+                source,
+                destination,
+            } => {
+                Self::PLACE_IN_CONTAINER_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::TakeOutContainer {
+                count,
+                source,
+                destination,
+            } => {
+                Self::TAKE_OUT_CONTAINER_ID.proto_serialize(stream)?;
+                count.proto_serialize(stream)?;
+                source.proto_serialize(stream)?;
+                destination.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::LabTableCombine => {
+                Self::LAB_TABLE_COMBINE_ID.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::BeaconPayment {
+                primary_effect,
+                secondary_effect,
+            } => {
+                Self::BEACON_PAYMENT_ID.proto_serialize(stream)?;
+                VAR::<i32>::new(*primary_effect).proto_serialize(stream)?;
+                VAR::<i32>::new(*secondary_effect).proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::MineBlock {
+                hotbar_slot,
+                predicted_durability,
+                stack_network_id,
+            } => {
+                Self::MINE_BLOCK_ID.proto_serialize(stream)?;
+                VAR::<i32>::new(*hotbar_slot).proto_serialize(stream)?;
+                VAR::<i32>::new(*predicted_durability).proto_serialize(stream)?;
+                VAR::<i32>::new(*stack_network_id).proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftRecipe { recipe_network_id } => {
+                Self::CRAFT_RECIPE_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(*recipe_network_id).proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftRecipeAuto {
+                recipe_network_id,
+                times_crafted,
+                ingredients,
+            } => {
+                Self::CRAFT_RECIPE_AUTO_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(*recipe_network_id).proto_serialize(stream)?;
+                times_crafted.proto_serialize(stream)?;
+                // The ingredients are prefixed with a single byte length
+                u8::try_from(ingredients.len())?.proto_serialize(stream)?;
+                for ingredient in ingredients {
+                    ingredient.proto_serialize(stream)?;
+                }
+            }
+            ItemStackRequestAction::CraftCreative {
+                creative_item_network_id,
+            } => {
+                Self::CRAFT_CREATIVE_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(*creative_item_network_id).proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftRecipeOptional {
+                recipe_network_id,
+                filter_string_index,
+            } => {
+                Self::CRAFT_RECIPE_OPTIONAL_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(*recipe_network_id).proto_serialize(stream)?;
+                LE::<i32>::new(*filter_string_index).proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftGrindstone {
+                recipe_network_id,
+                cost,
+            } => {
+                Self::CRAFT_GRINDSTONE_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(*recipe_network_id).proto_serialize(stream)?;
+                VAR::<i32>::new(*cost).proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftLoom { pattern } => {
+                Self::CRAFT_LOOM_ID.proto_serialize(stream)?;
+                pattern.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftNonImplemented => {
+                Self::CRAFT_NON_IMPLEMENTED_ID.proto_serialize(stream)?;
+            }
+            ItemStackRequestAction::CraftResultsDeprecated {
+                result_items,
+                times_crafted,
+            } => {
+                Self::CRAFT_RESULTS_DEPRECATED_ID.proto_serialize(stream)?;
+                VAR::<u32>::new(result_items.len().try_into()?).proto_serialize(stream)?;
+                for item in result_items {
+                    item.proto_serialize(stream)?;
+                }
+                times_crafted.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            Self::TAKE_ID => ItemStackRequestAction::Take {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::PLACE_ID => ItemStackRequestAction::Place {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::SWAP_ID => ItemStackRequestAction::Swap {
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::DROP_ID => ItemStackRequestAction::Drop {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                randomly: bool::proto_deserialize(stream)?,
+            },
+            Self::DESTROY_ID => ItemStackRequestAction::Destroy {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::CONSUME_ID => ItemStackRequestAction::Consume {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::CREATE_ID => ItemStackRequestAction::Create {
+                results_slot: u8::proto_deserialize(stream)?,
+            },
+            Self::PLACE_IN_CONTAINER_ID => ItemStackRequestAction::PlaceInContainer {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::TAKE_OUT_CONTAINER_ID => ItemStackRequestAction::TakeOutContainer {
+                count: u8::proto_deserialize(stream)?,
+                source: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+                destination: ItemStackRequestSlotInfo::proto_deserialize(stream)?,
+            },
+            Self::LAB_TABLE_COMBINE_ID => ItemStackRequestAction::LabTableCombine,
+            Self::BEACON_PAYMENT_ID => ItemStackRequestAction::BeaconPayment {
+                primary_effect: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+                secondary_effect: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::MINE_BLOCK_ID => ItemStackRequestAction::MineBlock {
+                hotbar_slot: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+                predicted_durability: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+                stack_network_id: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::CRAFT_RECIPE_ID => ItemStackRequestAction::CraftRecipe {
+                recipe_network_id: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::CRAFT_RECIPE_AUTO_ID => {
+                let recipe_network_id = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+                let times_crafted = u8::proto_deserialize(stream)?;
+
+                let len = u8::proto_deserialize(stream)?;
+                let mut ingredients = Vec::with_capacity(len as usize);
+                for _ in 0..len {
+                    ingredients.push(ItemDescriptorCount::proto_deserialize(stream)?);
+                }
+
+                ItemStackRequestAction::CraftRecipeAuto {
+                    recipe_network_id,
+                    times_crafted,
+                    ingredients,
+                }
+            }
+            Self::CRAFT_CREATIVE_ID => ItemStackRequestAction::CraftCreative {
+                creative_item_network_id: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::CRAFT_RECIPE_OPTIONAL_ID => ItemStackRequestAction::CraftRecipeOptional {
+                recipe_network_id: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+                filter_string_index: LE::<i32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::CRAFT_GRINDSTONE_ID => ItemStackRequestAction::CraftGrindstone {
+                recipe_network_id: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+                cost: VAR::<i32>::proto_deserialize(stream)?.into_inner(),
+            },
+            Self::CRAFT_LOOM_ID => ItemStackRequestAction::CraftLoom {
+                pattern: String::proto_deserialize(stream)?,
+            },
+            Self::CRAFT_NON_IMPLEMENTED_ID => ItemStackRequestAction::CraftNonImplemented,
+            Self::CRAFT_RESULTS_DEPRECATED_ID => {
+                let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+                let mut result_items = vec![];
+                for _ in 0..len {
+                    result_items.push(ItemStackDescriptor::proto_deserialize(stream)?);
+                }
+
+                ItemStackRequestAction::CraftResultsDeprecated {
+                    result_items,
+                    times_crafted: u8::proto_deserialize(stream)?,
+                }
+            }
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ItemStackRequestAction"),
+                ))
+            }
+        })
+    }
+}
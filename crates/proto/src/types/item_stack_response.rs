@@ -0,0 +1,222 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+
+use crate::info::{PROTOCOL_VERSION_1_20_80, PROTOCOL_VERSION_1_21_0};
+use crate::types::container_name::ContainerName;
+
+/// The answer to an [`ItemStackRequest`](crate::types::item_stack_request::ItemStackRequest),
+/// build it via the [`ItemStackResponseBuilder`](crate::inventory::response::ItemStackResponseBuilder).
+#[derive(Debug, Clone)]
+pub struct ItemStackResponse {
+    pub status: ItemStackResponseStatus,
+    pub request_id: i32,
+    /// The new contents of all changed slots, only sent if the request succeeded.
+    pub containers: Vec<ItemStackResponseContainer>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemStackResponseContainer {
+    pub container: ContainerName,
+    /// The ID of dynamic containers like bundles, sent since 1.20.80.
+    pub dynamic_id: LE<u32>,
+    pub slots: Vec<ItemStackResponseSlot>,
+}
+
+impl ProtoCodec for ItemStackResponseContainer {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.container.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.dynamic_id.proto_serialize(stream)?;
+        }
+
+        VAR::<u32>::new(self.slots.len().try_into()?).proto_serialize(stream)?;
+        for slot in &self.slots {
+            slot.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let container = ContainerName::proto_deserialize(stream)?;
+        let dynamic_id = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => LE::<u32>::proto_deserialize(stream)?,
+            false => LE::new(0),
+        };
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut slots = Vec::with_capacity(len.min(1024).try_into()?);
+        for _ in 0..len {
+            slots.push(ItemStackResponseSlot::proto_deserialize(stream)?);
+        }
+
+        Ok(Self {
+            container,
+            dynamic_id,
+            slots,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemStackResponseSlot {
+    pub slot: u8,
+    pub hotbar_slot: u8,
+    pub count: u8,
+    pub stack_network_id: i32,
+    pub custom_name: String,
+    pub filtered_custom_name: String,
+    pub durability_correction: i32,
+}
+
+impl ProtoCodec for ItemStackResponse {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.status.proto_serialize(stream)?;
+        VAR::<i32>::new(self.request_id).proto_serialize(stream)?;
+
+        if self.status == ItemStackResponseStatus::Ok {
+            VAR::<u32>::new(self.containers.len().try_into()?).proto_serialize(stream)?;
+            for container in &self.containers {
+                container.proto_serialize(stream)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let status = ItemStackResponseStatus::proto_deserialize(stream)?;
+        let request_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+
+        let mut containers = vec![];
+        if status == ItemStackResponseStatus::Ok {
+            let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+            for _ in 0..len {
+                containers.push(ItemStackResponseContainer::proto_deserialize(stream)?);
+            }
+        }
+
+        Ok(Self {
+            status,
+            request_id,
+            containers,
+        })
+    }
+}
+
+impl ProtoCodec for ItemStackResponseSlot {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.slot.proto_serialize(stream)?;
+        self.hotbar_slot.proto_serialize(stream)?;
+        self.count.proto_serialize(stream)?;
+        VAR::<i32>::new(self.stack_network_id).proto_serialize(stream)?;
+        self.custom_name.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.filtered_custom_name.proto_serialize(stream)?;
+        }
+        VAR::<i32>::new(self.durability_correction).proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let slot = u8::proto_deserialize(stream)?;
+        let hotbar_slot = u8::proto_deserialize(stream)?;
+        let count = u8::proto_deserialize(stream)?;
+        let stack_network_id = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        let custom_name = String::proto_deserialize(stream)?;
+        let filtered_custom_name = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => String::proto_deserialize(stream)?,
+            false => String::new(),
+        };
+        let durability_correction = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+
+        Ok(Self {
+            slot,
+            hotbar_slot,
+            count,
+            stack_network_id,
+            custom_name,
+            filtered_custom_name,
+            durability_correction,
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum ItemStackResponseStatus {
+    Ok = 0,
+    Error = 1,
+    InvalidRequestActionType = 2,
+    ActionRequestNotAllowed = 3,
+    ScreenHandlerEndRequestFailed = 4,
+    ItemRequestActionHandlerCommitFailed = 5,
+    InvalidRequestCraftActionType = 6,
+    InvalidCraftRequest = 7,
+    InvalidCraftRequestScreen = 8,
+    InvalidCraftResult = 9,
+    InvalidCraftResultIndex = 10,
+    InvalidCraftResultItem = 11,
+    InvalidItemNetId = 12,
+    MissingCreatedOutputContainer = 13,
+    FailedToSetCreatedItemOutputSlot = 14,
+    RequestAlreadyInProgress = 15,
+    FailedToInitSparseContainer = 16,
+    ResultTransferFailed = 17,
+    ExpectedItemSlotNotFullyConsumed = 18,
+    ExpectedAnywhereItemNotFullyConsumed = 19,
+    ItemAlreadyConsumedFromSlot = 20,
+    ConsumedTooMuchFromSlot = 21,
+    MismatchSlotExpectedConsumedItem = 22,
+    MismatchSlotExpectedConsumedItemNetIdVariant = 23,
+    FailedToMatchExpectedSlotConsumedItem = 24,
+    FailedToMatchExpectedAllowedAnywhereConsumedItem = 25,
+    ConsumedItemOutOfAllowedSlotRange = 26,
+    ConsumedItemNotAllowed = 27,
+    PlayerNotInCreativeMode = 28,
+    InvalidExperimentalRecipeRequest = 29,
+    FailedToCraftCreative = 30,
+    FailedToGetLevelRecipe = 31,
+    FailedToFindRecipeByNetId = 32,
+    MismatchedCraftingSize = 33,
+    MissingInputSparseContainer = 34,
+    MismatchedRecipeForInputGridItems = 35,
+    EmptyCraftResults = 36,
+    FailedToEnchant = 37,
+    MissingInputItem = 38,
+    InsufficientPlayerLevelToEnchant = 39,
+    MissingMaterialItem = 40,
+    MissingActor = 41,
+    UnknownPrimaryEffect = 42,
+    PrimaryEffectOutOfRange = 43,
+    PrimaryEffectUnavailable = 44,
+    SecondaryEffectOutOfRange = 45,
+    SecondaryEffectUnavailable = 46,
+    DstContainerEqualToCreatedOutputContainer = 47,
+    DstContainerAndSlotEqualToSrcContainerAndSlot = 48,
+    FailedToValidateSrcSlot = 49,
+    FailedToValidateDstSlot = 50,
+    InvalidAdjustedAmount = 51,
+    InvalidItemSetType = 52,
+    InvalidTransferAmount = 53,
+    CannotSwapItem = 54,
+    CannotPlaceItem = 55,
+    UnhandledItemSetType = 56,
+    InvalidRemovedAmount = 57,
+    InvalidRegion = 58,
+    CannotDropItem = 59,
+    CannotDestroyItem = 60,
+    InvalidSourceContainer = 61,
+    ItemNotConsumed = 62,
+    InvalidNumCrafts = 63,
+    InvalidCraftResultStackSize = 64,
+    CannotRemoveItem = 65,
+    CannotConsumeItem = 66,
+    ScreenStackError = 67,
+}
@@ -0,0 +1,374 @@
+/// Set on legacy particle IDs to send them as a level event.
+const PARTICLE_LEGACY_FLAG: i32 = 0x4000;
+
+/// The vanilla level events, used by [`LevelEventPacket`](crate::packets::level_event::LevelEventPacket)
+/// and [`LevelEventGenericPacket`](crate::packets::level_event_generic::LevelEventGenericPacket).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LevelEventType {
+    SoundClick,
+    SoundClickFail,
+    SoundLaunch,
+    SoundOpenDoor,
+    SoundFizz,
+    SoundFuse,
+    SoundPlayRecording,
+    SoundGhastWarning,
+    SoundGhastFireball,
+    SoundBlazeFireball,
+    SoundZombieWoodenDoor,
+    SoundZombieDoorCrash,
+    SoundZombieInfected,
+    SoundZombieConverted,
+    SoundEndermanTeleport,
+    SoundAnvilBroken,
+    SoundAnvilUsed,
+    SoundAnvilLand,
+    SoundInfinityArrowPickup,
+    SoundTeleportEnderPearl,
+    SoundAddItem,
+    SoundItemFrameBreak,
+    SoundItemFramePlace,
+    SoundItemFrameRemoveItem,
+    SoundItemFrameRotateItem,
+    SoundExperienceOrbPickup,
+    SoundTotemUsed,
+    SoundArmorStandBreak,
+    SoundArmorStandHit,
+    SoundArmorStandLand,
+    SoundArmorStandPlace,
+    SoundPointedDripstoneLand,
+    SoundDyeUsed,
+    SoundInkSacUsed,
+    SoundAmethystResonate,
+    QueueCustomMusic,
+    PlayCustomMusic,
+    StopCustomMusic,
+    SetMusicVolume,
+    ParticlesShoot,
+    /// The data is the runtime ID of the destroyed block.
+    ParticlesDestroyBlock,
+    ParticlesPotionSplash,
+    ParticlesEyeOfEnderDeath,
+    ParticlesMobBlockSpawn,
+    ParticlesCropGrowth,
+    ParticlesSoundGuardianGhost,
+    ParticlesDeathSmoke,
+    ParticlesDenyBlock,
+    ParticlesGenericSpawn,
+    ParticlesDragonEgg,
+    ParticlesCropEaten,
+    ParticlesCritical,
+    ParticlesTeleport,
+    ParticlesCrackBlock,
+    ParticlesBubble,
+    ParticlesEvaporate,
+    ParticlesDestroyArmorStand,
+    ParticlesBreakingEgg,
+    ParticlesDestroyEgg,
+    ParticlesEvaporateWater,
+    ParticlesDestroyBlockNoSound,
+    ParticlesKnockbackRoar,
+    ParticlesTeleportTrail,
+    ParticlesPointCloud,
+    ParticlesExplosion,
+    ParticlesBlockExplosion,
+    ParticlesVibrationSignal,
+    ParticlesDripstoneDrip,
+    ParticlesFizzEffect,
+    ParticlesWaxOn,
+    ParticlesWaxOff,
+    ParticlesScrape,
+    ParticlesElectricSpark,
+    ParticlesTurtleEgg,
+    ParticlesSculkShriek,
+    ParticlesSculkCatalystBloom,
+    ParticlesSculkCharge,
+    ParticlesSculkChargePop,
+    ParticlesSonicExplosion,
+    ParticlesDustPlume,
+    /// The data is the rain intensity, scaled by 65535.
+    StartRaining,
+    /// The data is the thunder intensity, scaled by 65535.
+    StartThunderstorm,
+    StopRaining,
+    StopThunderstorm,
+    GlobalPause,
+    SimTimeStep,
+    SimTimeScale,
+    ActivateBlock,
+    CauldronExplode,
+    CauldronDyeArmor,
+    CauldronCleanArmor,
+    CauldronFillPotion,
+    CauldronTakePotion,
+    CauldronFillWater,
+    CauldronTakeWater,
+    CauldronAddDye,
+    CauldronCleanBanner,
+    CauldronFlush,
+    AgentSpawnEffect,
+    CauldronFillLava,
+    CauldronTakeLava,
+    CauldronFillPowderSnow,
+    CauldronTakePowderSnow,
+    /// The data is the number of ticks it takes to break the block, scaled by 65535.
+    StartBlockCracking,
+    StopBlockCracking,
+    UpdateBlockCracking,
+    AllPlayersSleeping,
+    /// The data is the number of sleeping players.
+    SleepingPlayers,
+    JumpPrevented,
+    AnimationVaultActivate,
+    AnimationVaultDeactivate,
+    AnimationVaultEjectItem,
+    AnimationSpawnCobweb,
+    /// Spawns a legacy particle by its ID, the data is particle specific.
+    ParticleLegacy(i32),
+    /// A level event not known to this version.
+    Unknown(i32),
+}
+
+impl LevelEventType {
+    pub fn id(&self) -> i32 {
+        match self {
+            LevelEventType::SoundClick => 1000,
+            LevelEventType::SoundClickFail => 1001,
+            LevelEventType::SoundLaunch => 1002,
+            LevelEventType::SoundOpenDoor => 1003,
+            LevelEventType::SoundFizz => 1004,
+            LevelEventType::SoundFuse => 1005,
+            LevelEventType::SoundPlayRecording => 1006,
+            LevelEventType::SoundGhastWarning => 1007,
+            LevelEventType::SoundGhastFireball => 1008,
+            LevelEventType::SoundBlazeFireball => 1009,
+            LevelEventType::SoundZombieWoodenDoor => 1010,
+            LevelEventType::SoundZombieDoorCrash => 1012,
+            LevelEventType::SoundZombieInfected => 1016,
+            LevelEventType::SoundZombieConverted => 1017,
+            LevelEventType::SoundEndermanTeleport => 1018,
+            LevelEventType::SoundAnvilBroken => 1020,
+            LevelEventType::SoundAnvilUsed => 1021,
+            LevelEventType::SoundAnvilLand => 1022,
+            LevelEventType::SoundInfinityArrowPickup => 1030,
+            LevelEventType::SoundTeleportEnderPearl => 1032,
+            LevelEventType::SoundAddItem => 1040,
+            LevelEventType::SoundItemFrameBreak => 1041,
+            LevelEventType::SoundItemFramePlace => 1042,
+            LevelEventType::SoundItemFrameRemoveItem => 1043,
+            LevelEventType::SoundItemFrameRotateItem => 1044,
+            LevelEventType::SoundExperienceOrbPickup => 1051,
+            LevelEventType::SoundTotemUsed => 1052,
+            LevelEventType::SoundArmorStandBreak => 1060,
+            LevelEventType::SoundArmorStandHit => 1061,
+            LevelEventType::SoundArmorStandLand => 1062,
+            LevelEventType::SoundArmorStandPlace => 1063,
+            LevelEventType::SoundPointedDripstoneLand => 1064,
+            LevelEventType::SoundDyeUsed => 1065,
+            LevelEventType::SoundInkSacUsed => 1066,
+            LevelEventType::SoundAmethystResonate => 1067,
+            LevelEventType::QueueCustomMusic => 1900,
+            LevelEventType::PlayCustomMusic => 1901,
+            LevelEventType::StopCustomMusic => 1902,
+            LevelEventType::SetMusicVolume => 1903,
+            LevelEventType::ParticlesShoot => 2000,
+            LevelEventType::ParticlesDestroyBlock => 2001,
+            LevelEventType::ParticlesPotionSplash => 2002,
+            LevelEventType::ParticlesEyeOfEnderDeath => 2003,
+            LevelEventType::ParticlesMobBlockSpawn => 2004,
+            LevelEventType::ParticlesCropGrowth => 2005,
+            LevelEventType::ParticlesSoundGuardianGhost => 2006,
+            LevelEventType::ParticlesDeathSmoke => 2007,
+            LevelEventType::ParticlesDenyBlock => 2008,
+            LevelEventType::ParticlesGenericSpawn => 2009,
+            LevelEventType::ParticlesDragonEgg => 2010,
+            LevelEventType::ParticlesCropEaten => 2011,
+            LevelEventType::ParticlesCritical => 2012,
+            LevelEventType::ParticlesTeleport => 2013,
+            LevelEventType::ParticlesCrackBlock => 2014,
+            LevelEventType::ParticlesBubble => 2015,
+            LevelEventType::ParticlesEvaporate => 2016,
+            LevelEventType::ParticlesDestroyArmorStand => 2017,
+            LevelEventType::ParticlesBreakingEgg => 2018,
+            LevelEventType::ParticlesDestroyEgg => 2019,
+            LevelEventType::ParticlesEvaporateWater => 2020,
+            LevelEventType::ParticlesDestroyBlockNoSound => 2021,
+            LevelEventType::ParticlesKnockbackRoar => 2022,
+            LevelEventType::ParticlesTeleportTrail => 2023,
+            LevelEventType::ParticlesPointCloud => 2024,
+            LevelEventType::ParticlesExplosion => 2025,
+            LevelEventType::ParticlesBlockExplosion => 2026,
+            LevelEventType::ParticlesVibrationSignal => 2027,
+            LevelEventType::ParticlesDripstoneDrip => 2028,
+            LevelEventType::ParticlesFizzEffect => 2029,
+            LevelEventType::ParticlesWaxOn => 2030,
+            LevelEventType::ParticlesWaxOff => 2031,
+            LevelEventType::ParticlesScrape => 2032,
+            LevelEventType::ParticlesElectricSpark => 2033,
+            LevelEventType::ParticlesTurtleEgg => 2034,
+            LevelEventType::ParticlesSculkShriek => 2035,
+            LevelEventType::ParticlesSculkCatalystBloom => 2036,
+            LevelEventType::ParticlesSculkCharge => 2037,
+            LevelEventType::ParticlesSculkChargePop => 2038,
+            LevelEventType::ParticlesSonicExplosion => 2039,
+            LevelEventType::ParticlesDustPlume => 2040,
+            LevelEventType::StartRaining => 3001,
+            LevelEventType::StartThunderstorm => 3002,
+            LevelEventType::StopRaining => 3003,
+            LevelEventType::StopThunderstorm => 3004,
+            LevelEventType::GlobalPause => 3005,
+            LevelEventType::SimTimeStep => 3006,
+            LevelEventType::SimTimeScale => 3007,
+            LevelEventType::ActivateBlock => 3500,
+            LevelEventType::CauldronExplode => 3501,
+            LevelEventType::CauldronDyeArmor => 3502,
+            LevelEventType::CauldronCleanArmor => 3503,
+            LevelEventType::CauldronFillPotion => 3504,
+            LevelEventType::CauldronTakePotion => 3505,
+            LevelEventType::CauldronFillWater => 3506,
+            LevelEventType::CauldronTakeWater => 3507,
+            LevelEventType::CauldronAddDye => 3508,
+            LevelEventType::CauldronCleanBanner => 3509,
+            LevelEventType::CauldronFlush => 3510,
+            LevelEventType::AgentSpawnEffect => 3511,
+            LevelEventType::CauldronFillLava => 3512,
+            LevelEventType::CauldronTakeLava => 3513,
+            LevelEventType::CauldronFillPowderSnow => 3514,
+            LevelEventType::CauldronTakePowderSnow => 3515,
+            LevelEventType::StartBlockCracking => 3600,
+            LevelEventType::StopBlockCracking => 3601,
+            LevelEventType::UpdateBlockCracking => 3602,
+            LevelEventType::AllPlayersSleeping => 9800,
+            LevelEventType::SleepingPlayers => 9801,
+            LevelEventType::JumpPrevented => 9810,
+            LevelEventType::AnimationVaultActivate => 9811,
+            LevelEventType::AnimationVaultDeactivate => 9812,
+            LevelEventType::AnimationVaultEjectItem => 9813,
+            LevelEventType::AnimationSpawnCobweb => 9814,
+            LevelEventType::ParticleLegacy(id) => PARTICLE_LEGACY_FLAG | *id,
+            LevelEventType::Unknown(id) => *id,
+        }
+    }
+
+    pub fn from_id(id: i32) -> Self {
+        match id {
+            1000 => LevelEventType::SoundClick,
+            1001 => LevelEventType::SoundClickFail,
+            1002 => LevelEventType::SoundLaunch,
+            1003 => LevelEventType::SoundOpenDoor,
+            1004 => LevelEventType::SoundFizz,
+            1005 => LevelEventType::SoundFuse,
+            1006 => LevelEventType::SoundPlayRecording,
+            1007 => LevelEventType::SoundGhastWarning,
+            1008 => LevelEventType::SoundGhastFireball,
+            1009 => LevelEventType::SoundBlazeFireball,
+            1010 => LevelEventType::SoundZombieWoodenDoor,
+            1012 => LevelEventType::SoundZombieDoorCrash,
+            1016 => LevelEventType::SoundZombieInfected,
+            1017 => LevelEventType::SoundZombieConverted,
+            1018 => LevelEventType::SoundEndermanTeleport,
+            1020 => LevelEventType::SoundAnvilBroken,
+            1021 => LevelEventType::SoundAnvilUsed,
+            1022 => LevelEventType::SoundAnvilLand,
+            1030 => LevelEventType::SoundInfinityArrowPickup,
+            1032 => LevelEventType::SoundTeleportEnderPearl,
+            1040 => LevelEventType::SoundAddItem,
+            1041 => LevelEventType::SoundItemFrameBreak,
+            1042 => LevelEventType::SoundItemFramePlace,
+            1043 => LevelEventType::SoundItemFrameRemoveItem,
+            1044 => LevelEventType::SoundItemFrameRotateItem,
+            1051 => LevelEventType::SoundExperienceOrbPickup,
+            1052 => LevelEventType::SoundTotemUsed,
+            1060 => LevelEventType::SoundArmorStandBreak,
+            1061 => LevelEventType::SoundArmorStandHit,
+            1062 => LevelEventType::SoundArmorStandLand,
+            1063 => LevelEventType::SoundArmorStandPlace,
+            1064 => LevelEventType::SoundPointedDripstoneLand,
+            1065 => LevelEventType::SoundDyeUsed,
+            1066 => LevelEventType::SoundInkSacUsed,
+            1067 => LevelEventType::SoundAmethystResonate,
+            1900 => LevelEventType::QueueCustomMusic,
+            1901 => LevelEventType::PlayCustomMusic,
+            1902 => LevelEventType::StopCustomMusic,
+            1903 => LevelEventType::SetMusicVolume,
+            2000 => LevelEventType::ParticlesShoot,
+            2001 => LevelEventType::ParticlesDestroyBlock,
+            2002 => LevelEventType::ParticlesPotionSplash,
+            2003 => LevelEventType::ParticlesEyeOfEnderDeath,
+            2004 => LevelEventType::ParticlesMobBlockSpawn,
+            2005 => LevelEventType::ParticlesCropGrowth,
+            2006 => LevelEventType::ParticlesSoundGuardianGhost,
+            2007 => LevelEventType::ParticlesDeathSmoke,
+            2008 => LevelEventType::ParticlesDenyBlock,
+            2009 => LevelEventType::ParticlesGenericSpawn,
+            2010 => LevelEventType::ParticlesDragonEgg,
+            2011 => LevelEventType::ParticlesCropEaten,
+            2012 => LevelEventType::ParticlesCritical,
+            2013 => LevelEventType::ParticlesTeleport,
+            2014 => LevelEventType::ParticlesCrackBlock,
+            2015 => LevelEventType::ParticlesBubble,
+            2016 => LevelEventType::ParticlesEvaporate,
+            2017 => LevelEventType::ParticlesDestroyArmorStand,
+            2018 => LevelEventType::ParticlesBreakingEgg,
+            2019 => LevelEventType::ParticlesDestroyEgg,
+            2020 => LevelEventType::ParticlesEvaporateWater,
+            2021 => LevelEventType::ParticlesDestroyBlockNoSound,
+            2022 => LevelEventType::ParticlesKnockbackRoar,
+            2023 => LevelEventType::ParticlesTeleportTrail,
+            2024 => LevelEventType::ParticlesPointCloud,
+            2025 => LevelEventType::ParticlesExplosion,
+            2026 => LevelEventType::ParticlesBlockExplosion,
+            2027 => LevelEventType::ParticlesVibrationSignal,
+            2028 => LevelEventType::ParticlesDripstoneDrip,
+            2029 => LevelEventType::ParticlesFizzEffect,
+            2030 => LevelEventType::ParticlesWaxOn,
+            2031 => LevelEventType::ParticlesWaxOff,
+            2032 => LevelEventType::ParticlesScrape,
+            2033 => LevelEventType::ParticlesElectricSpark,
+            2034 => LevelEventType::ParticlesTurtleEgg,
+            2035 => LevelEventType::ParticlesSculkShriek,
+            2036 => LevelEventType::ParticlesSculkCatalystBloom,
+            2037 => LevelEventType::ParticlesSculkCharge,
+            2038 => LevelEventType::ParticlesSculkChargePop,
+            2039 => LevelEventType::ParticlesSonicExplosion,
+            2040 => LevelEventType::ParticlesDustPlume,
+            3001 => LevelEventType::StartRaining,
+            3002 => LevelEventType::StartThunderstorm,
+            3003 => LevelEventType::StopRaining,
+            3004 => LevelEventType::StopThunderstorm,
+            3005 => LevelEventType::GlobalPause,
+            3006 => LevelEventType::SimTimeStep,
+            3007 => LevelEventType::SimTimeScale,
+            3500 => LevelEventType::ActivateBlock,
+            3501 => LevelEventType::CauldronExplode,
+            3502 => LevelEventType::CauldronDyeArmor,
+            3503 => LevelEventType::CauldronCleanArmor,
+            3504 => LevelEventType::CauldronFillPotion,
+            3505 => LevelEventType::CauldronTakePotion,
+            3506 => LevelEventType::CauldronFillWater,
+            3507 => LevelEventType::CauldronTakeWater,
+            3508 => LevelEventType::CauldronAddDye,
+            3509 => LevelEventType::CauldronCleanBanner,
+            3510 => LevelEventType::CauldronFlush,
+            3511 => LevelEventType::AgentSpawnEffect,
+            3512 => LevelEventType::CauldronFillLava,
+            3513 => LevelEventType::CauldronTakeLava,
+            3514 => LevelEventType::CauldronFillPowderSnow,
+            3515 => LevelEventType::CauldronTakePowderSnow,
+            3600 => LevelEventType::StartBlockCracking,
+            3601 => LevelEventType::StopBlockCracking,
+            3602 => LevelEventType::UpdateBlockCracking,
+            9800 => LevelEventType::AllPlayersSleeping,
+            9801 => LevelEventType::SleepingPlayers,
+            9810 => LevelEventType::JumpPrevented,
+            9811 => LevelEventType::AnimationVaultActivate,
+            9812 => LevelEventType::AnimationVaultDeactivate,
+            9813 => LevelEventType::AnimationVaultEjectItem,
+            9814 => LevelEventType::AnimationSpawnCobweb,
+            other if other & PARTICLE_LEGACY_FLAG != 0 => {
+                LevelEventType::ParticleLegacy(other & !PARTICLE_LEGACY_FLAG)
+            }
+            other => LevelEventType::Unknown(other),
+        }
+    }
+}
@@ -1,10 +1,15 @@
+use std::io::Cursor;
+
 use bedrockrs_core::int::{LE, VAR};
-use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
 
 use bedrockrs_shared::world::difficulty::Difficulty;
 use bedrockrs_shared::world::gamemode::Gamemode;
 use bedrockrs_shared::world::generator_type::GeneratorType;
 
+use crate::info::{PROTOCOL_VERSION_1_20_80, PROTOCOL_VERSION_1_21_0};
 use crate::types::base_game_version::BaseGameVersion;
 use crate::types::chat_restriction_level::ChatRestrictionLevel;
 use crate::types::edu_shared_uri_resource::EduSharedResourceUri;
@@ -14,12 +19,13 @@ use crate::types::network_block_pos::NetworkBlockPos;
 use crate::types::spawn_settings::SpawnSettings;
 use bedrockrs_shared::world::editor_world_type::EditorWorldType;
 
-#[derive(ProtoCodec, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct LevelSettings {
     pub seed: LE<u64>,
     pub spawn_settings: SpawnSettings,
     pub generator_type: GeneratorType,
     pub game_type: Gamemode,
+    /// Only sent since 1.20.80.
     pub hardcore: bool,
     pub difficulty: Difficulty,
     pub default_spawn_block: NetworkBlockPos,
@@ -41,7 +47,6 @@ pub struct LevelSettings {
     pub broadcasting_settings_platform: VAR<i32>,
     pub commands_enabled: bool,
     pub texture_pack_required: bool,
-    #[len_repr(VAR::<u32>)]
     pub gamerules: Vec<GameRule>,
     pub experiments: Experiments,
     pub bonus_chest: bool,
@@ -67,7 +72,195 @@ pub struct LevelSettings {
     pub force_experimental_gameplay: bool,
     pub chat_restriction_level: ChatRestrictionLevel,
     pub disable_player_interactions: bool,
+    /// Only sent since 1.21.0, like the world and scenario ID.
     pub server_id: String,
     pub world_id: String,
     pub scenario_id: String,
 }
+
+impl ProtoCodec for LevelSettings {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.seed.proto_serialize(stream)?;
+        self.spawn_settings.proto_serialize(stream)?;
+        self.generator_type.proto_serialize(stream)?;
+        self.game_type.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.hardcore.proto_serialize(stream)?;
+        }
+        self.difficulty.proto_serialize(stream)?;
+        self.default_spawn_block.proto_serialize(stream)?;
+        self.achievements_disabled.proto_serialize(stream)?;
+        self.editor_world_type.proto_serialize(stream)?;
+        self.created_in_editor.proto_serialize(stream)?;
+        self.exported_from_editor.proto_serialize(stream)?;
+        self.day_cycle_stop_time.proto_serialize(stream)?;
+        self.education_edition_offer.proto_serialize(stream)?;
+        self.education_features.proto_serialize(stream)?;
+        self.education_product_id.proto_serialize(stream)?;
+        self.rain_level.proto_serialize(stream)?;
+        self.lightning_level.proto_serialize(stream)?;
+        self.platform_locked_content.proto_serialize(stream)?;
+        self.multiplayer_intended.proto_serialize(stream)?;
+        self.lan_broadcasting_intended.proto_serialize(stream)?;
+        self.broadcasting_settings_xbox_live
+            .proto_serialize(stream)?;
+        self.broadcasting_settings_platform
+            .proto_serialize(stream)?;
+        self.commands_enabled.proto_serialize(stream)?;
+        self.texture_pack_required.proto_serialize(stream)?;
+        VAR::<u32>::new(self.gamerules.len().try_into()?).proto_serialize(stream)?;
+        for gamerule in &self.gamerules {
+            gamerule.proto_serialize(stream)?;
+        }
+        self.experiments.proto_serialize(stream)?;
+        self.bonus_chest.proto_serialize(stream)?;
+        self.start_with_map.proto_serialize(stream)?;
+        self.player_permission.proto_serialize(stream)?;
+        self.server_chunk_tick_radius.proto_serialize(stream)?;
+        self.locked_behavior_packs.proto_serialize(stream)?;
+        self.locked_resource_packs.proto_serialize(stream)?;
+        self.from_locked_template.proto_serialize(stream)?;
+        self.msa_gamertags_only.proto_serialize(stream)?;
+        self.from_template.proto_serialize(stream)?;
+        self.is_template_locked_settings.proto_serialize(stream)?;
+        self.only_spawn_v1_villagers.proto_serialize(stream)?;
+        self.persona_disabled.proto_serialize(stream)?;
+        self.custom_skins_disabled.proto_serialize(stream)?;
+        self.emote_chat_muted.proto_serialize(stream)?;
+        self.base_game_version.proto_serialize(stream)?;
+        self.limited_world_width.proto_serialize(stream)?;
+        self.limited_world_depth.proto_serialize(stream)?;
+        self.new_nether.proto_serialize(stream)?;
+        self.edu_shared_uri_resource.proto_serialize(stream)?;
+        self.force_experimental_gameplay.proto_serialize(stream)?;
+        self.chat_restriction_level.proto_serialize(stream)?;
+        self.disable_player_interactions.proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.server_id.proto_serialize(stream)?;
+            self.world_id.proto_serialize(stream)?;
+            self.scenario_id.proto_serialize(stream)?;
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let seed = LE::<u64>::proto_deserialize(stream)?;
+        let spawn_settings = SpawnSettings::proto_deserialize(stream)?;
+        let generator_type = GeneratorType::proto_deserialize(stream)?;
+        let game_type = Gamemode::proto_deserialize(stream)?;
+        let hardcore = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => bool::proto_deserialize(stream)?,
+            false => false,
+        };
+        let difficulty = Difficulty::proto_deserialize(stream)?;
+        let default_spawn_block = NetworkBlockPos::proto_deserialize(stream)?;
+        let achievements_disabled = bool::proto_deserialize(stream)?;
+        let editor_world_type = EditorWorldType::proto_deserialize(stream)?;
+        let created_in_editor = bool::proto_deserialize(stream)?;
+        let exported_from_editor = bool::proto_deserialize(stream)?;
+        let day_cycle_stop_time = VAR::<i32>::proto_deserialize(stream)?;
+        let education_edition_offer = VAR::<i32>::proto_deserialize(stream)?;
+        let education_features = bool::proto_deserialize(stream)?;
+        let education_product_id = String::proto_deserialize(stream)?;
+        let rain_level = LE::<f32>::proto_deserialize(stream)?;
+        let lightning_level = LE::<f32>::proto_deserialize(stream)?;
+        let platform_locked_content = bool::proto_deserialize(stream)?;
+        let multiplayer_intended = bool::proto_deserialize(stream)?;
+        let lan_broadcasting_intended = bool::proto_deserialize(stream)?;
+        let broadcasting_settings_xbox_live = VAR::<i32>::proto_deserialize(stream)?;
+        let broadcasting_settings_platform = VAR::<i32>::proto_deserialize(stream)?;
+        let commands_enabled = bool::proto_deserialize(stream)?;
+        let texture_pack_required = bool::proto_deserialize(stream)?;
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut gamerules = Vec::with_capacity(len.min(1024) as usize);
+        for _ in 0..len {
+            gamerules.push(GameRule::proto_deserialize(stream)?);
+        }
+        let experiments = Experiments::proto_deserialize(stream)?;
+        let bonus_chest = bool::proto_deserialize(stream)?;
+        let start_with_map = bool::proto_deserialize(stream)?;
+        let player_permission = VAR::<i32>::proto_deserialize(stream)?;
+        let server_chunk_tick_radius = LE::<i32>::proto_deserialize(stream)?;
+        let locked_behavior_packs = bool::proto_deserialize(stream)?;
+        let locked_resource_packs = bool::proto_deserialize(stream)?;
+        let from_locked_template = bool::proto_deserialize(stream)?;
+        let msa_gamertags_only = bool::proto_deserialize(stream)?;
+        let from_template = bool::proto_deserialize(stream)?;
+        let is_template_locked_settings = bool::proto_deserialize(stream)?;
+        let only_spawn_v1_villagers = bool::proto_deserialize(stream)?;
+        let persona_disabled = bool::proto_deserialize(stream)?;
+        let custom_skins_disabled = bool::proto_deserialize(stream)?;
+        let emote_chat_muted = bool::proto_deserialize(stream)?;
+        let base_game_version = BaseGameVersion::proto_deserialize(stream)?;
+        let limited_world_width = LE::<i32>::proto_deserialize(stream)?;
+        let limited_world_depth = LE::<i32>::proto_deserialize(stream)?;
+        let new_nether = bool::proto_deserialize(stream)?;
+        let edu_shared_uri_resource = EduSharedResourceUri::proto_deserialize(stream)?;
+        let force_experimental_gameplay = bool::proto_deserialize(stream)?;
+        let chat_restriction_level = ChatRestrictionLevel::proto_deserialize(stream)?;
+        let disable_player_interactions = bool::proto_deserialize(stream)?;
+        let (server_id, world_id, scenario_id) = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => (
+                String::proto_deserialize(stream)?,
+                String::proto_deserialize(stream)?,
+                String::proto_deserialize(stream)?,
+            ),
+            false => (String::new(), String::new(), String::new()),
+        };
+
+        Ok(Self {
+            seed,
+            spawn_settings,
+            generator_type,
+            game_type,
+            hardcore,
+            difficulty,
+            default_spawn_block,
+            achievements_disabled,
+            editor_world_type,
+            created_in_editor,
+            exported_from_editor,
+            day_cycle_stop_time,
+            education_edition_offer,
+            education_features,
+            education_product_id,
+            rain_level,
+            lightning_level,
+            platform_locked_content,
+            multiplayer_intended,
+            lan_broadcasting_intended,
+            broadcasting_settings_xbox_live,
+            broadcasting_settings_platform,
+            commands_enabled,
+            texture_pack_required,
+            gamerules,
+            experiments,
+            bonus_chest,
+            start_with_map,
+            player_permission,
+            server_chunk_tick_radius,
+            locked_behavior_packs,
+            locked_resource_packs,
+            from_locked_template,
+            msa_gamertags_only,
+            from_template,
+            is_template_locked_settings,
+            only_spawn_v1_villagers,
+            persona_disabled,
+            custom_skins_disabled,
+            emote_chat_muted,
+            base_game_version,
+            limited_world_width,
+            limited_world_depth,
+            new_nether,
+            edu_shared_uri_resource,
+            force_experimental_gameplay,
+            chat_restriction_level,
+            disable_player_interactions,
+            server_id,
+            world_id,
+            scenario_id,
+        })
+    }
+}
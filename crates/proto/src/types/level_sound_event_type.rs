@@ -0,0 +1,1578 @@
+/// The vanilla sound events, shared by [`LevelSoundEventPacket`](crate::packets::level_sound_event::LevelSoundEventPacket)
+/// and [`LevelSoundEventV2Packet`](crate::packets::level_sound_event_v2::LevelSoundEventV2Packet).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LevelSoundEventType {
+    ItemUseOn,
+    Hit,
+    Step,
+    Fly,
+    Jump,
+    Break,
+    Place,
+    HeavyStep,
+    Gallop,
+    Fall,
+    Ambient,
+    AmbientBaby,
+    AmbientInWater,
+    Breathe,
+    Death,
+    DeathInWater,
+    DeathToZombie,
+    Hurt,
+    HurtInWater,
+    Mad,
+    Boost,
+    Bow,
+    SquishBig,
+    SquishSmall,
+    FallBig,
+    FallSmall,
+    Splash,
+    Fizz,
+    Flap,
+    Swim,
+    Drink,
+    Eat,
+    Takeoff,
+    Shake,
+    Plop,
+    Land,
+    Saddle,
+    Armor,
+    MobArmorStandPlace,
+    AddChest,
+    Throw,
+    Attack,
+    AttackNoDamage,
+    AttackStrong,
+    Warn,
+    Shear,
+    Milk,
+    Thunder,
+    Explode,
+    Fire,
+    Ignite,
+    Fuse,
+    Stare,
+    Spawn,
+    Shoot,
+    BreakBlock,
+    Launch,
+    Blast,
+    LargeBlast,
+    Twinkle,
+    Remedy,
+    Infect,
+    LevelUp,
+    BowHit,
+    BulletHit,
+    ExtinguishFire,
+    ItemFizz,
+    ChestOpen,
+    ChestClosed,
+    ShulkerBoxOpen,
+    ShulkerBoxClosed,
+    EnderChestOpen,
+    EnderChestClosed,
+    PowerOn,
+    PowerOff,
+    Attach,
+    Detach,
+    Deny,
+    Tripod,
+    Pop,
+    DropSlot,
+    Note,
+    Thorns,
+    PistonIn,
+    PistonOut,
+    Portal,
+    Water,
+    LavaPop,
+    Lava,
+    Burp,
+    BucketFillWater,
+    BucketFillLava,
+    BucketEmptyWater,
+    BucketEmptyLava,
+    ArmorEquipChain,
+    ArmorEquipDiamond,
+    ArmorEquipGeneric,
+    ArmorEquipGold,
+    ArmorEquipIron,
+    ArmorEquipLeather,
+    ArmorEquipElytra,
+    Record13,
+    RecordCat,
+    RecordBlocks,
+    RecordChirp,
+    RecordFar,
+    RecordMall,
+    RecordMellohi,
+    RecordStal,
+    RecordStrad,
+    RecordWard,
+    Record11,
+    RecordWait,
+    StopRecord,
+    Flop,
+    ElderGuardianCurse,
+    MobWarning,
+    MobWarningBaby,
+    Teleport,
+    ShulkerOpen,
+    ShulkerClose,
+    Haggle,
+    HaggleYes,
+    HaggleNo,
+    HaggleIdle,
+    ChorusGrow,
+    ChorusDeath,
+    Glass,
+    PotionBrewed,
+    CastSpell,
+    PrepareAttack,
+    PrepareSummon,
+    PrepareWololo,
+    Fang,
+    Charge,
+    CameraTakePicture,
+    LeashKnotPlace,
+    LeashKnotBreak,
+    Growl,
+    Whine,
+    Pant,
+    Purr,
+    Purreow,
+    DeathMinVolume,
+    DeathMidVolume,
+    ImitateBlaze,
+    ImitateCaveSpider,
+    ImitateCreeper,
+    ImitateElderGuardian,
+    ImitateEnderDragon,
+    ImitateEnderman,
+    ImitateEndermite,
+    ImitateEvocationIllager,
+    ImitateGhast,
+    ImitateHusk,
+    ImitateIllusionIllager,
+    ImitateMagmaCube,
+    ImitatePolarBear,
+    ImitateShulker,
+    ImitateSilverfish,
+    ImitateSkeleton,
+    ImitateSlime,
+    ImitateSpider,
+    ImitateStray,
+    ImitateVex,
+    ImitateVindicationIllager,
+    ImitateWitch,
+    ImitateWither,
+    ImitateWitherSkeleton,
+    ImitateWolf,
+    ImitateZombie,
+    ImitateZombiePigman,
+    ImitateZombieVillager,
+    BlockEndPortalFrameFill,
+    BlockEndPortalSpawn,
+    RandomAnvilUse,
+    BottleDragonBreath,
+    PortalTravel,
+    ItemTridentHit,
+    ItemTridentReturn,
+    ItemTridentRiptide1,
+    ItemTridentRiptide2,
+    ItemTridentRiptide3,
+    ItemTridentThrow,
+    ItemTridentThunder,
+    ItemTridentHitGround,
+    Default,
+    BlockFletchingTableUse,
+    ElemConstructOpen,
+    IceBombHit,
+    BalloonPop,
+    LtReactionIceBomb,
+    LtReactionBleach,
+    LtReactionElephantToothpaste,
+    LtReactionElephantToothpaste2,
+    LtReactionGlowStick,
+    LtReactionGlowStick2,
+    LtReactionLuminol,
+    LtReactionSalt,
+    LtReactionFertilizer,
+    LtReactionFireball,
+    LtReactionMagnesiumSalt,
+    LtReactionMiscFire,
+    LtReactionFire,
+    LtReactionMiscExplosion,
+    LtReactionMiscMystical,
+    LtReactionMiscMystical2,
+    LtReactionProduct,
+    SparklerUse,
+    GlowStickUse,
+    SparklerActive,
+    ConvertToDrowned,
+    BucketFillFish,
+    BucketEmptyFish,
+    BubbleUp,
+    BubbleDown,
+    BubblePop,
+    BubbleUpInside,
+    BubbleDownInside,
+    HurtBaby,
+    DeathBaby,
+    StepBaby,
+    SpawnBaby,
+    Born,
+    TurtleEggBreak,
+    TurtleEggCrack,
+    TurtleEggHatched,
+    LayEgg,
+    TurtleEggAttacked,
+    BeaconActivate,
+    BeaconAmbient,
+    BeaconDeactivate,
+    BeaconPower,
+    ConduitActivate,
+    ConduitAmbient,
+    ConduitAttack,
+    ConduitDeactivate,
+    ConduitShort,
+    Swoop,
+    BlockBambooSaplingPlace,
+    PreSneeze,
+    Sneeze,
+    AmbientTame,
+    Scared,
+    BlockScaffoldingClimb,
+    CrossbowLoadingStart,
+    CrossbowLoadingMiddle,
+    CrossbowLoadingEnd,
+    CrossbowShoot,
+    CrossbowQuickChargeStart,
+    CrossbowQuickChargeMiddle,
+    CrossbowQuickChargeEnd,
+    AmbientAggressive,
+    AmbientWorried,
+    CantBreed,
+    ItemShieldBlock,
+    ItemBookPut,
+    BlockGrindstoneUse,
+    BlockBellHit,
+    BlockCampfireCrackle,
+    Roar,
+    Stun,
+    BlockSweetBerryBushHurt,
+    BlockSweetBerryBushPick,
+    BlockCartographyTableUse,
+    BlockStonecutterUse,
+    BlockComposterEmpty,
+    BlockComposterFill,
+    BlockComposterFillSuccess,
+    BlockComposterReady,
+    BlockBarrelOpen,
+    BlockBarrelClose,
+    RaidHorn,
+    BlockLoomUse,
+    AmbientInRaid,
+    UiCartographyTableTakeResult,
+    UiStonecutterTakeResult,
+    UiLoomTakeResult,
+    BlockSmokerSmoke,
+    BlockBlastFurnaceFireCrackle,
+    BlockSmithingTableUse,
+    Screech,
+    Sleep,
+    BlockFurnaceLit,
+    ConvertMooshroom,
+    MilkSuspiciously,
+    Celebrate,
+    JumpPrevent,
+    AmbientPollinate,
+    BlockBeehiveDrip,
+    BlockBeehiveEnter,
+    BlockBeehiveExit,
+    BlockBeehiveWork,
+    BlockBeehiveShear,
+    DrinkHoney,
+    AmbientCave,
+    Retreat,
+    ConvertedToZombified,
+    Admire,
+    StepLava,
+    Tempt,
+    Panic,
+    Angry,
+    AmbientWarpedForestMood,
+    AmbientSoulsandValleyMood,
+    AmbientNetherWastesMood,
+    AmbientBasaltDeltasMood,
+    AmbientCrimsonForestMood,
+    RespawnAnchorCharge,
+    RespawnAnchorDeplete,
+    RespawnAnchorSetSpawn,
+    RespawnAnchorAmbient,
+    SoulEscapeQuiet,
+    SoulEscapeLoud,
+    RecordPigstep,
+    LinkCompassToLodestone,
+    UseSmithingTable,
+    EquipNetherite,
+    AmbientLoopWarpedForest,
+    AmbientLoopSoulsandValley,
+    AmbientLoopNetherWastes,
+    AmbientLoopBasaltDeltas,
+    AmbientLoopCrimsonForest,
+    AmbientAdditionWarpedForest,
+    AmbientAdditionSoulsandValley,
+    AmbientAdditionNetherWastes,
+    AmbientAdditionBasaltDeltas,
+    AmbientAdditionCrimsonForest,
+    SculkSensorPowerOn,
+    SculkSensorPowerOff,
+    BucketFillPowderSnow,
+    BucketEmptyPowderSnow,
+    PointedDripstoneCauldronDripWater,
+    PointedDripstoneCauldronDripLava,
+    PointedDripstoneDripWater,
+    PointedDripstoneDripLava,
+    CaveVinesPickBerries,
+    BigDripleafTiltDown,
+    BigDripleafTiltUp,
+    CopperWaxOn,
+    CopperWaxOff,
+    Scrape,
+    PlayerHurtDrown,
+    PlayerHurtOnFire,
+    PlayerHurtFreeze,
+    UseSpyglass,
+    StopUsingSpyglass,
+    AmethystBlockChime,
+    AmbientScreamer,
+    HurtScreamer,
+    DeathScreamer,
+    MilkScreamer,
+    JumpToBlock,
+    PreRam,
+    PreRamScreamer,
+    RamImpact,
+    RamImpactScreamer,
+    SquidInkSquirt,
+    GlowSquidInkSquirt,
+    ConvertToStray,
+    CakeAddCandle,
+    ExtinguishCandle,
+    AmbientCandle,
+    BlockClick,
+    BlockClickFail,
+    SculkCatalystBloom,
+    SculkShriekerShriek,
+    WardenNearbyClose,
+    WardenNearbyCloser,
+    WardenNearbyClosest,
+    WardenSlightlyAngry,
+    RecordOtherside,
+    Tongue,
+    CrackIronGolem,
+    RepairIronGolem,
+    Listening,
+    Heartbeat,
+    HornBreak,
+    SculkPlace,
+    SculkSpread,
+    SculkCharge,
+    SculkSensorPlace,
+    SculkShriekerPlace,
+    GoatCall0,
+    GoatCall1,
+    GoatCall2,
+    GoatCall3,
+    GoatCall4,
+    GoatCall5,
+    GoatCall6,
+    GoatCall7,
+    GoatCall8,
+    GoatCall9,
+    GoatHarmony0,
+    GoatHarmony1,
+    GoatHarmony2,
+    GoatHarmony3,
+    GoatHarmony4,
+    GoatHarmony5,
+    GoatHarmony6,
+    GoatHarmony7,
+    GoatHarmony8,
+    GoatHarmony9,
+    GoatMelody0,
+    GoatMelody1,
+    GoatMelody2,
+    GoatMelody3,
+    GoatMelody4,
+    GoatMelody5,
+    GoatMelody6,
+    GoatMelody7,
+    GoatMelody8,
+    GoatMelody9,
+    GoatBass0,
+    GoatBass1,
+    GoatBass2,
+    GoatBass3,
+    GoatBass4,
+    GoatBass5,
+    GoatBass6,
+    GoatBass7,
+    GoatBass8,
+    GoatBass9,
+    ImitateWarden,
+    ListeningAngry,
+    ItemGiven,
+    ItemTaken,
+    Disappeared,
+    Reappeared,
+    DrinkMilk,
+    FrogspawnHatched,
+    LaySpawn,
+    FrogspawnBreak,
+    SonicBoom,
+    SonicCharge,
+    ItemThrown,
+    Record5,
+    ConvertToFrog,
+    RecordPlaying,
+    EnchantingTableUse,
+    StepSand,
+    DashReady,
+    BundleDropContents,
+    BundleInsert,
+    BundleRemoveOne,
+    PressurePlateClickOff,
+    PressurePlateClickOn,
+    ButtonClickOff,
+    ButtonClickOn,
+    DoorOpen,
+    DoorClose,
+    TrapdoorOpen,
+    TrapdoorClose,
+    FenceGateOpen,
+    FenceGateClose,
+    Insert,
+    Pickup,
+    InsertEnchanted,
+    PickupEnchanted,
+    Brush,
+    BrushCompleted,
+    ShatterDecoratedPot,
+    BreakDecoratedPot,
+    SnifferEggCrack,
+    SnifferEggHatched,
+    WaxedSignInteractFail,
+    RecordRelic,
+    Bump,
+    PumpkinCarve,
+    ConvertHuskToZombie,
+    PigDeath,
+    HoglinConvertToZombified,
+    AmbientUnderwaterEnter,
+    AmbientUnderwaterExit,
+    BottleFill,
+    BottleEmpty,
+    CrafterCraft,
+    CrafterFail,
+    DecoratedPotInsert,
+    DecoratedPotInsertFail,
+    CrafterDisableSlot,
+    TrialSpawnerOpenShutter,
+    TrialSpawnerEjectItem,
+    TrialSpawnerDetectPlayer,
+    TrialSpawnerSpawnMob,
+    TrialSpawnerCloseShutter,
+    TrialSpawnerAmbient,
+    CopperBulbTurnOn,
+    CopperBulbTurnOff,
+    AmbientInAir,
+    BreezeWindChargeBurst,
+    ImitateBreeze,
+    ArmadilloBrush,
+    ArmadilloScuteDrop,
+    EquipWolf,
+    UnequipWolf,
+    Reflect,
+    VaultOpenShutter,
+    VaultCloseShutter,
+    VaultEjectItem,
+    VaultInsertItem,
+    VaultInsertItemFail,
+    VaultAmbient,
+    VaultActivate,
+    VaultDeactivate,
+    HurtReduced,
+    WindChargeBurst,
+    ImitateBogged,
+    WolfArmorCrack,
+    WolfArmorBreak,
+    WolfArmorRepair,
+    MaceSmashAir,
+    MaceSmashGround,
+    TrialSpawnerChargeActivate,
+    TrialSpawnerAmbientOminous,
+    OminousItemSpawnerSpawnItem,
+    OminousBottleEndUse,
+    MaceHeavySmashGround,
+    OminousItemSpawnerSpawnItemBegin,
+    /// A sound event not known to this version.
+    Unknown(u32),
+}
+
+impl LevelSoundEventType {
+    pub fn id(&self) -> u32 {
+        match self {
+            LevelSoundEventType::ItemUseOn => 0,
+            LevelSoundEventType::Hit => 1,
+            LevelSoundEventType::Step => 2,
+            LevelSoundEventType::Fly => 3,
+            LevelSoundEventType::Jump => 4,
+            LevelSoundEventType::Break => 5,
+            LevelSoundEventType::Place => 6,
+            LevelSoundEventType::HeavyStep => 7,
+            LevelSoundEventType::Gallop => 8,
+            LevelSoundEventType::Fall => 9,
+            LevelSoundEventType::Ambient => 10,
+            LevelSoundEventType::AmbientBaby => 11,
+            LevelSoundEventType::AmbientInWater => 12,
+            LevelSoundEventType::Breathe => 13,
+            LevelSoundEventType::Death => 14,
+            LevelSoundEventType::DeathInWater => 15,
+            LevelSoundEventType::DeathToZombie => 16,
+            LevelSoundEventType::Hurt => 17,
+            LevelSoundEventType::HurtInWater => 18,
+            LevelSoundEventType::Mad => 19,
+            LevelSoundEventType::Boost => 20,
+            LevelSoundEventType::Bow => 21,
+            LevelSoundEventType::SquishBig => 22,
+            LevelSoundEventType::SquishSmall => 23,
+            LevelSoundEventType::FallBig => 24,
+            LevelSoundEventType::FallSmall => 25,
+            LevelSoundEventType::Splash => 26,
+            LevelSoundEventType::Fizz => 27,
+            LevelSoundEventType::Flap => 28,
+            LevelSoundEventType::Swim => 29,
+            LevelSoundEventType::Drink => 30,
+            LevelSoundEventType::Eat => 31,
+            LevelSoundEventType::Takeoff => 32,
+            LevelSoundEventType::Shake => 33,
+            LevelSoundEventType::Plop => 34,
+            LevelSoundEventType::Land => 35,
+            LevelSoundEventType::Saddle => 36,
+            LevelSoundEventType::Armor => 37,
+            LevelSoundEventType::MobArmorStandPlace => 38,
+            LevelSoundEventType::AddChest => 39,
+            LevelSoundEventType::Throw => 40,
+            LevelSoundEventType::Attack => 41,
+            LevelSoundEventType::AttackNoDamage => 42,
+            LevelSoundEventType::AttackStrong => 43,
+            LevelSoundEventType::Warn => 44,
+            LevelSoundEventType::Shear => 45,
+            LevelSoundEventType::Milk => 46,
+            LevelSoundEventType::Thunder => 47,
+            LevelSoundEventType::Explode => 48,
+            LevelSoundEventType::Fire => 49,
+            LevelSoundEventType::Ignite => 50,
+            LevelSoundEventType::Fuse => 51,
+            LevelSoundEventType::Stare => 52,
+            LevelSoundEventType::Spawn => 53,
+            LevelSoundEventType::Shoot => 54,
+            LevelSoundEventType::BreakBlock => 55,
+            LevelSoundEventType::Launch => 56,
+            LevelSoundEventType::Blast => 57,
+            LevelSoundEventType::LargeBlast => 58,
+            LevelSoundEventType::Twinkle => 59,
+            LevelSoundEventType::Remedy => 60,
+            LevelSoundEventType::Infect => 61,
+            LevelSoundEventType::LevelUp => 62,
+            LevelSoundEventType::BowHit => 63,
+            LevelSoundEventType::BulletHit => 64,
+            LevelSoundEventType::ExtinguishFire => 65,
+            LevelSoundEventType::ItemFizz => 66,
+            LevelSoundEventType::ChestOpen => 67,
+            LevelSoundEventType::ChestClosed => 68,
+            LevelSoundEventType::ShulkerBoxOpen => 69,
+            LevelSoundEventType::ShulkerBoxClosed => 70,
+            LevelSoundEventType::EnderChestOpen => 71,
+            LevelSoundEventType::EnderChestClosed => 72,
+            LevelSoundEventType::PowerOn => 73,
+            LevelSoundEventType::PowerOff => 74,
+            LevelSoundEventType::Attach => 75,
+            LevelSoundEventType::Detach => 76,
+            LevelSoundEventType::Deny => 77,
+            LevelSoundEventType::Tripod => 78,
+            LevelSoundEventType::Pop => 79,
+            LevelSoundEventType::DropSlot => 80,
+            LevelSoundEventType::Note => 81,
+            LevelSoundEventType::Thorns => 82,
+            LevelSoundEventType::PistonIn => 83,
+            LevelSoundEventType::PistonOut => 84,
+            LevelSoundEventType::Portal => 85,
+            LevelSoundEventType::Water => 86,
+            LevelSoundEventType::LavaPop => 87,
+            LevelSoundEventType::Lava => 88,
+            LevelSoundEventType::Burp => 89,
+            LevelSoundEventType::BucketFillWater => 90,
+            LevelSoundEventType::BucketFillLava => 91,
+            LevelSoundEventType::BucketEmptyWater => 92,
+            LevelSoundEventType::BucketEmptyLava => 93,
+            LevelSoundEventType::ArmorEquipChain => 94,
+            LevelSoundEventType::ArmorEquipDiamond => 95,
+            LevelSoundEventType::ArmorEquipGeneric => 96,
+            LevelSoundEventType::ArmorEquipGold => 97,
+            LevelSoundEventType::ArmorEquipIron => 98,
+            LevelSoundEventType::ArmorEquipLeather => 99,
+            LevelSoundEventType::ArmorEquipElytra => 100,
+            LevelSoundEventType::Record13 => 101,
+            LevelSoundEventType::RecordCat => 102,
+            LevelSoundEventType::RecordBlocks => 103,
+            LevelSoundEventType::RecordChirp => 104,
+            LevelSoundEventType::RecordFar => 105,
+            LevelSoundEventType::RecordMall => 106,
+            LevelSoundEventType::RecordMellohi => 107,
+            LevelSoundEventType::RecordStal => 108,
+            LevelSoundEventType::RecordStrad => 109,
+            LevelSoundEventType::RecordWard => 110,
+            LevelSoundEventType::Record11 => 111,
+            LevelSoundEventType::RecordWait => 112,
+            LevelSoundEventType::StopRecord => 113,
+            LevelSoundEventType::Flop => 114,
+            LevelSoundEventType::ElderGuardianCurse => 115,
+            LevelSoundEventType::MobWarning => 116,
+            LevelSoundEventType::MobWarningBaby => 117,
+            LevelSoundEventType::Teleport => 118,
+            LevelSoundEventType::ShulkerOpen => 119,
+            LevelSoundEventType::ShulkerClose => 120,
+            LevelSoundEventType::Haggle => 121,
+            LevelSoundEventType::HaggleYes => 122,
+            LevelSoundEventType::HaggleNo => 123,
+            LevelSoundEventType::HaggleIdle => 124,
+            LevelSoundEventType::ChorusGrow => 125,
+            LevelSoundEventType::ChorusDeath => 126,
+            LevelSoundEventType::Glass => 127,
+            LevelSoundEventType::PotionBrewed => 128,
+            LevelSoundEventType::CastSpell => 129,
+            LevelSoundEventType::PrepareAttack => 130,
+            LevelSoundEventType::PrepareSummon => 131,
+            LevelSoundEventType::PrepareWololo => 132,
+            LevelSoundEventType::Fang => 133,
+            LevelSoundEventType::Charge => 134,
+            LevelSoundEventType::CameraTakePicture => 135,
+            LevelSoundEventType::LeashKnotPlace => 136,
+            LevelSoundEventType::LeashKnotBreak => 137,
+            LevelSoundEventType::Growl => 138,
+            LevelSoundEventType::Whine => 139,
+            LevelSoundEventType::Pant => 140,
+            LevelSoundEventType::Purr => 141,
+            LevelSoundEventType::Purreow => 142,
+            LevelSoundEventType::DeathMinVolume => 143,
+            LevelSoundEventType::DeathMidVolume => 144,
+            LevelSoundEventType::ImitateBlaze => 145,
+            LevelSoundEventType::ImitateCaveSpider => 146,
+            LevelSoundEventType::ImitateCreeper => 147,
+            LevelSoundEventType::ImitateElderGuardian => 148,
+            LevelSoundEventType::ImitateEnderDragon => 149,
+            LevelSoundEventType::ImitateEnderman => 150,
+            LevelSoundEventType::ImitateEndermite => 151,
+            LevelSoundEventType::ImitateEvocationIllager => 152,
+            LevelSoundEventType::ImitateGhast => 153,
+            LevelSoundEventType::ImitateHusk => 154,
+            LevelSoundEventType::ImitateIllusionIllager => 155,
+            LevelSoundEventType::ImitateMagmaCube => 156,
+            LevelSoundEventType::ImitatePolarBear => 157,
+            LevelSoundEventType::ImitateShulker => 158,
+            LevelSoundEventType::ImitateSilverfish => 159,
+            LevelSoundEventType::ImitateSkeleton => 160,
+            LevelSoundEventType::ImitateSlime => 161,
+            LevelSoundEventType::ImitateSpider => 162,
+            LevelSoundEventType::ImitateStray => 163,
+            LevelSoundEventType::ImitateVex => 164,
+            LevelSoundEventType::ImitateVindicationIllager => 165,
+            LevelSoundEventType::ImitateWitch => 166,
+            LevelSoundEventType::ImitateWither => 167,
+            LevelSoundEventType::ImitateWitherSkeleton => 168,
+            LevelSoundEventType::ImitateWolf => 169,
+            LevelSoundEventType::ImitateZombie => 170,
+            LevelSoundEventType::ImitateZombiePigman => 171,
+            LevelSoundEventType::ImitateZombieVillager => 172,
+            LevelSoundEventType::BlockEndPortalFrameFill => 173,
+            LevelSoundEventType::BlockEndPortalSpawn => 174,
+            LevelSoundEventType::RandomAnvilUse => 175,
+            LevelSoundEventType::BottleDragonBreath => 176,
+            LevelSoundEventType::PortalTravel => 177,
+            LevelSoundEventType::ItemTridentHit => 178,
+            LevelSoundEventType::ItemTridentReturn => 179,
+            LevelSoundEventType::ItemTridentRiptide1 => 180,
+            LevelSoundEventType::ItemTridentRiptide2 => 181,
+            LevelSoundEventType::ItemTridentRiptide3 => 182,
+            LevelSoundEventType::ItemTridentThrow => 183,
+            LevelSoundEventType::ItemTridentThunder => 184,
+            LevelSoundEventType::ItemTridentHitGround => 185,
+            LevelSoundEventType::Default => 186,
+            LevelSoundEventType::BlockFletchingTableUse => 187,
+            LevelSoundEventType::ElemConstructOpen => 188,
+            LevelSoundEventType::IceBombHit => 189,
+            LevelSoundEventType::BalloonPop => 190,
+            LevelSoundEventType::LtReactionIceBomb => 191,
+            LevelSoundEventType::LtReactionBleach => 192,
+            LevelSoundEventType::LtReactionElephantToothpaste => 193,
+            LevelSoundEventType::LtReactionElephantToothpaste2 => 194,
+            LevelSoundEventType::LtReactionGlowStick => 195,
+            LevelSoundEventType::LtReactionGlowStick2 => 196,
+            LevelSoundEventType::LtReactionLuminol => 197,
+            LevelSoundEventType::LtReactionSalt => 198,
+            LevelSoundEventType::LtReactionFertilizer => 199,
+            LevelSoundEventType::LtReactionFireball => 200,
+            LevelSoundEventType::LtReactionMagnesiumSalt => 201,
+            LevelSoundEventType::LtReactionMiscFire => 202,
+            LevelSoundEventType::LtReactionFire => 203,
+            LevelSoundEventType::LtReactionMiscExplosion => 204,
+            LevelSoundEventType::LtReactionMiscMystical => 205,
+            LevelSoundEventType::LtReactionMiscMystical2 => 206,
+            LevelSoundEventType::LtReactionProduct => 207,
+            LevelSoundEventType::SparklerUse => 208,
+            LevelSoundEventType::GlowStickUse => 209,
+            LevelSoundEventType::SparklerActive => 210,
+            LevelSoundEventType::ConvertToDrowned => 211,
+            LevelSoundEventType::BucketFillFish => 212,
+            LevelSoundEventType::BucketEmptyFish => 213,
+            LevelSoundEventType::BubbleUp => 214,
+            LevelSoundEventType::BubbleDown => 215,
+            LevelSoundEventType::BubblePop => 216,
+            LevelSoundEventType::BubbleUpInside => 217,
+            LevelSoundEventType::BubbleDownInside => 218,
+            LevelSoundEventType::HurtBaby => 219,
+            LevelSoundEventType::DeathBaby => 220,
+            LevelSoundEventType::StepBaby => 221,
+            LevelSoundEventType::SpawnBaby => 222,
+            LevelSoundEventType::Born => 223,
+            LevelSoundEventType::TurtleEggBreak => 224,
+            LevelSoundEventType::TurtleEggCrack => 225,
+            LevelSoundEventType::TurtleEggHatched => 226,
+            LevelSoundEventType::LayEgg => 227,
+            LevelSoundEventType::TurtleEggAttacked => 228,
+            LevelSoundEventType::BeaconActivate => 229,
+            LevelSoundEventType::BeaconAmbient => 230,
+            LevelSoundEventType::BeaconDeactivate => 231,
+            LevelSoundEventType::BeaconPower => 232,
+            LevelSoundEventType::ConduitActivate => 233,
+            LevelSoundEventType::ConduitAmbient => 234,
+            LevelSoundEventType::ConduitAttack => 235,
+            LevelSoundEventType::ConduitDeactivate => 236,
+            LevelSoundEventType::ConduitShort => 237,
+            LevelSoundEventType::Swoop => 238,
+            LevelSoundEventType::BlockBambooSaplingPlace => 239,
+            LevelSoundEventType::PreSneeze => 240,
+            LevelSoundEventType::Sneeze => 241,
+            LevelSoundEventType::AmbientTame => 242,
+            LevelSoundEventType::Scared => 243,
+            LevelSoundEventType::BlockScaffoldingClimb => 244,
+            LevelSoundEventType::CrossbowLoadingStart => 245,
+            LevelSoundEventType::CrossbowLoadingMiddle => 246,
+            LevelSoundEventType::CrossbowLoadingEnd => 247,
+            LevelSoundEventType::CrossbowShoot => 248,
+            LevelSoundEventType::CrossbowQuickChargeStart => 249,
+            LevelSoundEventType::CrossbowQuickChargeMiddle => 250,
+            LevelSoundEventType::CrossbowQuickChargeEnd => 251,
+            LevelSoundEventType::AmbientAggressive => 252,
+            LevelSoundEventType::AmbientWorried => 253,
+            LevelSoundEventType::CantBreed => 254,
+            LevelSoundEventType::ItemShieldBlock => 255,
+            LevelSoundEventType::ItemBookPut => 256,
+            LevelSoundEventType::BlockGrindstoneUse => 257,
+            LevelSoundEventType::BlockBellHit => 258,
+            LevelSoundEventType::BlockCampfireCrackle => 259,
+            LevelSoundEventType::Roar => 260,
+            LevelSoundEventType::Stun => 261,
+            LevelSoundEventType::BlockSweetBerryBushHurt => 262,
+            LevelSoundEventType::BlockSweetBerryBushPick => 263,
+            LevelSoundEventType::BlockCartographyTableUse => 264,
+            LevelSoundEventType::BlockStonecutterUse => 265,
+            LevelSoundEventType::BlockComposterEmpty => 266,
+            LevelSoundEventType::BlockComposterFill => 267,
+            LevelSoundEventType::BlockComposterFillSuccess => 268,
+            LevelSoundEventType::BlockComposterReady => 269,
+            LevelSoundEventType::BlockBarrelOpen => 270,
+            LevelSoundEventType::BlockBarrelClose => 271,
+            LevelSoundEventType::RaidHorn => 272,
+            LevelSoundEventType::BlockLoomUse => 273,
+            LevelSoundEventType::AmbientInRaid => 274,
+            LevelSoundEventType::UiCartographyTableTakeResult => 275,
+            LevelSoundEventType::UiStonecutterTakeResult => 276,
+            LevelSoundEventType::UiLoomTakeResult => 277,
+            LevelSoundEventType::BlockSmokerSmoke => 278,
+            LevelSoundEventType::BlockBlastFurnaceFireCrackle => 279,
+            LevelSoundEventType::BlockSmithingTableUse => 280,
+            LevelSoundEventType::Screech => 281,
+            LevelSoundEventType::Sleep => 282,
+            LevelSoundEventType::BlockFurnaceLit => 283,
+            LevelSoundEventType::ConvertMooshroom => 284,
+            LevelSoundEventType::MilkSuspiciously => 285,
+            LevelSoundEventType::Celebrate => 286,
+            LevelSoundEventType::JumpPrevent => 287,
+            LevelSoundEventType::AmbientPollinate => 288,
+            LevelSoundEventType::BlockBeehiveDrip => 289,
+            LevelSoundEventType::BlockBeehiveEnter => 290,
+            LevelSoundEventType::BlockBeehiveExit => 291,
+            LevelSoundEventType::BlockBeehiveWork => 292,
+            LevelSoundEventType::BlockBeehiveShear => 293,
+            LevelSoundEventType::DrinkHoney => 294,
+            LevelSoundEventType::AmbientCave => 295,
+            LevelSoundEventType::Retreat => 296,
+            LevelSoundEventType::ConvertedToZombified => 297,
+            LevelSoundEventType::Admire => 298,
+            LevelSoundEventType::StepLava => 299,
+            LevelSoundEventType::Tempt => 300,
+            LevelSoundEventType::Panic => 301,
+            LevelSoundEventType::Angry => 302,
+            LevelSoundEventType::AmbientWarpedForestMood => 303,
+            LevelSoundEventType::AmbientSoulsandValleyMood => 304,
+            LevelSoundEventType::AmbientNetherWastesMood => 305,
+            LevelSoundEventType::AmbientBasaltDeltasMood => 306,
+            LevelSoundEventType::AmbientCrimsonForestMood => 307,
+            LevelSoundEventType::RespawnAnchorCharge => 308,
+            LevelSoundEventType::RespawnAnchorDeplete => 309,
+            LevelSoundEventType::RespawnAnchorSetSpawn => 310,
+            LevelSoundEventType::RespawnAnchorAmbient => 311,
+            LevelSoundEventType::SoulEscapeQuiet => 312,
+            LevelSoundEventType::SoulEscapeLoud => 313,
+            LevelSoundEventType::RecordPigstep => 314,
+            LevelSoundEventType::LinkCompassToLodestone => 315,
+            LevelSoundEventType::UseSmithingTable => 316,
+            LevelSoundEventType::EquipNetherite => 317,
+            LevelSoundEventType::AmbientLoopWarpedForest => 318,
+            LevelSoundEventType::AmbientLoopSoulsandValley => 319,
+            LevelSoundEventType::AmbientLoopNetherWastes => 320,
+            LevelSoundEventType::AmbientLoopBasaltDeltas => 321,
+            LevelSoundEventType::AmbientLoopCrimsonForest => 322,
+            LevelSoundEventType::AmbientAdditionWarpedForest => 323,
+            LevelSoundEventType::AmbientAdditionSoulsandValley => 324,
+            LevelSoundEventType::AmbientAdditionNetherWastes => 325,
+            LevelSoundEventType::AmbientAdditionBasaltDeltas => 326,
+            LevelSoundEventType::AmbientAdditionCrimsonForest => 327,
+            LevelSoundEventType::SculkSensorPowerOn => 328,
+            LevelSoundEventType::SculkSensorPowerOff => 329,
+            LevelSoundEventType::BucketFillPowderSnow => 330,
+            LevelSoundEventType::BucketEmptyPowderSnow => 331,
+            LevelSoundEventType::PointedDripstoneCauldronDripWater => 332,
+            LevelSoundEventType::PointedDripstoneCauldronDripLava => 333,
+            LevelSoundEventType::PointedDripstoneDripWater => 334,
+            LevelSoundEventType::PointedDripstoneDripLava => 335,
+            LevelSoundEventType::CaveVinesPickBerries => 336,
+            LevelSoundEventType::BigDripleafTiltDown => 337,
+            LevelSoundEventType::BigDripleafTiltUp => 338,
+            LevelSoundEventType::CopperWaxOn => 339,
+            LevelSoundEventType::CopperWaxOff => 340,
+            LevelSoundEventType::Scrape => 341,
+            LevelSoundEventType::PlayerHurtDrown => 342,
+            LevelSoundEventType::PlayerHurtOnFire => 343,
+            LevelSoundEventType::PlayerHurtFreeze => 344,
+            LevelSoundEventType::UseSpyglass => 345,
+            LevelSoundEventType::StopUsingSpyglass => 346,
+            LevelSoundEventType::AmethystBlockChime => 347,
+            LevelSoundEventType::AmbientScreamer => 348,
+            LevelSoundEventType::HurtScreamer => 349,
+            LevelSoundEventType::DeathScreamer => 350,
+            LevelSoundEventType::MilkScreamer => 351,
+            LevelSoundEventType::JumpToBlock => 352,
+            LevelSoundEventType::PreRam => 353,
+            LevelSoundEventType::PreRamScreamer => 354,
+            LevelSoundEventType::RamImpact => 355,
+            LevelSoundEventType::RamImpactScreamer => 356,
+            LevelSoundEventType::SquidInkSquirt => 357,
+            LevelSoundEventType::GlowSquidInkSquirt => 358,
+            LevelSoundEventType::ConvertToStray => 359,
+            LevelSoundEventType::CakeAddCandle => 360,
+            LevelSoundEventType::ExtinguishCandle => 361,
+            LevelSoundEventType::AmbientCandle => 362,
+            LevelSoundEventType::BlockClick => 363,
+            LevelSoundEventType::BlockClickFail => 364,
+            LevelSoundEventType::SculkCatalystBloom => 365,
+            LevelSoundEventType::SculkShriekerShriek => 366,
+            LevelSoundEventType::WardenNearbyClose => 367,
+            LevelSoundEventType::WardenNearbyCloser => 368,
+            LevelSoundEventType::WardenNearbyClosest => 369,
+            LevelSoundEventType::WardenSlightlyAngry => 370,
+            LevelSoundEventType::RecordOtherside => 371,
+            LevelSoundEventType::Tongue => 372,
+            LevelSoundEventType::CrackIronGolem => 373,
+            LevelSoundEventType::RepairIronGolem => 374,
+            LevelSoundEventType::Listening => 375,
+            LevelSoundEventType::Heartbeat => 376,
+            LevelSoundEventType::HornBreak => 377,
+            LevelSoundEventType::SculkPlace => 378,
+            LevelSoundEventType::SculkSpread => 379,
+            LevelSoundEventType::SculkCharge => 380,
+            LevelSoundEventType::SculkSensorPlace => 381,
+            LevelSoundEventType::SculkShriekerPlace => 382,
+            LevelSoundEventType::GoatCall0 => 383,
+            LevelSoundEventType::GoatCall1 => 384,
+            LevelSoundEventType::GoatCall2 => 385,
+            LevelSoundEventType::GoatCall3 => 386,
+            LevelSoundEventType::GoatCall4 => 387,
+            LevelSoundEventType::GoatCall5 => 388,
+            LevelSoundEventType::GoatCall6 => 389,
+            LevelSoundEventType::GoatCall7 => 390,
+            LevelSoundEventType::GoatCall8 => 391,
+            LevelSoundEventType::GoatCall9 => 392,
+            LevelSoundEventType::GoatHarmony0 => 393,
+            LevelSoundEventType::GoatHarmony1 => 394,
+            LevelSoundEventType::GoatHarmony2 => 395,
+            LevelSoundEventType::GoatHarmony3 => 396,
+            LevelSoundEventType::GoatHarmony4 => 397,
+            LevelSoundEventType::GoatHarmony5 => 398,
+            LevelSoundEventType::GoatHarmony6 => 399,
+            LevelSoundEventType::GoatHarmony7 => 400,
+            LevelSoundEventType::GoatHarmony8 => 401,
+            LevelSoundEventType::GoatHarmony9 => 402,
+            LevelSoundEventType::GoatMelody0 => 403,
+            LevelSoundEventType::GoatMelody1 => 404,
+            LevelSoundEventType::GoatMelody2 => 405,
+            LevelSoundEventType::GoatMelody3 => 406,
+            LevelSoundEventType::GoatMelody4 => 407,
+            LevelSoundEventType::GoatMelody5 => 408,
+            LevelSoundEventType::GoatMelody6 => 409,
+            LevelSoundEventType::GoatMelody7 => 410,
+            LevelSoundEventType::GoatMelody8 => 411,
+            LevelSoundEventType::GoatMelody9 => 412,
+            LevelSoundEventType::GoatBass0 => 413,
+            LevelSoundEventType::GoatBass1 => 414,
+            LevelSoundEventType::GoatBass2 => 415,
+            LevelSoundEventType::GoatBass3 => 416,
+            LevelSoundEventType::GoatBass4 => 417,
+            LevelSoundEventType::GoatBass5 => 418,
+            LevelSoundEventType::GoatBass6 => 419,
+            LevelSoundEventType::GoatBass7 => 420,
+            LevelSoundEventType::GoatBass8 => 421,
+            LevelSoundEventType::GoatBass9 => 422,
+            LevelSoundEventType::ImitateWarden => 423,
+            LevelSoundEventType::ListeningAngry => 424,
+            LevelSoundEventType::ItemGiven => 425,
+            LevelSoundEventType::ItemTaken => 426,
+            LevelSoundEventType::Disappeared => 427,
+            LevelSoundEventType::Reappeared => 428,
+            LevelSoundEventType::DrinkMilk => 429,
+            LevelSoundEventType::FrogspawnHatched => 430,
+            LevelSoundEventType::LaySpawn => 431,
+            LevelSoundEventType::FrogspawnBreak => 432,
+            LevelSoundEventType::SonicBoom => 433,
+            LevelSoundEventType::SonicCharge => 434,
+            LevelSoundEventType::ItemThrown => 435,
+            LevelSoundEventType::Record5 => 436,
+            LevelSoundEventType::ConvertToFrog => 437,
+            LevelSoundEventType::RecordPlaying => 438,
+            LevelSoundEventType::EnchantingTableUse => 439,
+            LevelSoundEventType::StepSand => 440,
+            LevelSoundEventType::DashReady => 441,
+            LevelSoundEventType::BundleDropContents => 442,
+            LevelSoundEventType::BundleInsert => 443,
+            LevelSoundEventType::BundleRemoveOne => 444,
+            LevelSoundEventType::PressurePlateClickOff => 445,
+            LevelSoundEventType::PressurePlateClickOn => 446,
+            LevelSoundEventType::ButtonClickOff => 447,
+            LevelSoundEventType::ButtonClickOn => 448,
+            LevelSoundEventType::DoorOpen => 449,
+            LevelSoundEventType::DoorClose => 450,
+            LevelSoundEventType::TrapdoorOpen => 451,
+            LevelSoundEventType::TrapdoorClose => 452,
+            LevelSoundEventType::FenceGateOpen => 453,
+            LevelSoundEventType::FenceGateClose => 454,
+            LevelSoundEventType::Insert => 455,
+            LevelSoundEventType::Pickup => 456,
+            LevelSoundEventType::InsertEnchanted => 457,
+            LevelSoundEventType::PickupEnchanted => 458,
+            LevelSoundEventType::Brush => 459,
+            LevelSoundEventType::BrushCompleted => 460,
+            LevelSoundEventType::ShatterDecoratedPot => 461,
+            LevelSoundEventType::BreakDecoratedPot => 462,
+            LevelSoundEventType::SnifferEggCrack => 463,
+            LevelSoundEventType::SnifferEggHatched => 464,
+            LevelSoundEventType::WaxedSignInteractFail => 465,
+            LevelSoundEventType::RecordRelic => 466,
+            LevelSoundEventType::Bump => 467,
+            LevelSoundEventType::PumpkinCarve => 468,
+            LevelSoundEventType::ConvertHuskToZombie => 469,
+            LevelSoundEventType::PigDeath => 470,
+            LevelSoundEventType::HoglinConvertToZombified => 471,
+            LevelSoundEventType::AmbientUnderwaterEnter => 472,
+            LevelSoundEventType::AmbientUnderwaterExit => 473,
+            LevelSoundEventType::BottleFill => 474,
+            LevelSoundEventType::BottleEmpty => 475,
+            LevelSoundEventType::CrafterCraft => 476,
+            LevelSoundEventType::CrafterFail => 477,
+            LevelSoundEventType::DecoratedPotInsert => 478,
+            LevelSoundEventType::DecoratedPotInsertFail => 479,
+            LevelSoundEventType::CrafterDisableSlot => 480,
+            LevelSoundEventType::TrialSpawnerOpenShutter => 481,
+            LevelSoundEventType::TrialSpawnerEjectItem => 482,
+            LevelSoundEventType::TrialSpawnerDetectPlayer => 483,
+            LevelSoundEventType::TrialSpawnerSpawnMob => 484,
+            LevelSoundEventType::TrialSpawnerCloseShutter => 485,
+            LevelSoundEventType::TrialSpawnerAmbient => 486,
+            LevelSoundEventType::CopperBulbTurnOn => 487,
+            LevelSoundEventType::CopperBulbTurnOff => 488,
+            LevelSoundEventType::AmbientInAir => 489,
+            LevelSoundEventType::BreezeWindChargeBurst => 490,
+            LevelSoundEventType::ImitateBreeze => 491,
+            LevelSoundEventType::ArmadilloBrush => 492,
+            LevelSoundEventType::ArmadilloScuteDrop => 493,
+            LevelSoundEventType::EquipWolf => 494,
+            LevelSoundEventType::UnequipWolf => 495,
+            LevelSoundEventType::Reflect => 496,
+            LevelSoundEventType::VaultOpenShutter => 497,
+            LevelSoundEventType::VaultCloseShutter => 498,
+            LevelSoundEventType::VaultEjectItem => 499,
+            LevelSoundEventType::VaultInsertItem => 500,
+            LevelSoundEventType::VaultInsertItemFail => 501,
+            LevelSoundEventType::VaultAmbient => 502,
+            LevelSoundEventType::VaultActivate => 503,
+            LevelSoundEventType::VaultDeactivate => 504,
+            LevelSoundEventType::HurtReduced => 505,
+            LevelSoundEventType::WindChargeBurst => 506,
+            LevelSoundEventType::ImitateBogged => 507,
+            LevelSoundEventType::WolfArmorCrack => 508,
+            LevelSoundEventType::WolfArmorBreak => 509,
+            LevelSoundEventType::WolfArmorRepair => 510,
+            LevelSoundEventType::MaceSmashAir => 511,
+            LevelSoundEventType::MaceSmashGround => 512,
+            LevelSoundEventType::TrialSpawnerChargeActivate => 513,
+            LevelSoundEventType::TrialSpawnerAmbientOminous => 514,
+            LevelSoundEventType::OminousItemSpawnerSpawnItem => 515,
+            LevelSoundEventType::OminousBottleEndUse => 516,
+            LevelSoundEventType::MaceHeavySmashGround => 517,
+            LevelSoundEventType::OminousItemSpawnerSpawnItemBegin => 518,
+            LevelSoundEventType::Unknown(id) => *id,
+        }
+    }
+
+    pub fn from_id(id: u32) -> Self {
+        match id {
+            0 => LevelSoundEventType::ItemUseOn,
+            1 => LevelSoundEventType::Hit,
+            2 => LevelSoundEventType::Step,
+            3 => LevelSoundEventType::Fly,
+            4 => LevelSoundEventType::Jump,
+            5 => LevelSoundEventType::Break,
+            6 => LevelSoundEventType::Place,
+            7 => LevelSoundEventType::HeavyStep,
+            8 => LevelSoundEventType::Gallop,
+            9 => LevelSoundEventType::Fall,
+            10 => LevelSoundEventType::Ambient,
+            11 => LevelSoundEventType::AmbientBaby,
+            12 => LevelSoundEventType::AmbientInWater,
+            13 => LevelSoundEventType::Breathe,
+            14 => LevelSoundEventType::Death,
+            15 => LevelSoundEventType::DeathInWater,
+            16 => LevelSoundEventType::DeathToZombie,
+            17 => LevelSoundEventType::Hurt,
+            18 => LevelSoundEventType::HurtInWater,
+            19 => LevelSoundEventType::Mad,
+            20 => LevelSoundEventType::Boost,
+            21 => LevelSoundEventType::Bow,
+            22 => LevelSoundEventType::SquishBig,
+            23 => LevelSoundEventType::SquishSmall,
+            24 => LevelSoundEventType::FallBig,
+            25 => LevelSoundEventType::FallSmall,
+            26 => LevelSoundEventType::Splash,
+            27 => LevelSoundEventType::Fizz,
+            28 => LevelSoundEventType::Flap,
+            29 => LevelSoundEventType::Swim,
+            30 => LevelSoundEventType::Drink,
+            31 => LevelSoundEventType::Eat,
+            32 => LevelSoundEventType::Takeoff,
+            33 => LevelSoundEventType::Shake,
+            34 => LevelSoundEventType::Plop,
+            35 => LevelSoundEventType::Land,
+            36 => LevelSoundEventType::Saddle,
+            37 => LevelSoundEventType::Armor,
+            38 => LevelSoundEventType::MobArmorStandPlace,
+            39 => LevelSoundEventType::AddChest,
+            40 => LevelSoundEventType::Throw,
+            41 => LevelSoundEventType::Attack,
+            42 => LevelSoundEventType::AttackNoDamage,
+            43 => LevelSoundEventType::AttackStrong,
+            44 => LevelSoundEventType::Warn,
+            45 => LevelSoundEventType::Shear,
+            46 => LevelSoundEventType::Milk,
+            47 => LevelSoundEventType::Thunder,
+            48 => LevelSoundEventType::Explode,
+            49 => LevelSoundEventType::Fire,
+            50 => LevelSoundEventType::Ignite,
+            51 => LevelSoundEventType::Fuse,
+            52 => LevelSoundEventType::Stare,
+            53 => LevelSoundEventType::Spawn,
+            54 => LevelSoundEventType::Shoot,
+            55 => LevelSoundEventType::BreakBlock,
+            56 => LevelSoundEventType::Launch,
+            57 => LevelSoundEventType::Blast,
+            58 => LevelSoundEventType::LargeBlast,
+            59 => LevelSoundEventType::Twinkle,
+            60 => LevelSoundEventType::Remedy,
+            61 => LevelSoundEventType::Infect,
+            62 => LevelSoundEventType::LevelUp,
+            63 => LevelSoundEventType::BowHit,
+            64 => LevelSoundEventType::BulletHit,
+            65 => LevelSoundEventType::ExtinguishFire,
+            66 => LevelSoundEventType::ItemFizz,
+            67 => LevelSoundEventType::ChestOpen,
+            68 => LevelSoundEventType::ChestClosed,
+            69 => LevelSoundEventType::ShulkerBoxOpen,
+            70 => LevelSoundEventType::ShulkerBoxClosed,
+            71 => LevelSoundEventType::EnderChestOpen,
+            72 => LevelSoundEventType::EnderChestClosed,
+            73 => LevelSoundEventType::PowerOn,
+            74 => LevelSoundEventType::PowerOff,
+            75 => LevelSoundEventType::Attach,
+            76 => LevelSoundEventType::Detach,
+            77 => LevelSoundEventType::Deny,
+            78 => LevelSoundEventType::Tripod,
+            79 => LevelSoundEventType::Pop,
+            80 => LevelSoundEventType::DropSlot,
+            81 => LevelSoundEventType::Note,
+            82 => LevelSoundEventType::Thorns,
+            83 => LevelSoundEventType::PistonIn,
+            84 => LevelSoundEventType::PistonOut,
+            85 => LevelSoundEventType::Portal,
+            86 => LevelSoundEventType::Water,
+            87 => LevelSoundEventType::LavaPop,
+            88 => LevelSoundEventType::Lava,
+            89 => LevelSoundEventType::Burp,
+            90 => LevelSoundEventType::BucketFillWater,
+            91 => LevelSoundEventType::BucketFillLava,
+            92 => LevelSoundEventType::BucketEmptyWater,
+            93 => LevelSoundEventType::BucketEmptyLava,
+            94 => LevelSoundEventType::ArmorEquipChain,
+            95 => LevelSoundEventType::ArmorEquipDiamond,
+            96 => LevelSoundEventType::ArmorEquipGeneric,
+            97 => LevelSoundEventType::ArmorEquipGold,
+            98 => LevelSoundEventType::ArmorEquipIron,
+            99 => LevelSoundEventType::ArmorEquipLeather,
+            100 => LevelSoundEventType::ArmorEquipElytra,
+            101 => LevelSoundEventType::Record13,
+            102 => LevelSoundEventType::RecordCat,
+            103 => LevelSoundEventType::RecordBlocks,
+            104 => LevelSoundEventType::RecordChirp,
+            105 => LevelSoundEventType::RecordFar,
+            106 => LevelSoundEventType::RecordMall,
+            107 => LevelSoundEventType::RecordMellohi,
+            108 => LevelSoundEventType::RecordStal,
+            109 => LevelSoundEventType::RecordStrad,
+            110 => LevelSoundEventType::RecordWard,
+            111 => LevelSoundEventType::Record11,
+            112 => LevelSoundEventType::RecordWait,
+            113 => LevelSoundEventType::StopRecord,
+            114 => LevelSoundEventType::Flop,
+            115 => LevelSoundEventType::ElderGuardianCurse,
+            116 => LevelSoundEventType::MobWarning,
+            117 => LevelSoundEventType::MobWarningBaby,
+            118 => LevelSoundEventType::Teleport,
+            119 => LevelSoundEventType::ShulkerOpen,
+            120 => LevelSoundEventType::ShulkerClose,
+            121 => LevelSoundEventType::Haggle,
+            122 => LevelSoundEventType::HaggleYes,
+            123 => LevelSoundEventType::HaggleNo,
+            124 => LevelSoundEventType::HaggleIdle,
+            125 => LevelSoundEventType::ChorusGrow,
+            126 => LevelSoundEventType::ChorusDeath,
+            127 => LevelSoundEventType::Glass,
+            128 => LevelSoundEventType::PotionBrewed,
+            129 => LevelSoundEventType::CastSpell,
+            130 => LevelSoundEventType::PrepareAttack,
+            131 => LevelSoundEventType::PrepareSummon,
+            132 => LevelSoundEventType::PrepareWololo,
+            133 => LevelSoundEventType::Fang,
+            134 => LevelSoundEventType::Charge,
+            135 => LevelSoundEventType::CameraTakePicture,
+            136 => LevelSoundEventType::LeashKnotPlace,
+            137 => LevelSoundEventType::LeashKnotBreak,
+            138 => LevelSoundEventType::Growl,
+            139 => LevelSoundEventType::Whine,
+            140 => LevelSoundEventType::Pant,
+            141 => LevelSoundEventType::Purr,
+            142 => LevelSoundEventType::Purreow,
+            143 => LevelSoundEventType::DeathMinVolume,
+            144 => LevelSoundEventType::DeathMidVolume,
+            145 => LevelSoundEventType::ImitateBlaze,
+            146 => LevelSoundEventType::ImitateCaveSpider,
+            147 => LevelSoundEventType::ImitateCreeper,
+            148 => LevelSoundEventType::ImitateElderGuardian,
+            149 => LevelSoundEventType::ImitateEnderDragon,
+            150 => LevelSoundEventType::ImitateEnderman,
+            151 => LevelSoundEventType::ImitateEndermite,
+            152 => LevelSoundEventType::ImitateEvocationIllager,
+            153 => LevelSoundEventType::ImitateGhast,
+            154 => LevelSoundEventType::ImitateHusk,
+            155 => LevelSoundEventType::ImitateIllusionIllager,
+            156 => LevelSoundEventType::ImitateMagmaCube,
+            157 => LevelSoundEventType::ImitatePolarBear,
+            158 => LevelSoundEventType::ImitateShulker,
+            159 => LevelSoundEventType::ImitateSilverfish,
+            160 => LevelSoundEventType::ImitateSkeleton,
+            161 => LevelSoundEventType::ImitateSlime,
+            162 => LevelSoundEventType::ImitateSpider,
+            163 => LevelSoundEventType::ImitateStray,
+            164 => LevelSoundEventType::ImitateVex,
+            165 => LevelSoundEventType::ImitateVindicationIllager,
+            166 => LevelSoundEventType::ImitateWitch,
+            167 => LevelSoundEventType::ImitateWither,
+            168 => LevelSoundEventType::ImitateWitherSkeleton,
+            169 => LevelSoundEventType::ImitateWolf,
+            170 => LevelSoundEventType::ImitateZombie,
+            171 => LevelSoundEventType::ImitateZombiePigman,
+            172 => LevelSoundEventType::ImitateZombieVillager,
+            173 => LevelSoundEventType::BlockEndPortalFrameFill,
+            174 => LevelSoundEventType::BlockEndPortalSpawn,
+            175 => LevelSoundEventType::RandomAnvilUse,
+            176 => LevelSoundEventType::BottleDragonBreath,
+            177 => LevelSoundEventType::PortalTravel,
+            178 => LevelSoundEventType::ItemTridentHit,
+            179 => LevelSoundEventType::ItemTridentReturn,
+            180 => LevelSoundEventType::ItemTridentRiptide1,
+            181 => LevelSoundEventType::ItemTridentRiptide2,
+            182 => LevelSoundEventType::ItemTridentRiptide3,
+            183 => LevelSoundEventType::ItemTridentThrow,
+            184 => LevelSoundEventType::ItemTridentThunder,
+            185 => LevelSoundEventType::ItemTridentHitGround,
+            186 => LevelSoundEventType::Default,
+            187 => LevelSoundEventType::BlockFletchingTableUse,
+            188 => LevelSoundEventType::ElemConstructOpen,
+            189 => LevelSoundEventType::IceBombHit,
+            190 => LevelSoundEventType::BalloonPop,
+            191 => LevelSoundEventType::LtReactionIceBomb,
+            192 => LevelSoundEventType::LtReactionBleach,
+            193 => LevelSoundEventType::LtReactionElephantToothpaste,
+            194 => LevelSoundEventType::LtReactionElephantToothpaste2,
+            195 => LevelSoundEventType::LtReactionGlowStick,
+            196 => LevelSoundEventType::LtReactionGlowStick2,
+            197 => LevelSoundEventType::LtReactionLuminol,
+            198 => LevelSoundEventType::LtReactionSalt,
+            199 => LevelSoundEventType::LtReactionFertilizer,
+            200 => LevelSoundEventType::LtReactionFireball,
+            201 => LevelSoundEventType::LtReactionMagnesiumSalt,
+            202 => LevelSoundEventType::LtReactionMiscFire,
+            203 => LevelSoundEventType::LtReactionFire,
+            204 => LevelSoundEventType::LtReactionMiscExplosion,
+            205 => LevelSoundEventType::LtReactionMiscMystical,
+            206 => LevelSoundEventType::LtReactionMiscMystical2,
+            207 => LevelSoundEventType::LtReactionProduct,
+            208 => LevelSoundEventType::SparklerUse,
+            209 => LevelSoundEventType::GlowStickUse,
+            210 => LevelSoundEventType::SparklerActive,
+            211 => LevelSoundEventType::ConvertToDrowned,
+            212 => LevelSoundEventType::BucketFillFish,
+            213 => LevelSoundEventType::BucketEmptyFish,
+            214 => LevelSoundEventType::BubbleUp,
+            215 => LevelSoundEventType::BubbleDown,
+            216 => LevelSoundEventType::BubblePop,
+            217 => LevelSoundEventType::BubbleUpInside,
+            218 => LevelSoundEventType::BubbleDownInside,
+            219 => LevelSoundEventType::HurtBaby,
+            220 => LevelSoundEventType::DeathBaby,
+            221 => LevelSoundEventType::StepBaby,
+            222 => LevelSoundEventType::SpawnBaby,
+            223 => LevelSoundEventType::Born,
+            224 => LevelSoundEventType::TurtleEggBreak,
+            225 => LevelSoundEventType::TurtleEggCrack,
+            226 => LevelSoundEventType::TurtleEggHatched,
+            227 => LevelSoundEventType::LayEgg,
+            228 => LevelSoundEventType::TurtleEggAttacked,
+            229 => LevelSoundEventType::BeaconActivate,
+            230 => LevelSoundEventType::BeaconAmbient,
+            231 => LevelSoundEventType::BeaconDeactivate,
+            232 => LevelSoundEventType::BeaconPower,
+            233 => LevelSoundEventType::ConduitActivate,
+            234 => LevelSoundEventType::ConduitAmbient,
+            235 => LevelSoundEventType::ConduitAttack,
+            236 => LevelSoundEventType::ConduitDeactivate,
+            237 => LevelSoundEventType::ConduitShort,
+            238 => LevelSoundEventType::Swoop,
+            239 => LevelSoundEventType::BlockBambooSaplingPlace,
+            240 => LevelSoundEventType::PreSneeze,
+            241 => LevelSoundEventType::Sneeze,
+            242 => LevelSoundEventType::AmbientTame,
+            243 => LevelSoundEventType::Scared,
+            244 => LevelSoundEventType::BlockScaffoldingClimb,
+            245 => LevelSoundEventType::CrossbowLoadingStart,
+            246 => LevelSoundEventType::CrossbowLoadingMiddle,
+            247 => LevelSoundEventType::CrossbowLoadingEnd,
+            248 => LevelSoundEventType::CrossbowShoot,
+            249 => LevelSoundEventType::CrossbowQuickChargeStart,
+            250 => LevelSoundEventType::CrossbowQuickChargeMiddle,
+            251 => LevelSoundEventType::CrossbowQuickChargeEnd,
+            252 => LevelSoundEventType::AmbientAggressive,
+            253 => LevelSoundEventType::AmbientWorried,
+            254 => LevelSoundEventType::CantBreed,
+            255 => LevelSoundEventType::ItemShieldBlock,
+            256 => LevelSoundEventType::ItemBookPut,
+            257 => LevelSoundEventType::BlockGrindstoneUse,
+            258 => LevelSoundEventType::BlockBellHit,
+            259 => LevelSoundEventType::BlockCampfireCrackle,
+            260 => LevelSoundEventType::Roar,
+            261 => LevelSoundEventType::Stun,
+            262 => LevelSoundEventType::BlockSweetBerryBushHurt,
+            263 => LevelSoundEventType::BlockSweetBerryBushPick,
+            264 => LevelSoundEventType::BlockCartographyTableUse,
+            265 => LevelSoundEventType::BlockStonecutterUse,
+            266 => LevelSoundEventType::BlockComposterEmpty,
+            267 => LevelSoundEventType::BlockComposterFill,
+            268 => LevelSoundEventType::BlockComposterFillSuccess,
+            269 => LevelSoundEventType::BlockComposterReady,
+            270 => LevelSoundEventType::BlockBarrelOpen,
+            271 => LevelSoundEventType::BlockBarrelClose,
+            272 => LevelSoundEventType::RaidHorn,
+            273 => LevelSoundEventType::BlockLoomUse,
+            274 => LevelSoundEventType::AmbientInRaid,
+            275 => LevelSoundEventType::UiCartographyTableTakeResult,
+            276 => LevelSoundEventType::UiStonecutterTakeResult,
+            277 => LevelSoundEventType::UiLoomTakeResult,
+            278 => LevelSoundEventType::BlockSmokerSmoke,
+            279 => LevelSoundEventType::BlockBlastFurnaceFireCrackle,
+            280 => LevelSoundEventType::BlockSmithingTableUse,
+            281 => LevelSoundEventType::Screech,
+            282 => LevelSoundEventType::Sleep,
+            283 => LevelSoundEventType::BlockFurnaceLit,
+            284 => LevelSoundEventType::ConvertMooshroom,
+            285 => LevelSoundEventType::MilkSuspiciously,
+            286 => LevelSoundEventType::Celebrate,
+            287 => LevelSoundEventType::JumpPrevent,
+            288 => LevelSoundEventType::AmbientPollinate,
+            289 => LevelSoundEventType::BlockBeehiveDrip,
+            290 => LevelSoundEventType::BlockBeehiveEnter,
+            291 => LevelSoundEventType::BlockBeehiveExit,
+            292 => LevelSoundEventType::BlockBeehiveWork,
+            293 => LevelSoundEventType::BlockBeehiveShear,
+            294 => LevelSoundEventType::DrinkHoney,
+            295 => LevelSoundEventType::AmbientCave,
+            296 => LevelSoundEventType::Retreat,
+            297 => LevelSoundEventType::ConvertedToZombified,
+            298 => LevelSoundEventType::Admire,
+            299 => LevelSoundEventType::StepLava,
+            300 => LevelSoundEventType::Tempt,
+            301 => LevelSoundEventType::Panic,
+            302 => LevelSoundEventType::Angry,
+            303 => LevelSoundEventType::AmbientWarpedForestMood,
+            304 => LevelSoundEventType::AmbientSoulsandValleyMood,
+            305 => LevelSoundEventType::AmbientNetherWastesMood,
+            306 => LevelSoundEventType::AmbientBasaltDeltasMood,
+            307 => LevelSoundEventType::AmbientCrimsonForestMood,
+            308 => LevelSoundEventType::RespawnAnchorCharge,
+            309 => LevelSoundEventType::RespawnAnchorDeplete,
+            310 => LevelSoundEventType::RespawnAnchorSetSpawn,
+            311 => LevelSoundEventType::RespawnAnchorAmbient,
+            312 => LevelSoundEventType::SoulEscapeQuiet,
+            313 => LevelSoundEventType::SoulEscapeLoud,
+            314 => LevelSoundEventType::RecordPigstep,
+            315 => LevelSoundEventType::LinkCompassToLodestone,
+            316 => LevelSoundEventType::UseSmithingTable,
+            317 => LevelSoundEventType::EquipNetherite,
+            318 => LevelSoundEventType::AmbientLoopWarpedForest,
+            319 => LevelSoundEventType::AmbientLoopSoulsandValley,
+            320 => LevelSoundEventType::AmbientLoopNetherWastes,
+            321 => LevelSoundEventType::AmbientLoopBasaltDeltas,
+            322 => LevelSoundEventType::AmbientLoopCrimsonForest,
+            323 => LevelSoundEventType::AmbientAdditionWarpedForest,
+            324 => LevelSoundEventType::AmbientAdditionSoulsandValley,
+            325 => LevelSoundEventType::AmbientAdditionNetherWastes,
+            326 => LevelSoundEventType::AmbientAdditionBasaltDeltas,
+            327 => LevelSoundEventType::AmbientAdditionCrimsonForest,
+            328 => LevelSoundEventType::SculkSensorPowerOn,
+            329 => LevelSoundEventType::SculkSensorPowerOff,
+            330 => LevelSoundEventType::BucketFillPowderSnow,
+            331 => LevelSoundEventType::BucketEmptyPowderSnow,
+            332 => LevelSoundEventType::PointedDripstoneCauldronDripWater,
+            333 => LevelSoundEventType::PointedDripstoneCauldronDripLava,
+            334 => LevelSoundEventType::PointedDripstoneDripWater,
+            335 => LevelSoundEventType::PointedDripstoneDripLava,
+            336 => LevelSoundEventType::CaveVinesPickBerries,
+            337 => LevelSoundEventType::BigDripleafTiltDown,
+            338 => LevelSoundEventType::BigDripleafTiltUp,
+            339 => LevelSoundEventType::CopperWaxOn,
+            340 => LevelSoundEventType::CopperWaxOff,
+            341 => LevelSoundEventType::Scrape,
+            342 => LevelSoundEventType::PlayerHurtDrown,
+            343 => LevelSoundEventType::PlayerHurtOnFire,
+            344 => LevelSoundEventType::PlayerHurtFreeze,
+            345 => LevelSoundEventType::UseSpyglass,
+            346 => LevelSoundEventType::StopUsingSpyglass,
+            347 => LevelSoundEventType::AmethystBlockChime,
+            348 => LevelSoundEventType::AmbientScreamer,
+            349 => LevelSoundEventType::HurtScreamer,
+            350 => LevelSoundEventType::DeathScreamer,
+            351 => LevelSoundEventType::MilkScreamer,
+            352 => LevelSoundEventType::JumpToBlock,
+            353 => LevelSoundEventType::PreRam,
+            354 => LevelSoundEventType::PreRamScreamer,
+            355 => LevelSoundEventType::RamImpact,
+            356 => LevelSoundEventType::RamImpactScreamer,
+            357 => LevelSoundEventType::SquidInkSquirt,
+            358 => LevelSoundEventType::GlowSquidInkSquirt,
+            359 => LevelSoundEventType::ConvertToStray,
+            360 => LevelSoundEventType::CakeAddCandle,
+            361 => LevelSoundEventType::ExtinguishCandle,
+            362 => LevelSoundEventType::AmbientCandle,
+            363 => LevelSoundEventType::BlockClick,
+            364 => LevelSoundEventType::BlockClickFail,
+            365 => LevelSoundEventType::SculkCatalystBloom,
+            366 => LevelSoundEventType::SculkShriekerShriek,
+            367 => LevelSoundEventType::WardenNearbyClose,
+            368 => LevelSoundEventType::WardenNearbyCloser,
+            369 => LevelSoundEventType::WardenNearbyClosest,
+            370 => LevelSoundEventType::WardenSlightlyAngry,
+            371 => LevelSoundEventType::RecordOtherside,
+            372 => LevelSoundEventType::Tongue,
+            373 => LevelSoundEventType::CrackIronGolem,
+            374 => LevelSoundEventType::RepairIronGolem,
+            375 => LevelSoundEventType::Listening,
+            376 => LevelSoundEventType::Heartbeat,
+            377 => LevelSoundEventType::HornBreak,
+            378 => LevelSoundEventType::SculkPlace,
+            379 => LevelSoundEventType::SculkSpread,
+            380 => LevelSoundEventType::SculkCharge,
+            381 => LevelSoundEventType::SculkSensorPlace,
+            382 => LevelSoundEventType::SculkShriekerPlace,
+            383 => LevelSoundEventType::GoatCall0,
+            384 => LevelSoundEventType::GoatCall1,
+            385 => LevelSoundEventType::GoatCall2,
+            386 => LevelSoundEventType::GoatCall3,
+            387 => LevelSoundEventType::GoatCall4,
+            388 => LevelSoundEventType::GoatCall5,
+            389 => LevelSoundEventType::GoatCall6,
+            390 => LevelSoundEventType::GoatCall7,
+            391 => LevelSoundEventType::GoatCall8,
+            392 => LevelSoundEventType::GoatCall9,
+            393 => LevelSoundEventType::GoatHarmony0,
+            394 => LevelSoundEventType::GoatHarmony1,
+            395 => LevelSoundEventType::GoatHarmony2,
+            396 => LevelSoundEventType::GoatHarmony3,
+            397 => LevelSoundEventType::GoatHarmony4,
+            398 => LevelSoundEventType::GoatHarmony5,
+            399 => LevelSoundEventType::GoatHarmony6,
+            400 => LevelSoundEventType::GoatHarmony7,
+            401 => LevelSoundEventType::GoatHarmony8,
+            402 => LevelSoundEventType::GoatHarmony9,
+            403 => LevelSoundEventType::GoatMelody0,
+            404 => LevelSoundEventType::GoatMelody1,
+            405 => LevelSoundEventType::GoatMelody2,
+            406 => LevelSoundEventType::GoatMelody3,
+            407 => LevelSoundEventType::GoatMelody4,
+            408 => LevelSoundEventType::GoatMelody5,
+            409 => LevelSoundEventType::GoatMelody6,
+            410 => LevelSoundEventType::GoatMelody7,
+            411 => LevelSoundEventType::GoatMelody8,
+            412 => LevelSoundEventType::GoatMelody9,
+            413 => LevelSoundEventType::GoatBass0,
+            414 => LevelSoundEventType::GoatBass1,
+            415 => LevelSoundEventType::GoatBass2,
+            416 => LevelSoundEventType::GoatBass3,
+            417 => LevelSoundEventType::GoatBass4,
+            418 => LevelSoundEventType::GoatBass5,
+            419 => LevelSoundEventType::GoatBass6,
+            420 => LevelSoundEventType::GoatBass7,
+            421 => LevelSoundEventType::GoatBass8,
+            422 => LevelSoundEventType::GoatBass9,
+            423 => LevelSoundEventType::ImitateWarden,
+            424 => LevelSoundEventType::ListeningAngry,
+            425 => LevelSoundEventType::ItemGiven,
+            426 => LevelSoundEventType::ItemTaken,
+            427 => LevelSoundEventType::Disappeared,
+            428 => LevelSoundEventType::Reappeared,
+            429 => LevelSoundEventType::DrinkMilk,
+            430 => LevelSoundEventType::FrogspawnHatched,
+            431 => LevelSoundEventType::LaySpawn,
+            432 => LevelSoundEventType::FrogspawnBreak,
+            433 => LevelSoundEventType::SonicBoom,
+            434 => LevelSoundEventType::SonicCharge,
+            435 => LevelSoundEventType::ItemThrown,
+            436 => LevelSoundEventType::Record5,
+            437 => LevelSoundEventType::ConvertToFrog,
+            438 => LevelSoundEventType::RecordPlaying,
+            439 => LevelSoundEventType::EnchantingTableUse,
+            440 => LevelSoundEventType::StepSand,
+            441 => LevelSoundEventType::DashReady,
+            442 => LevelSoundEventType::BundleDropContents,
+            443 => LevelSoundEventType::BundleInsert,
+            444 => LevelSoundEventType::BundleRemoveOne,
+            445 => LevelSoundEventType::PressurePlateClickOff,
+            446 => LevelSoundEventType::PressurePlateClickOn,
+            447 => LevelSoundEventType::ButtonClickOff,
+            448 => LevelSoundEventType::ButtonClickOn,
+            449 => LevelSoundEventType::DoorOpen,
+            450 => LevelSoundEventType::DoorClose,
+            451 => LevelSoundEventType::TrapdoorOpen,
+            452 => LevelSoundEventType::TrapdoorClose,
+            453 => LevelSoundEventType::FenceGateOpen,
+            454 => LevelSoundEventType::FenceGateClose,
+            455 => LevelSoundEventType::Insert,
+            456 => LevelSoundEventType::Pickup,
+            457 => LevelSoundEventType::InsertEnchanted,
+            458 => LevelSoundEventType::PickupEnchanted,
+            459 => LevelSoundEventType::Brush,
+            460 => LevelSoundEventType::BrushCompleted,
+            461 => LevelSoundEventType::ShatterDecoratedPot,
+            462 => LevelSoundEventType::BreakDecoratedPot,
+            463 => LevelSoundEventType::SnifferEggCrack,
+            464 => LevelSoundEventType::SnifferEggHatched,
+            465 => LevelSoundEventType::WaxedSignInteractFail,
+            466 => LevelSoundEventType::RecordRelic,
+            467 => LevelSoundEventType::Bump,
+            468 => LevelSoundEventType::PumpkinCarve,
+            469 => LevelSoundEventType::ConvertHuskToZombie,
+            470 => LevelSoundEventType::PigDeath,
+            471 => LevelSoundEventType::HoglinConvertToZombified,
+            472 => LevelSoundEventType::AmbientUnderwaterEnter,
+            473 => LevelSoundEventType::AmbientUnderwaterExit,
+            474 => LevelSoundEventType::BottleFill,
+            475 => LevelSoundEventType::BottleEmpty,
+            476 => LevelSoundEventType::CrafterCraft,
+            477 => LevelSoundEventType::CrafterFail,
+            478 => LevelSoundEventType::DecoratedPotInsert,
+            479 => LevelSoundEventType::DecoratedPotInsertFail,
+            480 => LevelSoundEventType::CrafterDisableSlot,
+            481 => LevelSoundEventType::TrialSpawnerOpenShutter,
+            482 => LevelSoundEventType::TrialSpawnerEjectItem,
+            483 => LevelSoundEventType::TrialSpawnerDetectPlayer,
+            484 => LevelSoundEventType::TrialSpawnerSpawnMob,
+            485 => LevelSoundEventType::TrialSpawnerCloseShutter,
+            486 => LevelSoundEventType::TrialSpawnerAmbient,
+            487 => LevelSoundEventType::CopperBulbTurnOn,
+            488 => LevelSoundEventType::CopperBulbTurnOff,
+            489 => LevelSoundEventType::AmbientInAir,
+            490 => LevelSoundEventType::BreezeWindChargeBurst,
+            491 => LevelSoundEventType::ImitateBreeze,
+            492 => LevelSoundEventType::ArmadilloBrush,
+            493 => LevelSoundEventType::ArmadilloScuteDrop,
+            494 => LevelSoundEventType::EquipWolf,
+            495 => LevelSoundEventType::UnequipWolf,
+            496 => LevelSoundEventType::Reflect,
+            497 => LevelSoundEventType::VaultOpenShutter,
+            498 => LevelSoundEventType::VaultCloseShutter,
+            499 => LevelSoundEventType::VaultEjectItem,
+            500 => LevelSoundEventType::VaultInsertItem,
+            501 => LevelSoundEventType::VaultInsertItemFail,
+            502 => LevelSoundEventType::VaultAmbient,
+            503 => LevelSoundEventType::VaultActivate,
+            504 => LevelSoundEventType::VaultDeactivate,
+            505 => LevelSoundEventType::HurtReduced,
+            506 => LevelSoundEventType::WindChargeBurst,
+            507 => LevelSoundEventType::ImitateBogged,
+            508 => LevelSoundEventType::WolfArmorCrack,
+            509 => LevelSoundEventType::WolfArmorBreak,
+            510 => LevelSoundEventType::WolfArmorRepair,
+            511 => LevelSoundEventType::MaceSmashAir,
+            512 => LevelSoundEventType::MaceSmashGround,
+            513 => LevelSoundEventType::TrialSpawnerChargeActivate,
+            514 => LevelSoundEventType::TrialSpawnerAmbientOminous,
+            515 => LevelSoundEventType::OminousItemSpawnerSpawnItem,
+            516 => LevelSoundEventType::OminousBottleEndUse,
+            517 => LevelSoundEventType::MaceHeavySmashGround,
+            518 => LevelSoundEventType::OminousItemSpawnerSpawnItemBegin,
+            other => LevelSoundEventType::Unknown(other),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[enum_repr(VAR::<i32>)]
+pub enum MobEffectType {
+    Speed = 1,
+    Slowness = 2,
+    Haste = 3,
+    MiningFatigue = 4,
+    Strength = 5,
+    InstantHealth = 6,
+    InstantDamage = 7,
+    JumpBoost = 8,
+    Nausea = 9,
+    Regeneration = 10,
+    Resistance = 11,
+    FireResistance = 12,
+    WaterBreathing = 13,
+    Invisibility = 14,
+    Blindness = 15,
+    NightVision = 16,
+    Hunger = 17,
+    Weakness = 18,
+    Poison = 19,
+    Wither = 20,
+    HealthBoost = 21,
+    Absorption = 22,
+    Saturation = 23,
+    Levitation = 24,
+    FatalPoison = 25,
+    ConduitPower = 26,
+    SlowFalling = 27,
+    BadOmen = 28,
+    HeroOfTheVillage = 29,
+    Darkness = 30,
+    TrialOmen = 31,
+    WindCharged = 32,
+    Weaving = 33,
+    Oozing = 34,
+    Infested = 35,
+    RaidOmen = 36,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum MobEffectEvent {
+    Add = 1,
+    /// Changes the amplifier or duration of an effect the actor already has.
+    Modify = 2,
+    Remove = 3,
+}
@@ -1,9 +1,23 @@
+pub mod ability_data;
+pub mod actor_attribute;
+pub mod actor_data;
+pub mod actor_flags;
+pub mod actor_link;
 pub mod animate_action;
+pub mod attribute;
 pub mod base_game_version;
 pub mod block_action;
+pub mod block_property;
+pub mod boss_event;
 pub mod chat_restriction_level;
 pub mod chunk_pos;
+pub mod command_data;
+pub mod command_origin;
+pub mod command_output;
 pub mod connection_request;
+pub mod container_name;
+pub mod container_type;
+pub mod dimension_definition;
 pub mod disconnect_reason;
 pub mod edu_shared_uri_resource;
 pub mod experiments;
@@ -13,8 +27,17 @@ pub mod input_mode;
 pub mod interact_action;
 pub mod interaction_model;
 pub mod inventory_transaction;
+pub mod item_descriptor;
+pub mod item_entry;
+pub mod item_stack;
+pub mod item_stack_request;
+pub mod item_stack_response;
+pub mod level_event_type;
 pub mod level_settings;
+pub mod level_sound_event_type;
+pub mod mob_effect;
 pub mod modal_form_cancel_reason;
+pub mod molang_variables;
 pub mod network_block_pos;
 pub mod network_permissions;
 pub mod pack_info_behavior;
@@ -22,10 +45,19 @@ pub mod pack_info_resource;
 pub mod pack_url;
 pub mod play_mode;
 pub mod play_status;
+pub mod player_action_type;
+pub mod player_list_entry;
 pub mod player_movement_mode;
 pub mod player_movement_settings;
+pub mod property_sync_data;
+pub mod recipe;
 pub mod resource_packs_response_status;
 pub mod resource_packs_stack_pack;
+pub mod respawn_state;
+pub mod scoreboard;
+pub mod skin;
+pub mod soft_enum_action;
 pub mod spawn_biome_type;
 pub mod spawn_settings;
 pub mod text_message_data;
+pub mod title_type;
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use bedrockrs_core::Vec3;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MolangValue {
+    Float(f32),
+    /// Named members like `.x`, used for vectors and colors.
+    MemberArray(Vec<(String, MolangValue)>),
+}
+
+impl MolangValue {
+    fn to_json(&self) -> Value {
+        match self {
+            MolangValue::Float(value) => json!({ "type": "float", "value": value }),
+            MolangValue::MemberArray(members) => json!({
+                "type": "member_array",
+                "value": members
+                    .iter()
+                    .map(|(name, value)| json!({ "name": name, "value": value.to_json() }))
+                    .collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    fn from_json(value: &Value) -> Result<Self, ProtoCodecError> {
+        let format_error =
+            || ProtoCodecError::FormatMismatch(format!("Invalid molang value: {value}"));
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("float") => value
+                .get("value")
+                .and_then(Value::as_f64)
+                .map(|value| MolangValue::Float(value as f32))
+                .ok_or_else(format_error),
+            Some("member_array") => Ok(MolangValue::MemberArray(
+                value
+                    .get("value")
+                    .and_then(Value::as_array)
+                    .ok_or_else(format_error)?
+                    .iter()
+                    .map(read_variable)
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => Err(format_error()),
+        }
+    }
+}
+
+/// MoLang variables passed to a particle effect, like `variable.direction`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MolangVariables {
+    pub variables: Vec<(String, MolangValue)>,
+}
+
+impl MolangVariables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: MolangValue) -> Self {
+        self.variables.push((name.into(), value));
+        self
+    }
+
+    pub fn float(self, name: impl Into<String>, value: f32) -> Self {
+        self.with(name, MolangValue::Float(value))
+    }
+
+    /// Adds a vector with the `.x`, `.y` and `.z` members.
+    pub fn vec3(self, name: impl Into<String>, value: Vec3<f32>) -> Self {
+        self.with(
+            name,
+            MolangValue::MemberArray(vec![
+                (String::from(".x"), MolangValue::Float(value.x)),
+                (String::from(".y"), MolangValue::Float(value.y)),
+                (String::from(".z"), MolangValue::Float(value.z)),
+            ]),
+        )
+    }
+
+    /// Adds a color with the `.r`, `.g`, `.b` and `.a` members, ranging from 0 to 1.
+    pub fn color(self, name: impl Into<String>, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.with(
+            name,
+            MolangValue::MemberArray(vec![
+                (String::from(".r"), MolangValue::Float(r)),
+                (String::from(".g"), MolangValue::Float(g)),
+                (String::from(".b"), MolangValue::Float(b)),
+                (String::from(".a"), MolangValue::Float(a)),
+            ]),
+        )
+    }
+
+    pub fn get(&self, name: &str) -> Option<&MolangValue> {
+        self.variables
+            .iter()
+            .find_map(|(variable, value)| (variable == name).then_some(value))
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::Array(
+            self.variables
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value.to_json() }))
+                .collect(),
+        )
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            variables: value
+                .as_array()
+                .ok_or_else(|| {
+                    ProtoCodecError::FormatMismatch(format!(
+                        "Expected an array of molang variables, got {value}"
+                    ))
+                })?
+                .iter()
+                .map(read_variable)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn parse(json: &str) -> Result<Self, ProtoCodecError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| ProtoCodecError::JsonError(Arc::new(e)))?;
+
+        Self::from_json(&value)
+    }
+}
+
+fn read_variable(value: &Value) -> Result<(String, MolangValue), ProtoCodecError> {
+    let name = value.get("name").and_then(Value::as_str).ok_or_else(|| {
+        ProtoCodecError::FormatMismatch(format!("Invalid molang variable: {value}"))
+    })?;
+    let variable = value.get("value").ok_or_else(|| {
+        ProtoCodecError::FormatMismatch(format!("Invalid molang variable: {value}"))
+    })?;
+
+    Ok((String::from(name), MolangValue::from_json(variable)?))
+}
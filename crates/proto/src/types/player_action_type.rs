@@ -0,0 +1,45 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<i32>)]
+pub enum PlayerActionType {
+    StartBreak = 0,
+    AbortBreak = 1,
+    StopBreak = 2,
+    GetUpdatedBlock = 3,
+    DropItem = 4,
+    StartSleeping = 5,
+    StopSleeping = 6,
+    Respawn = 7,
+    Jump = 8,
+    StartSprint = 9,
+    StopSprint = 10,
+    StartSneak = 11,
+    StopSneak = 12,
+    CreativePlayerDestroyBlock = 13,
+    /// Sent once the client finished loading the new dimension.
+    DimensionChangeDone = 14,
+    StartGlide = 15,
+    StopGlide = 16,
+    BuildDenied = 17,
+    CrackBreak = 18,
+    ChangeSkin = 19,
+    SetEnchantmentSeed = 20,
+    StartSwimming = 21,
+    StopSwimming = 22,
+    StartSpinAttack = 23,
+    StopSpinAttack = 24,
+    StartBuildingBlock = 25,
+    PredictDestroyBlock = 26,
+    ContinueDestroyBlock = 27,
+    StartItemUseOn = 28,
+    StopItemUseOn = 29,
+    HandledTeleport = 30,
+    MissedSwing = 31,
+    StartCrawling = 32,
+    StopCrawling = 33,
+    StartFlying = 34,
+    StopFlying = 35,
+    ReceivedServerData = 36,
+}
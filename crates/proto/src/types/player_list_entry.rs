@@ -0,0 +1,79 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+use uuid::Uuid;
+
+use crate::types::skin::Skin;
+
+#[derive(Debug, Clone)]
+pub struct PlayerListEntry {
+    pub uuid: Uuid,
+    pub actor_unique_id: ActorUniqueID,
+    pub username: String,
+    pub xuid: String,
+    pub platform_chat_id: String,
+    pub build_platform: LE<i32>,
+    /// The skin used for the player, [`Skin::trusted`] is sent after all entries.
+    pub skin: Skin,
+    pub is_teacher: bool,
+    pub is_host: bool,
+    pub is_sub_client: bool,
+}
+
+impl PlayerListEntry {
+    pub fn new(
+        uuid: Uuid,
+        actor_unique_id: ActorUniqueID,
+        username: impl Into<String>,
+        xuid: impl Into<String>,
+        skin: Skin,
+    ) -> Self {
+        Self {
+            uuid,
+            actor_unique_id,
+            username: username.into(),
+            xuid: xuid.into(),
+            platform_chat_id: String::new(),
+            build_platform: LE::new(0),
+            skin,
+            is_teacher: false,
+            is_host: false,
+            is_sub_client: false,
+        }
+    }
+}
+
+impl ProtoCodec for PlayerListEntry {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.uuid.proto_serialize(stream)?;
+        self.actor_unique_id.proto_serialize(stream)?;
+        self.username.proto_serialize(stream)?;
+        self.xuid.proto_serialize(stream)?;
+        self.platform_chat_id.proto_serialize(stream)?;
+        self.build_platform.proto_serialize(stream)?;
+        self.skin.proto_serialize(stream)?;
+        self.is_teacher.proto_serialize(stream)?;
+        self.is_host.proto_serialize(stream)?;
+        self.is_sub_client.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            uuid: Uuid::proto_deserialize(stream)?,
+            actor_unique_id: ActorUniqueID::proto_deserialize(stream)?,
+            username: String::proto_deserialize(stream)?,
+            xuid: String::proto_deserialize(stream)?,
+            platform_chat_id: String::proto_deserialize(stream)?,
+            build_platform: LE::<i32>::proto_deserialize(stream)?,
+            skin: Skin::proto_deserialize(stream)?,
+            is_teacher: bool::proto_deserialize(stream)?,
+            is_host: bool::proto_deserialize(stream)?,
+            is_sub_client: bool::proto_deserialize(stream)?,
+        })
+    }
+}
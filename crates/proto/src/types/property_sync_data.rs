@@ -0,0 +1,77 @@
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_derive::ProtoCodec;
+
+/// The values of the properties defined in the entity behavior of an actor,
+/// referenced by the index of the property in its definition.
+#[derive(ProtoCodec, Debug, Clone, Default, PartialEq)]
+pub struct PropertySyncData {
+    /// Int, enum and bool properties, bools are sent as `0` or `1`.
+    #[len_repr(VAR::<u32>)]
+    pub int_properties: Vec<IntProperty>,
+    #[len_repr(VAR::<u32>)]
+    pub float_properties: Vec<FloatProperty>,
+}
+
+#[derive(ProtoCodec, Debug, Clone, PartialEq)]
+pub struct IntProperty {
+    pub index: VAR<u32>,
+    pub value: VAR<i32>,
+}
+
+#[derive(ProtoCodec, Debug, Clone, PartialEq)]
+pub struct FloatProperty {
+    pub index: VAR<u32>,
+    pub value: LE<f32>,
+}
+
+impl PropertySyncData {
+    pub fn int(&self, index: u32) -> Option<i32> {
+        self.int_properties
+            .iter()
+            .find(|v| v.index.into_inner() == index)
+            .map(|v| v.value.into_inner())
+    }
+
+    pub fn set_int(&mut self, index: u32, value: i32) {
+        match self
+            .int_properties
+            .iter_mut()
+            .find(|v| v.index.into_inner() == index)
+        {
+            Some(property) => property.value = VAR::new(value),
+            None => self.int_properties.push(IntProperty {
+                index: VAR::new(index),
+                value: VAR::new(value),
+            }),
+        }
+    }
+
+    pub fn bool(&self, index: u32) -> Option<bool> {
+        self.int(index).map(|v| v != 0)
+    }
+
+    pub fn set_bool(&mut self, index: u32, value: bool) {
+        self.set_int(index, value as i32);
+    }
+
+    pub fn float(&self, index: u32) -> Option<f32> {
+        self.float_properties
+            .iter()
+            .find(|v| v.index.into_inner() == index)
+            .map(|v| v.value.into_inner())
+    }
+
+    pub fn set_float(&mut self, index: u32, value: f32) {
+        match self
+            .float_properties
+            .iter_mut()
+            .find(|v| v.index.into_inner() == index)
+        {
+            Some(property) => property.value = LE::new(value),
+            None => self.float_properties.push(FloatProperty {
+                index: VAR::new(index),
+                value: LE::new(value),
+            }),
+        }
+    }
+}
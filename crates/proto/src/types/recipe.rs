@@ -0,0 +1,491 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::version::protocol_at_least;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use uuid::Uuid;
+
+use crate::info::{PROTOCOL_VERSION_1_20_80, PROTOCOL_VERSION_1_21_0};
+use crate::types::item_descriptor::{ItemDescriptor, ItemDescriptorCount};
+use crate::types::item_stack::ItemStackDescriptor;
+
+/// A recipe sent in the [`CraftingDataPacket`](crate::packets::crafting_data::CraftingDataPacket).
+#[derive(Debug, Clone)]
+pub enum Recipe {
+    Shapeless(ShapelessRecipe),
+    Shaped(ShapedRecipe),
+    Furnace(FurnaceRecipe),
+    FurnaceData(FurnaceDataRecipe),
+    /// A hardcoded recipe like map cloning or firework crafting.
+    Multi(MultiRecipe),
+    /// Keeps the content of the shulker box when dyeing it.
+    ShulkerBox(ShapelessRecipe),
+    ShapelessChemistry(ShapelessRecipe),
+    ShapedChemistry(ShapedRecipe),
+    SmithingTransform(SmithingTransformRecipe),
+    SmithingTrim(SmithingTrimRecipe),
+}
+
+impl Recipe {
+    const SHAPELESS_ID: i32 = 0;
+    const SHAPED_ID: i32 = 1;
+    const FURNACE_ID: i32 = 2;
+    const FURNACE_DATA_ID: i32 = 3;
+    const MULTI_ID: i32 = 4;
+    const SHULKER_BOX_ID: i32 = 5;
+    const SHAPELESS_CHEMISTRY_ID: i32 = 6;
+    const SHAPED_CHEMISTRY_ID: i32 = 7;
+    const SMITHING_TRANSFORM_ID: i32 = 8;
+    const SMITHING_TRIM_ID: i32 = 9;
+
+    /// The network ID crafting requests refer to this recipe with,
+    /// furnace recipes don't have one.
+    pub fn network_id(&self) -> Option<u32> {
+        match self {
+            Recipe::Shapeless(recipe)
+            | Recipe::ShulkerBox(recipe)
+            | Recipe::ShapelessChemistry(recipe) => Some(recipe.network_id),
+            Recipe::Shaped(recipe) | Recipe::ShapedChemistry(recipe) => Some(recipe.network_id),
+            Recipe::Furnace(_) | Recipe::FurnaceData(_) => None,
+            Recipe::Multi(recipe) => Some(recipe.network_id.into_inner()),
+            Recipe::SmithingTransform(recipe) => Some(recipe.network_id),
+            Recipe::SmithingTrim(recipe) => Some(recipe.network_id),
+        }
+    }
+}
+
+impl ProtoCodec for Recipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let id = match self {
+            Recipe::Shapeless(_) => Self::SHAPELESS_ID,
+            Recipe::Shaped(_) => Self::SHAPED_ID,
+            Recipe::Furnace(_) => Self::FURNACE_ID,
+            Recipe::FurnaceData(_) => Self::FURNACE_DATA_ID,
+            Recipe::Multi(_) => Self::MULTI_ID,
+            Recipe::ShulkerBox(_) => Self::SHULKER_BOX_ID,
+            Recipe::ShapelessChemistry(_) => Self::SHAPELESS_CHEMISTRY_ID,
+            Recipe::ShapedChemistry(_) => Self::SHAPED_CHEMISTRY_ID,
+            Recipe::SmithingTransform(_) => Self::SMITHING_TRANSFORM_ID,
+            Recipe::SmithingTrim(_) => Self::SMITHING_TRIM_ID,
+        };
+
+        VAR::<i32>::new(id).proto_serialize(stream)?;
+
+        match self {
+            Recipe::Shapeless(recipe)
+            | Recipe::ShulkerBox(recipe)
+            | Recipe::ShapelessChemistry(recipe) => recipe.proto_serialize(stream),
+            Recipe::Shaped(recipe) | Recipe::ShapedChemistry(recipe) => {
+                recipe.proto_serialize(stream)
+            }
+            Recipe::Furnace(recipe) => recipe.proto_serialize(stream),
+            Recipe::FurnaceData(recipe) => recipe.proto_serialize(stream),
+            Recipe::Multi(recipe) => recipe.proto_serialize(stream),
+            Recipe::SmithingTransform(recipe) => recipe.proto_serialize(stream),
+            Recipe::SmithingTrim(recipe) => recipe.proto_serialize(stream),
+        }
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match VAR::<i32>::proto_deserialize(stream)?.into_inner() {
+            Self::SHAPELESS_ID => Recipe::Shapeless(ShapelessRecipe::proto_deserialize(stream)?),
+            Self::SHAPED_ID => Recipe::Shaped(ShapedRecipe::proto_deserialize(stream)?),
+            Self::FURNACE_ID => Recipe::Furnace(FurnaceRecipe::proto_deserialize(stream)?),
+            Self::FURNACE_DATA_ID => {
+                Recipe::FurnaceData(FurnaceDataRecipe::proto_deserialize(stream)?)
+            }
+            Self::MULTI_ID => Recipe::Multi(MultiRecipe::proto_deserialize(stream)?),
+            Self::SHULKER_BOX_ID => Recipe::ShulkerBox(ShapelessRecipe::proto_deserialize(stream)?),
+            Self::SHAPELESS_CHEMISTRY_ID => {
+                Recipe::ShapelessChemistry(ShapelessRecipe::proto_deserialize(stream)?)
+            }
+            Self::SHAPED_CHEMISTRY_ID => {
+                Recipe::ShapedChemistry(ShapedRecipe::proto_deserialize(stream)?)
+            }
+            Self::SMITHING_TRANSFORM_ID => {
+                Recipe::SmithingTransform(SmithingTransformRecipe::proto_deserialize(stream)?)
+            }
+            Self::SMITHING_TRIM_ID => {
+                Recipe::SmithingTrim(SmithingTrimRecipe::proto_deserialize(stream)?)
+            }
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("Recipe"),
+                ))
+            }
+        })
+    }
+}
+
+/// The condition under which a recipe gets unlocked in the recipe book.
+#[derive(Debug, Clone)]
+pub enum RecipeUnlockRequirement {
+    /// Unlocked once the player had one of the ingredients.
+    Ingredients(Vec<ItemDescriptor>),
+    AlwaysUnlocked,
+    PlayerInWater,
+    PlayerHasManyItems,
+}
+
+impl ProtoCodec for RecipeUnlockRequirement {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            RecipeUnlockRequirement::Ingredients(ingredients) => {
+                0u8.proto_serialize(stream)?;
+                VAR::<u32>::new(ingredients.len().try_into()?).proto_serialize(stream)?;
+                for ingredient in ingredients {
+                    ingredient.proto_serialize(stream)?;
+                }
+            }
+            RecipeUnlockRequirement::AlwaysUnlocked => 1u8.proto_serialize(stream)?,
+            RecipeUnlockRequirement::PlayerInWater => 2u8.proto_serialize(stream)?,
+            RecipeUnlockRequirement::PlayerHasManyItems => 3u8.proto_serialize(stream)?,
+        }
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            0 => {
+                let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+                let mut ingredients = vec![];
+                for _ in 0..len {
+                    ingredients.push(ItemDescriptor::proto_deserialize(stream)?);
+                }
+                RecipeUnlockRequirement::Ingredients(ingredients)
+            }
+            1 => RecipeUnlockRequirement::AlwaysUnlocked,
+            2 => RecipeUnlockRequirement::PlayerInWater,
+            3 => RecipeUnlockRequirement::PlayerHasManyItems,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("RecipeUnlockRequirement"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShapelessRecipe {
+    pub recipe_id: String,
+    pub input: Vec<ItemDescriptorCount>,
+    pub output: Vec<ItemStackDescriptor>,
+    pub uuid: Uuid,
+    /// The block this recipe can be used in, e.g. "crafting_table".
+    pub block: String,
+    pub priority: i32,
+    pub unlock_requirement: RecipeUnlockRequirement,
+    pub network_id: u32,
+}
+
+impl ProtoCodec for ShapelessRecipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.recipe_id.proto_serialize(stream)?;
+        VAR::<u32>::new(self.input.len().try_into()?).proto_serialize(stream)?;
+        for input in &self.input {
+            input.proto_serialize(stream)?;
+        }
+        VAR::<u32>::new(self.output.len().try_into()?).proto_serialize(stream)?;
+        for output in &self.output {
+            output.proto_serialize(stream)?;
+        }
+        self.uuid.proto_serialize(stream)?;
+        self.block.proto_serialize(stream)?;
+        VAR::<i32>::new(self.priority).proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.unlock_requirement.proto_serialize(stream)?;
+        }
+        VAR::<u32>::new(self.network_id).proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let recipe_id = String::proto_deserialize(stream)?;
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut input = vec![];
+        for _ in 0..len {
+            input.push(ItemDescriptorCount::proto_deserialize(stream)?);
+        }
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut output = vec![];
+        for _ in 0..len {
+            output.push(ItemStackDescriptor::proto_deserialize(stream)?);
+        }
+
+        let uuid = Uuid::proto_deserialize(stream)?;
+        let block = String::proto_deserialize(stream)?;
+        let priority = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        let unlock_requirement = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => RecipeUnlockRequirement::proto_deserialize(stream)?,
+            false => RecipeUnlockRequirement::AlwaysUnlocked,
+        };
+        let network_id = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        Ok(Self {
+            recipe_id,
+            input,
+            output,
+            uuid,
+            block,
+            priority,
+            unlock_requirement,
+            network_id,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShapedRecipe {
+    pub recipe_id: String,
+    pub width: i32,
+    pub height: i32,
+    /// The crafting grid row by row, contains `width * height` entries.
+    pub input: Vec<ItemDescriptorCount>,
+    pub output: Vec<ItemStackDescriptor>,
+    pub uuid: Uuid,
+    /// The block this recipe can be used in, e.g. "crafting_table".
+    pub block: String,
+    pub priority: i32,
+    /// If the recipe also matches when its pattern is mirrored.
+    pub assume_symmetry: bool,
+    pub unlock_requirement: RecipeUnlockRequirement,
+    pub network_id: u32,
+}
+
+impl ProtoCodec for ShapedRecipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        let input_count = self
+            .width
+            .max(0)
+            .checked_mul(self.height.max(0))
+            .ok_or_else(|| {
+                ProtoCodecError::FormatMismatch(format!(
+                    "Shaped recipe {} is too large: {}x{}",
+                    self.recipe_id, self.width, self.height
+                ))
+            })?;
+
+        if self.input.len() != input_count as usize {
+            return Err(ProtoCodecError::FormatMismatch(format!(
+                "Shaped recipe {} has {} inputs, expected {}x{}",
+                self.recipe_id,
+                self.input.len(),
+                self.width,
+                self.height
+            )));
+        }
+
+        self.recipe_id.proto_serialize(stream)?;
+        VAR::<i32>::new(self.width).proto_serialize(stream)?;
+        VAR::<i32>::new(self.height).proto_serialize(stream)?;
+        // The input isn't length prefixed, its length is given by width and height
+        for input in &self.input {
+            input.proto_serialize(stream)?;
+        }
+        VAR::<u32>::new(self.output.len().try_into()?).proto_serialize(stream)?;
+        for output in &self.output {
+            output.proto_serialize(stream)?;
+        }
+        self.uuid.proto_serialize(stream)?;
+        self.block.proto_serialize(stream)?;
+        VAR::<i32>::new(self.priority).proto_serialize(stream)?;
+        if protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            self.assume_symmetry.proto_serialize(stream)?;
+        }
+        if protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            self.unlock_requirement.proto_serialize(stream)?;
+        }
+        VAR::<u32>::new(self.network_id).proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let recipe_id = String::proto_deserialize(stream)?;
+        let width = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        let height = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+
+        let input_count = width.max(0).checked_mul(height.max(0)).ok_or_else(|| {
+            ProtoCodecError::FormatMismatch(format!(
+                "Shaped recipe {recipe_id} is too large: {width}x{height}"
+            ))
+        })?;
+
+        let mut input = vec![];
+        for _ in 0..input_count {
+            input.push(ItemDescriptorCount::proto_deserialize(stream)?);
+        }
+
+        let len = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut output = vec![];
+        for _ in 0..len {
+            output.push(ItemStackDescriptor::proto_deserialize(stream)?);
+        }
+
+        let uuid = Uuid::proto_deserialize(stream)?;
+        let block = String::proto_deserialize(stream)?;
+        let priority = VAR::<i32>::proto_deserialize(stream)?.into_inner();
+        let assume_symmetry = match protocol_at_least(PROTOCOL_VERSION_1_20_80) {
+            true => bool::proto_deserialize(stream)?,
+            false => true,
+        };
+        let unlock_requirement = match protocol_at_least(PROTOCOL_VERSION_1_21_0) {
+            true => RecipeUnlockRequirement::proto_deserialize(stream)?,
+            false => RecipeUnlockRequirement::AlwaysUnlocked,
+        };
+        let network_id = VAR::<u32>::proto_deserialize(stream)?.into_inner();
+
+        Ok(Self {
+            recipe_id,
+            width,
+            height,
+            input,
+            output,
+            uuid,
+            block,
+            priority,
+            assume_symmetry,
+            unlock_requirement,
+            network_id,
+        })
+    }
+}
+
+/// Smelting an item regardless of its metadata.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct FurnaceRecipe {
+    pub input_network_id: VAR<i32>,
+    pub output: ItemStackDescriptor,
+    /// The block this recipe can be used in, e.g. "furnace" or "smoker".
+    pub block: String,
+}
+
+/// Smelting an item with a specific metadata.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct FurnaceDataRecipe {
+    pub input_network_id: VAR<i32>,
+    pub input_metadata: VAR<i32>,
+    pub output: ItemStackDescriptor,
+    /// The block this recipe can be used in, e.g. "furnace" or "smoker".
+    pub block: String,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MultiRecipe {
+    pub uuid: Uuid,
+    pub network_id: VAR<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SmithingTransformRecipe {
+    pub recipe_id: String,
+    pub template: ItemDescriptorCount,
+    pub base: ItemDescriptorCount,
+    pub addition: ItemDescriptorCount,
+    pub result: ItemStackDescriptor,
+    pub block: String,
+    pub network_id: u32,
+}
+
+impl ProtoCodec for SmithingTransformRecipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.recipe_id.proto_serialize(stream)?;
+        self.template.proto_serialize(stream)?;
+        self.base.proto_serialize(stream)?;
+        self.addition.proto_serialize(stream)?;
+        self.result.proto_serialize(stream)?;
+        self.block.proto_serialize(stream)?;
+        VAR::<u32>::new(self.network_id).proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            recipe_id: String::proto_deserialize(stream)?,
+            template: ItemDescriptorCount::proto_deserialize(stream)?,
+            base: ItemDescriptorCount::proto_deserialize(stream)?,
+            addition: ItemDescriptorCount::proto_deserialize(stream)?,
+            result: ItemStackDescriptor::proto_deserialize(stream)?,
+            block: String::proto_deserialize(stream)?,
+            network_id: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SmithingTrimRecipe {
+    pub recipe_id: String,
+    pub template: ItemDescriptorCount,
+    pub base: ItemDescriptorCount,
+    pub addition: ItemDescriptorCount,
+    pub block: String,
+    pub network_id: u32,
+}
+
+impl ProtoCodec for SmithingTrimRecipe {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.recipe_id.proto_serialize(stream)?;
+        self.template.proto_serialize(stream)?;
+        self.base.proto_serialize(stream)?;
+        self.addition.proto_serialize(stream)?;
+        self.block.proto_serialize(stream)?;
+        VAR::<u32>::new(self.network_id).proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            recipe_id: String::proto_deserialize(stream)?,
+            template: ItemDescriptorCount::proto_deserialize(stream)?,
+            base: ItemDescriptorCount::proto_deserialize(stream)?,
+            addition: ItemDescriptorCount::proto_deserialize(stream)?,
+            block: String::proto_deserialize(stream)?,
+            network_id: VAR::<u32>::proto_deserialize(stream)?.into_inner(),
+        })
+    }
+}
+
+/// Brewing a potion into another potion.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct PotionRecipe {
+    pub input_potion_id: VAR<i32>,
+    pub input_potion_metadata: VAR<i32>,
+    pub reagent_item_id: VAR<i32>,
+    pub reagent_item_metadata: VAR<i32>,
+    pub output_potion_id: VAR<i32>,
+    pub output_potion_metadata: VAR<i32>,
+}
+
+/// Brewing a potion into a different container, e.g. a splash potion.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct PotionContainerChangeRecipe {
+    pub input_item_id: VAR<i32>,
+    pub reagent_item_id: VAR<i32>,
+    pub output_item_id: VAR<i32>,
+}
+
+/// The outputs of the material reducer for an item.
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MaterialReducerRecipe {
+    /// The network ID of the input shifted 16 bits to the left, combined with its metadata.
+    pub input: VAR<i32>,
+    #[len_repr(VAR::<u32>)]
+    pub outputs: Vec<MaterialReducerOutput>,
+}
+
+#[derive(ProtoCodec, Debug, Clone)]
+pub struct MaterialReducerOutput {
+    pub network_id: VAR<i32>,
+    pub count: VAR<i32>,
+}
@@ -0,0 +1,13 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum RespawnState {
+    /// Sent by the server while the respawn position is searched, the respawn button is disabled.
+    SearchingForSpawn = 0,
+    /// Sent by the server with the final respawn position.
+    ReadyToSpawn = 1,
+    /// Sent by the client when the respawn button was pressed.
+    ClientReadyToSpawn = 2,
+}
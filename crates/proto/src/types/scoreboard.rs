@@ -0,0 +1,170 @@
+use std::io::Cursor;
+
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use bedrockrs_shared::actor_unique_id::ActorUniqueID;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ScoreboardDisplaySlot {
+    /// The player list opened via the pause menu.
+    List,
+    Sidebar,
+    BelowName,
+}
+
+impl ScoreboardDisplaySlot {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreboardDisplaySlot::List => "list",
+            ScoreboardDisplaySlot::Sidebar => "sidebar",
+            ScoreboardDisplaySlot::BelowName => "belowname",
+        }
+    }
+}
+
+impl ProtoCodec for ScoreboardDisplaySlot {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.as_str().to_string().proto_serialize(stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match String::proto_deserialize(stream)?.as_str() {
+            "list" => ScoreboardDisplaySlot::List,
+            "sidebar" => ScoreboardDisplaySlot::Sidebar,
+            "belowname" => ScoreboardDisplaySlot::BelowName,
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ScoreboardDisplaySlot"),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<i32>)]
+pub enum ObjectiveSortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+/// Who a score belongs to.
+#[derive(Debug, Clone)]
+pub enum ScoreboardIdentity {
+    Player(ActorUniqueID),
+    Entity(ActorUniqueID),
+    /// A name without an actor, used for custom sidebar lines.
+    FakePlayer(String),
+}
+
+impl ScoreboardIdentity {
+    const PLAYER_ID: u8 = 1;
+    const ENTITY_ID: u8 = 2;
+    const FAKE_PLAYER_ID: u8 = 3;
+}
+
+impl ProtoCodec for ScoreboardIdentity {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        match self {
+            ScoreboardIdentity::Player(id) => {
+                Self::PLAYER_ID.proto_serialize(stream)?;
+                id.proto_serialize(stream)
+            }
+            ScoreboardIdentity::Entity(id) => {
+                Self::ENTITY_ID.proto_serialize(stream)?;
+                id.proto_serialize(stream)
+            }
+            ScoreboardIdentity::FakePlayer(name) => {
+                Self::FAKE_PLAYER_ID.proto_serialize(stream)?;
+                name.proto_serialize(stream)
+            }
+        }
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(match u8::proto_deserialize(stream)? {
+            Self::PLAYER_ID => {
+                ScoreboardIdentity::Player(ActorUniqueID::proto_deserialize(stream)?)
+            }
+            Self::ENTITY_ID => {
+                ScoreboardIdentity::Entity(ActorUniqueID::proto_deserialize(stream)?)
+            }
+            Self::FAKE_PLAYER_ID => {
+                ScoreboardIdentity::FakePlayer(String::proto_deserialize(stream)?)
+            }
+            other => {
+                return Err(ProtoCodecError::InvalidEnumID(
+                    format!("{other:?}"),
+                    String::from("ScoreboardIdentity"),
+                ))
+            }
+        })
+    }
+}
+
+/// A score of an objective.
+#[derive(Debug, Clone)]
+pub struct ScoreEntry {
+    /// Identifies the holder of the score, shared across objectives.
+    pub scoreboard_id: i64,
+    pub objective_name: String,
+    pub score: i32,
+    /// Only sent when changing scores.
+    pub identity: Option<ScoreboardIdentity>,
+}
+
+impl ScoreEntry {
+    pub(crate) fn serialize(
+        &self,
+        stream: &mut Vec<u8>,
+        change: bool,
+    ) -> Result<(), ProtoCodecError> {
+        VAR::new(self.scoreboard_id).proto_serialize(stream)?;
+        self.objective_name.proto_serialize(stream)?;
+        LE::new(self.score).proto_serialize(stream)?;
+
+        if change {
+            match &self.identity {
+                Some(identity) => identity.proto_serialize(stream)?,
+                None => {
+                    return Err(ProtoCodecError::FormatMismatch(String::from(
+                        "Changed score entries need an identity",
+                    )))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn deserialize(
+        stream: &mut Cursor<&[u8]>,
+        change: bool,
+    ) -> Result<Self, ProtoCodecError> {
+        let scoreboard_id = VAR::<i64>::proto_deserialize(stream)?.into_inner();
+        let objective_name = String::proto_deserialize(stream)?;
+        let score = LE::<i32>::proto_deserialize(stream)?.into_inner();
+        let identity = match change {
+            true => Some(ScoreboardIdentity::proto_deserialize(stream)?),
+            false => None,
+        };
+
+        Ok(Self {
+            scoreboard_id,
+            objective_name,
+            score,
+            identity,
+        })
+    }
+}
+
+/// Links a scoreboard ID to a player.
+#[derive(Debug, Clone)]
+pub struct ScoreboardIdentityEntry {
+    pub scoreboard_id: i64,
+    /// Only sent when registering identities.
+    pub player_unique_id: Option<ActorUniqueID>,
+}
@@ -0,0 +1,548 @@
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bedrockrs_core::int::{LE, VAR};
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use bedrockrs_proto_derive::ProtoCodec;
+use image::{ImageFormat, RgbaImage};
+use serde_json::Value;
+
+use crate::error::SkinError;
+
+/// A raw RGBA image with 4 bytes per pixel.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkinImage {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl SkinImage {
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Result<Self, SkinError> {
+        let image = Self {
+            width,
+            height,
+            data,
+        };
+        image.validate()?;
+
+        Ok(image)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), SkinError> {
+        if self.width as usize * self.height as usize * 4 != self.data.len() {
+            return Err(SkinError::InvalidImageSize {
+                width: self.width,
+                height: self.height,
+                len: self.data.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn to_rgba_image(&self) -> Result<RgbaImage, SkinError> {
+        self.validate()?;
+
+        RgbaImage::from_raw(self.width, self.height, self.data.clone()).ok_or(
+            SkinError::InvalidImageSize {
+                width: self.width,
+                height: self.height,
+                len: self.data.len(),
+            },
+        )
+    }
+
+    /// Encodes the image as PNG.
+    pub fn to_png(&self) -> Result<Vec<u8>, SkinError> {
+        let mut png = Cursor::new(vec![]);
+
+        self.to_rgba_image()?
+            .write_to(&mut png, ImageFormat::Png)
+            .map_err(Arc::new)?;
+
+        Ok(png.into_inner())
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), SkinError> {
+        self.to_rgba_image()?
+            .save_with_format(path, ImageFormat::Png)
+            .map_err(Arc::new)?;
+
+        Ok(())
+    }
+}
+
+impl ProtoCodec for SkinImage {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        LE::<u32>::new(self.width).proto_serialize(stream)?;
+        LE::<u32>::new(self.height).proto_serialize(stream)?;
+        write_bytes(&self.data, stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(Self {
+            width: LE::<u32>::proto_deserialize(stream)?.into_inner(),
+            height: LE::<u32>::proto_deserialize(stream)?.into_inner(),
+            data: read_bytes(stream)?,
+        })
+    }
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u32>)]
+pub enum SkinAnimationType {
+    Head = 1,
+    Body32x32 = 2,
+    Body128x128 = 3,
+}
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u32>)]
+pub enum SkinExpressionType {
+    Linear = 0,
+    Blinking = 1,
+}
+
+/// An animated overlay, the frames are stacked vertically in the image.
+#[derive(ProtoCodec, Debug, Clone, PartialEq)]
+pub struct SkinAnimation {
+    pub image: SkinImage,
+    pub animation_type: SkinAnimationType,
+    pub frame_count: LE<f32>,
+    pub expression_type: SkinExpressionType,
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum ArmSize {
+    #[default]
+    Wide,
+    Slim,
+}
+
+impl ArmSize {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArmSize::Wide => "wide",
+            ArmSize::Slim => "slim",
+        }
+    }
+
+    /// Everything but `slim` is treated as wide, like the client does.
+    pub fn from_name(arm_size: &str) -> Self {
+        match arm_size {
+            "slim" => ArmSize::Slim,
+            _ => ArmSize::Wide,
+        }
+    }
+}
+
+impl ProtoCodec for ArmSize {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.as_str().to_string().proto_serialize(stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        Ok(ArmSize::from_name(&String::proto_deserialize(stream)?))
+    }
+}
+
+/// A piece of a skin created in the character creator.
+#[derive(ProtoCodec, Debug, Clone, PartialEq)]
+pub struct PersonaPiece {
+    pub piece_id: String,
+    pub piece_type: String,
+    pub pack_id: String,
+    pub is_default: bool,
+    pub product_id: String,
+}
+
+#[derive(ProtoCodec, Debug, Clone, PartialEq)]
+pub struct PersonaPieceTintColor {
+    pub piece_type: String,
+    /// Colors as `#AARRGGBB` hex strings.
+    #[len_repr(LE::<u32>)]
+    pub colors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Skin {
+    pub skin_id: String,
+    pub play_fab_id: String,
+    /// Json selecting the geometry and animations, like `{"geometry":{"default":"geometry.humanoid.custom"}}`.
+    pub resource_patch: String,
+    pub skin_image: SkinImage,
+    pub animations: Vec<SkinAnimation>,
+    pub cape_image: SkinImage,
+    /// The custom geometry json.
+    pub geometry_data: String,
+    pub geometry_data_engine_version: String,
+    pub animation_data: String,
+    pub cape_id: String,
+    pub full_id: String,
+    pub arm_size: ArmSize,
+    /// The skin color as a `#RRGGBB` hex string.
+    pub skin_color: String,
+    pub persona_pieces: Vec<PersonaPiece>,
+    pub piece_tint_colors: Vec<PersonaPieceTintColor>,
+    pub premium: bool,
+    pub persona: bool,
+    pub cape_on_classic_skin: bool,
+    pub primary_user: bool,
+    pub override_appearance: bool,
+    /// If the skin was bought from the marketplace, untrusted skins are only shown
+    /// to players allowing custom skins. Sent separately from the other fields.
+    pub trusted: bool,
+}
+
+impl Skin {
+    /// Reads the skin from the client data of a [`ConnectionRequest`](crate::types::connection_request::ConnectionRequest).
+    pub fn from_client_data(data: &BTreeMap<String, Value>) -> Result<Self, SkinError> {
+        let skin_id = client_string(data, "SkinId")?.ok_or(missing("SkinId"))?;
+        let cape_id = client_string(data, "CapeId")?.unwrap_or_default();
+
+        let skin_image = SkinImage::new(
+            client_u32(data, "SkinImageWidth")?.ok_or(missing("SkinImageWidth"))?,
+            client_u32(data, "SkinImageHeight")?.ok_or(missing("SkinImageHeight"))?,
+            client_base64(data, "SkinData")?.ok_or(missing("SkinData"))?,
+        )?;
+
+        let cape_image = match client_base64(data, "CapeData")? {
+            Some(cape_data) if !cape_data.is_empty() => SkinImage::new(
+                client_u32(data, "CapeImageWidth")?.unwrap_or_default(),
+                client_u32(data, "CapeImageHeight")?.unwrap_or_default(),
+                cape_data,
+            )?,
+            _ => SkinImage::default(),
+        };
+
+        let animations = client_array(data, "AnimatedImageData")?
+            .iter()
+            .map(|animation| {
+                let animation = client_object(animation, "AnimatedImageData")?;
+
+                Ok(SkinAnimation {
+                    image: SkinImage::new(
+                        client_u32(animation, "ImageWidth")?.unwrap_or_default(),
+                        client_u32(animation, "ImageHeight")?.unwrap_or_default(),
+                        client_base64(animation, "Image")?.unwrap_or_default(),
+                    )?,
+                    animation_type: match client_u32(animation, "Type")? {
+                        Some(1) => SkinAnimationType::Head,
+                        Some(2) => SkinAnimationType::Body32x32,
+                        Some(3) => SkinAnimationType::Body128x128,
+                        other => return Err(invalid("Type", other)),
+                    },
+                    frame_count: LE::new(client_f32(animation, "Frames")?.unwrap_or_default()),
+                    expression_type: match client_u32(animation, "AnimationExpression")? {
+                        None | Some(0) => SkinExpressionType::Linear,
+                        Some(1) => SkinExpressionType::Blinking,
+                        other => return Err(invalid("AnimationExpression", other)),
+                    },
+                })
+            })
+            .collect::<Result<_, SkinError>>()?;
+
+        let persona_pieces = client_array(data, "PersonaPieces")?
+            .iter()
+            .map(|piece| {
+                let piece = client_object(piece, "PersonaPieces")?;
+
+                Ok(PersonaPiece {
+                    piece_id: client_string(piece, "PieceId")?.unwrap_or_default(),
+                    piece_type: client_string(piece, "PieceType")?.unwrap_or_default(),
+                    pack_id: client_string(piece, "PackId")?.unwrap_or_default(),
+                    is_default: client_bool(piece, "IsDefault")?.unwrap_or_default(),
+                    product_id: client_string(piece, "ProductId")?.unwrap_or_default(),
+                })
+            })
+            .collect::<Result<_, SkinError>>()?;
+
+        let piece_tint_colors = client_array(data, "PieceTintColors")?
+            .iter()
+            .map(|tint| {
+                let tint = client_object(tint, "PieceTintColors")?;
+
+                Ok(PersonaPieceTintColor {
+                    piece_type: client_string(tint, "PieceType")?.unwrap_or_default(),
+                    colors: client_array(tint, "Colors")?
+                        .iter()
+                        .map(|color| {
+                            color
+                                .as_str()
+                                .map(String::from)
+                                .ok_or_else(|| invalid("Colors", color))
+                        })
+                        .collect::<Result<_, SkinError>>()?,
+                })
+            })
+            .collect::<Result<_, SkinError>>()?;
+
+        Ok(Self {
+            full_id: format!("{skin_id}{cape_id}"),
+            skin_id,
+            play_fab_id: client_string(data, "PlayFabId")?.unwrap_or_default(),
+            resource_patch: client_base64_string(data, "SkinResourcePatch")?,
+            skin_image,
+            animations,
+            cape_image,
+            geometry_data: client_base64_string(data, "SkinGeometryData")?,
+            geometry_data_engine_version: client_base64_string(
+                data,
+                "SkinGeometryDataEngineVersion",
+            )?,
+            animation_data: client_base64_string(data, "SkinAnimationData")?,
+            cape_id,
+            arm_size: ArmSize::from_name(&client_string(data, "ArmSize")?.unwrap_or_default()),
+            skin_color: client_string(data, "SkinColor")?.unwrap_or_default(),
+            persona_pieces,
+            piece_tint_colors,
+            premium: client_bool(data, "PremiumSkin")?.unwrap_or_default(),
+            persona: client_bool(data, "PersonaSkin")?.unwrap_or_default(),
+            cape_on_classic_skin: client_bool(data, "CapeOnClassicSkin")?.unwrap_or_default(),
+            primary_user: true,
+            override_appearance: client_bool(data, "OverrideSkin")?.unwrap_or(true),
+            trusted: client_bool(data, "TrustedSkin")?.unwrap_or_default(),
+        })
+    }
+
+    /// Parses the custom geometry json.
+    pub fn geometry_json(&self) -> Result<Value, SkinError> {
+        serde_json::from_str(&self.geometry_data).map_err(|e| SkinError::JsonError(Arc::new(e)))
+    }
+
+    /// Parses the resource patch json.
+    pub fn resource_patch_json(&self) -> Result<Value, SkinError> {
+        serde_json::from_str(&self.resource_patch).map_err(|e| SkinError::JsonError(Arc::new(e)))
+    }
+}
+
+impl ProtoCodec for Skin {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.skin_id.proto_serialize(stream)?;
+        self.play_fab_id.proto_serialize(stream)?;
+        self.resource_patch.proto_serialize(stream)?;
+        self.skin_image.proto_serialize(stream)?;
+
+        LE::<u32>::new(self.animations.len().try_into()?).proto_serialize(stream)?;
+        for animation in &self.animations {
+            animation.proto_serialize(stream)?;
+        }
+
+        self.cape_image.proto_serialize(stream)?;
+        self.geometry_data.proto_serialize(stream)?;
+        self.geometry_data_engine_version.proto_serialize(stream)?;
+        self.animation_data.proto_serialize(stream)?;
+        self.cape_id.proto_serialize(stream)?;
+        self.full_id.proto_serialize(stream)?;
+        self.arm_size.proto_serialize(stream)?;
+        self.skin_color.proto_serialize(stream)?;
+
+        LE::<u32>::new(self.persona_pieces.len().try_into()?).proto_serialize(stream)?;
+        for piece in &self.persona_pieces {
+            piece.proto_serialize(stream)?;
+        }
+
+        LE::<u32>::new(self.piece_tint_colors.len().try_into()?).proto_serialize(stream)?;
+        for tint in &self.piece_tint_colors {
+            tint.proto_serialize(stream)?;
+        }
+
+        self.premium.proto_serialize(stream)?;
+        self.persona.proto_serialize(stream)?;
+        self.cape_on_classic_skin.proto_serialize(stream)?;
+        self.primary_user.proto_serialize(stream)?;
+        self.override_appearance.proto_serialize(stream)?;
+
+        Ok(())
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        let skin_id = String::proto_deserialize(stream)?;
+        let play_fab_id = String::proto_deserialize(stream)?;
+        let resource_patch = String::proto_deserialize(stream)?;
+        let skin_image = SkinImage::proto_deserialize(stream)?;
+
+        let len = LE::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut animations = Vec::with_capacity(len.min(1024) as usize);
+        for _ in 0..len {
+            animations.push(SkinAnimation::proto_deserialize(stream)?);
+        }
+
+        let cape_image = SkinImage::proto_deserialize(stream)?;
+        let geometry_data = String::proto_deserialize(stream)?;
+        let geometry_data_engine_version = String::proto_deserialize(stream)?;
+        let animation_data = String::proto_deserialize(stream)?;
+        let cape_id = String::proto_deserialize(stream)?;
+        let full_id = String::proto_deserialize(stream)?;
+        let arm_size = ArmSize::proto_deserialize(stream)?;
+        let skin_color = String::proto_deserialize(stream)?;
+
+        let len = LE::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut persona_pieces = Vec::with_capacity(len.min(1024) as usize);
+        for _ in 0..len {
+            persona_pieces.push(PersonaPiece::proto_deserialize(stream)?);
+        }
+
+        let len = LE::<u32>::proto_deserialize(stream)?.into_inner();
+        let mut piece_tint_colors = Vec::with_capacity(len.min(1024) as usize);
+        for _ in 0..len {
+            piece_tint_colors.push(PersonaPieceTintColor::proto_deserialize(stream)?);
+        }
+
+        Ok(Self {
+            skin_id,
+            play_fab_id,
+            resource_patch,
+            skin_image,
+            animations,
+            cape_image,
+            geometry_data,
+            geometry_data_engine_version,
+            animation_data,
+            cape_id,
+            full_id,
+            arm_size,
+            skin_color,
+            persona_pieces,
+            piece_tint_colors,
+            premium: bool::proto_deserialize(stream)?,
+            persona: bool::proto_deserialize(stream)?,
+            cape_on_classic_skin: bool::proto_deserialize(stream)?,
+            primary_user: bool::proto_deserialize(stream)?,
+            override_appearance: bool::proto_deserialize(stream)?,
+            trusted: false,
+        })
+    }
+}
+
+fn write_bytes(bytes: &[u8], stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+    VAR::<u32>::new(bytes.len().try_into()?).proto_serialize(stream)?;
+    stream.extend_from_slice(bytes);
+
+    Ok(())
+}
+
+fn read_bytes(stream: &mut Cursor<&[u8]>) -> Result<Vec<u8>, ProtoCodecError> {
+    let len: usize = VAR::<u32>::proto_deserialize(stream)?
+        .into_inner()
+        .try_into()?;
+
+    let remaining = stream
+        .get_ref()
+        .len()
+        .saturating_sub(stream.position() as usize);
+    if len > remaining {
+        return Err(ProtoCodecError::FormatMismatch(format!(
+            "Byte array of length {len} is longer than the {remaining} remaining bytes"
+        )));
+    }
+
+    let mut bytes = vec![0; len];
+
+    stream
+        .read_exact(&mut bytes)
+        .map_err(|e| ProtoCodecError::IOError(Arc::new(e)))?;
+
+    Ok(bytes)
+}
+
+fn missing(field: &str) -> SkinError {
+    SkinError::MissingField(String::from(field))
+}
+
+fn invalid(field: &str, value: impl std::fmt::Debug) -> SkinError {
+    SkinError::InvalidField(String::from(field), format!("{value:?}"))
+}
+
+fn client_object<'a>(
+    value: &'a Value,
+    field: &str,
+) -> Result<&'a serde_json::Map<String, Value>, SkinError> {
+    value.as_object().ok_or_else(|| invalid(field, value))
+}
+
+fn client_string(data: &impl ClientData, field: &str) -> Result<Option<String>, SkinError> {
+    match data.field(field) {
+        None => Ok(None),
+        Some(Value::String(string)) => Ok(Some(string.clone())),
+        Some(other) => Err(invalid(field, other)),
+    }
+}
+
+fn client_u32(data: &impl ClientData, field: &str) -> Result<Option<u32>, SkinError> {
+    match data.field(field) {
+        None => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .map(Some)
+            .ok_or_else(|| invalid(field, value)),
+    }
+}
+
+fn client_f32(data: &impl ClientData, field: &str) -> Result<Option<f32>, SkinError> {
+    match data.field(field) {
+        None => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(|v| Some(v as f32))
+            .ok_or_else(|| invalid(field, value)),
+    }
+}
+
+fn client_bool(data: &impl ClientData, field: &str) -> Result<Option<bool>, SkinError> {
+    match data.field(field) {
+        None => Ok(None),
+        Some(Value::Bool(bool)) => Ok(Some(*bool)),
+        Some(other) => Err(invalid(field, other)),
+    }
+}
+
+fn client_array<'a>(data: &'a impl ClientData, field: &str) -> Result<&'a [Value], SkinError> {
+    match data.field(field) {
+        None => Ok(&[]),
+        Some(Value::Array(array)) => Ok(array),
+        Some(other) => Err(invalid(field, other)),
+    }
+}
+
+fn client_base64(data: &impl ClientData, field: &str) -> Result<Option<Vec<u8>>, SkinError> {
+    match client_string(data, field)? {
+        None => Ok(None),
+        Some(string) => Ok(Some(BASE64_STANDARD.decode(string)?)),
+    }
+}
+
+fn client_base64_string(data: &impl ClientData, field: &str) -> Result<String, SkinError> {
+    match client_base64(data, field)? {
+        None => Ok(String::new()),
+        Some(bytes) => String::from_utf8(bytes).map_err(|e| invalid(field, e)),
+    }
+}
+
+/// Lookup of the json objects in the client data.
+trait ClientData {
+    fn field(&self, field: &str) -> Option<&Value>;
+}
+
+impl ClientData for BTreeMap<String, Value> {
+    fn field(&self, field: &str) -> Option<&Value> {
+        self.get(field)
+    }
+}
+
+impl ClientData for serde_json::Map<String, Value> {
+    fn field(&self, field: &str) -> Option<&Value> {
+        self.get(field)
+    }
+}
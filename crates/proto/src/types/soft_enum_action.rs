@@ -0,0 +1,11 @@
+use bedrockrs_core::int::LE;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(LE::<u8>)]
+pub enum SoftEnumAction {
+    Add = 0,
+    Remove = 1,
+    /// Replaces all values.
+    Set = 2,
+}
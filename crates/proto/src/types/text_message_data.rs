@@ -1,3 +1,5 @@
+use bedrockrs_shared::text::rawtext::RawText;
+
 #[derive(Debug, Clone)]
 pub enum TextMessageData {
     Raw(String),
@@ -27,7 +29,7 @@ pub enum TextMessageData {
         player_name: String,
         message: String,
     },
-    TextObjectWhisper(String),
-    TextObject(String),
-    TextObjectAnnouncement(String),
+    TextObjectWhisper(RawText),
+    TextObject(RawText),
+    TextObjectAnnouncement(RawText),
 }
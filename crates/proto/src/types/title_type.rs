@@ -0,0 +1,20 @@
+use bedrockrs_core::int::VAR;
+use bedrockrs_proto_derive::ProtoCodec;
+
+#[derive(ProtoCodec, Debug, Copy, Clone, Eq, PartialEq)]
+#[enum_repr(VAR::<i32>)]
+pub enum TitleType {
+    /// Hides the current title.
+    Clear = 0,
+    /// Hides the current title and resets the subtitle and fade times.
+    Reset = 1,
+    Title = 2,
+    Subtitle = 3,
+    ActionBar = 4,
+    /// Only sets the fade times.
+    Times = 5,
+    /// A title given as raw text json.
+    TitleJson = 6,
+    SubtitleJson = 7,
+    ActionBarJson = 8,
+}
@@ -6,6 +6,7 @@ use crate::error::ProtoCodecError;
 
 pub mod error;
 pub mod types;
+pub mod version;
 
 pub trait ProtoCodec: Sized {
     fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError>;
@@ -0,0 +1,45 @@
+use std::cell::Cell;
+
+thread_local! {
+    static PROTOCOL_VERSION: Cell<Option<i32>> = const { Cell::new(None) };
+}
+
+/// Restores the previously set protocol version once dropped,
+/// this keeps nested calls and panics from leaking a version.
+struct ProtocolVersionGuard {
+    previous: Option<i32>,
+}
+
+impl Drop for ProtocolVersionGuard {
+    fn drop(&mut self) {
+        PROTOCOL_VERSION.with(|v| v.set(self.previous));
+    }
+}
+
+/// Runs the given closure with the negotiated protocol version of a connection,
+/// [`ProtoCodec`](crate::ProtoCodec) implementations called inside of it can
+/// branch on that version using [`protocol_version`] or [`protocol_at_least`].
+pub fn with_protocol_version<R>(version: Option<i32>, f: impl FnOnce() -> R) -> R {
+    let _guard = ProtocolVersionGuard {
+        previous: PROTOCOL_VERSION.with(|v| v.replace(version)),
+    };
+
+    f()
+}
+
+/// Returns the protocol version that is currently being en-/decoded,
+/// or [`None`] if no version has been negotiated.
+#[inline]
+pub fn protocol_version() -> Option<i32> {
+    PROTOCOL_VERSION.with(|v| v.get())
+}
+
+/// Returns if the protocol version currently being en-/decoded is at least the given version.
+/// If no version has been negotiated the newest layout is assumed.
+#[inline]
+pub fn protocol_at_least(version: i32) -> bool {
+    match protocol_version() {
+        Some(v) => v >= version,
+        None => true,
+    }
+}
@@ -1,3 +1,4 @@
 pub mod actor_runtime_id;
 pub mod actor_unique_id;
+pub mod text;
 pub mod world;
@@ -0,0 +1,170 @@
+use std::fmt::{Display, Formatter};
+
+/// The character starting a format code.
+pub const FORMAT_CODE_PREFIX: char = '§';
+
+/// A `§` color or format code.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FormatCode {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+    MinecoinGold,
+    MaterialQuartz,
+    MaterialIron,
+    MaterialNetherite,
+    MaterialRedstone,
+    MaterialCopper,
+    MaterialGold,
+    MaterialEmerald,
+    MaterialDiamond,
+    MaterialLapis,
+    MaterialAmethyst,
+    Obfuscated,
+    Bold,
+    Italic,
+    /// Resets all colors and formats.
+    Reset,
+}
+
+impl FormatCode {
+    pub fn code(&self) -> char {
+        match self {
+            FormatCode::Black => '0',
+            FormatCode::DarkBlue => '1',
+            FormatCode::DarkGreen => '2',
+            FormatCode::DarkAqua => '3',
+            FormatCode::DarkRed => '4',
+            FormatCode::DarkPurple => '5',
+            FormatCode::Gold => '6',
+            FormatCode::Gray => '7',
+            FormatCode::DarkGray => '8',
+            FormatCode::Blue => '9',
+            FormatCode::Green => 'a',
+            FormatCode::Aqua => 'b',
+            FormatCode::Red => 'c',
+            FormatCode::LightPurple => 'd',
+            FormatCode::Yellow => 'e',
+            FormatCode::White => 'f',
+            FormatCode::MinecoinGold => 'g',
+            FormatCode::MaterialQuartz => 'h',
+            FormatCode::MaterialIron => 'i',
+            FormatCode::MaterialNetherite => 'j',
+            FormatCode::Obfuscated => 'k',
+            FormatCode::Bold => 'l',
+            FormatCode::MaterialRedstone => 'm',
+            FormatCode::MaterialCopper => 'n',
+            FormatCode::Italic => 'o',
+            FormatCode::MaterialGold => 'p',
+            FormatCode::MaterialEmerald => 'q',
+            FormatCode::Reset => 'r',
+            FormatCode::MaterialDiamond => 's',
+            FormatCode::MaterialLapis => 't',
+            FormatCode::MaterialAmethyst => 'u',
+        }
+    }
+
+    pub fn from_code(code: char) -> Option<Self> {
+        Some(match code.to_ascii_lowercase() {
+            '0' => FormatCode::Black,
+            '1' => FormatCode::DarkBlue,
+            '2' => FormatCode::DarkGreen,
+            '3' => FormatCode::DarkAqua,
+            '4' => FormatCode::DarkRed,
+            '5' => FormatCode::DarkPurple,
+            '6' => FormatCode::Gold,
+            '7' => FormatCode::Gray,
+            '8' => FormatCode::DarkGray,
+            '9' => FormatCode::Blue,
+            'a' => FormatCode::Green,
+            'b' => FormatCode::Aqua,
+            'c' => FormatCode::Red,
+            'd' => FormatCode::LightPurple,
+            'e' => FormatCode::Yellow,
+            'f' => FormatCode::White,
+            'g' => FormatCode::MinecoinGold,
+            'h' => FormatCode::MaterialQuartz,
+            'i' => FormatCode::MaterialIron,
+            'j' => FormatCode::MaterialNetherite,
+            'k' => FormatCode::Obfuscated,
+            'l' => FormatCode::Bold,
+            'm' => FormatCode::MaterialRedstone,
+            'n' => FormatCode::MaterialCopper,
+            'o' => FormatCode::Italic,
+            'p' => FormatCode::MaterialGold,
+            'q' => FormatCode::MaterialEmerald,
+            'r' => FormatCode::Reset,
+            's' => FormatCode::MaterialDiamond,
+            't' => FormatCode::MaterialLapis,
+            'u' => FormatCode::MaterialAmethyst,
+            _ => return None,
+        })
+    }
+
+    /// If this code changes the color, all other codes change the format.
+    pub fn is_color(&self) -> bool {
+        !matches!(
+            self,
+            FormatCode::Obfuscated | FormatCode::Bold | FormatCode::Italic | FormatCode::Reset
+        )
+    }
+
+    /// Formats the text with this code and resets the format afterwards.
+    pub fn apply(&self, text: &str) -> String {
+        format!("{self}{text}{}", FormatCode::Reset)
+    }
+}
+
+impl Display for FormatCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{FORMAT_CODE_PREFIX}{}", self.code())
+    }
+}
+
+/// Removes all format codes from the text.
+pub fn strip_format_codes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            FORMAT_CODE_PREFIX => {
+                chars.next();
+            }
+            c => stripped.push(c),
+        }
+    }
+
+    stripped
+}
+
+/// Replaces format codes written with another prefix, like `&a`, with `§` codes.
+/// Prefixes not followed by a valid code are kept.
+pub fn translate_format_codes(prefix: char, text: &str) -> String {
+    let mut translated = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(code) if c == prefix && FormatCode::from_code(*code).is_some() => {
+                translated.push(FORMAT_CODE_PREFIX);
+            }
+            _ => translated.push(c),
+        }
+    }
+
+    translated
+}
@@ -0,0 +1,255 @@
+use std::fmt::{Display, Formatter};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use bedrockrs_proto_core::error::ProtoCodecError;
+use bedrockrs_proto_core::ProtoCodec;
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum RawTextError {
+    #[error("Json Error: {0}")]
+    JsonError(#[from] Arc<serde_json::Error>),
+    #[error("Format Error: {0}")]
+    FormatError(String),
+}
+
+/// The parameters of a translated component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TranslateWith {
+    Strings(Vec<String>),
+    RawText(RawText),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawTextComponent {
+    Text(String),
+    /// A translation key, `%%s` in the translation is replaced with the parameters.
+    Translate {
+        key: String,
+        with: Option<TranslateWith>,
+    },
+    /// The score of a player or fake player.
+    Score {
+        name: String,
+        objective: String,
+    },
+    /// The names of the actors matching the target selector.
+    Selector(String),
+    RawText(RawText),
+}
+
+/// Text made of components that are resolved by the client,
+/// sent as `{"rawtext":[...]}` json.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawText {
+    pub components: Vec<RawTextComponent>,
+}
+
+impl RawText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(mut self, component: RawTextComponent) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.push(RawTextComponent::Text(text.into()))
+    }
+
+    pub fn translate(self, key: impl Into<String>, with: Vec<String>) -> Self {
+        self.push(RawTextComponent::Translate {
+            key: key.into(),
+            with: match with.is_empty() {
+                true => None,
+                false => Some(TranslateWith::Strings(with)),
+            },
+        })
+    }
+
+    pub fn translate_with(self, key: impl Into<String>, with: RawText) -> Self {
+        self.push(RawTextComponent::Translate {
+            key: key.into(),
+            with: Some(TranslateWith::RawText(with)),
+        })
+    }
+
+    pub fn score(self, name: impl Into<String>, objective: impl Into<String>) -> Self {
+        self.push(RawTextComponent::Score {
+            name: name.into(),
+            objective: objective.into(),
+        })
+    }
+
+    pub fn selector(self, selector: impl Into<String>) -> Self {
+        self.push(RawTextComponent::Selector(selector.into()))
+    }
+
+    /// Returns the text of all text components, other components are left out.
+    pub fn plain_text(&self) -> String {
+        self.components
+            .iter()
+            .map(|component| match component {
+                RawTextComponent::Text(text) => text.clone(),
+                RawTextComponent::RawText(raw_text) => raw_text.plain_text(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    /// Returns the text if this only consists of text components.
+    pub fn as_plain_text(&self) -> Option<String> {
+        self.components
+            .iter()
+            .all(|component| match component {
+                RawTextComponent::Text(_) => true,
+                RawTextComponent::RawText(raw_text) => raw_text.as_plain_text().is_some(),
+                _ => false,
+            })
+            .then(|| self.plain_text())
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({ "rawtext": self.components.iter().map(component_to_json).collect::<Vec<_>>() })
+    }
+
+    /// Returns plain text as a json string and everything else as a rawtext object,
+    /// for places accepting both like forms.
+    pub fn to_compact_json(&self) -> Value {
+        match self.as_plain_text() {
+            Some(text) => Value::String(text),
+            None => self.to_json(),
+        }
+    }
+
+    pub fn parse(json: &str) -> Result<Self, RawTextError> {
+        let value: Value = serde_json::from_str(json).map_err(Arc::new)?;
+
+        Self::from_json(&value)
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, RawTextError> {
+        let components = value
+            .get("rawtext")
+            .and_then(Value::as_array)
+            .ok_or_else(|| RawTextError::FormatError(String::from("Missing rawtext array")))?;
+
+        Ok(Self {
+            components: components
+                .iter()
+                .map(component_from_json)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+fn component_to_json(component: &RawTextComponent) -> Value {
+    match component {
+        RawTextComponent::Text(text) => json!({ "text": text }),
+        RawTextComponent::Translate { key, with } => {
+            let mut object = Map::new();
+            object.insert(String::from("translate"), Value::String(key.clone()));
+
+            match with {
+                None => {}
+                Some(TranslateWith::Strings(with)) => {
+                    object.insert(String::from("with"), json!(with));
+                }
+                Some(TranslateWith::RawText(with)) => {
+                    object.insert(String::from("with"), with.to_json());
+                }
+            }
+
+            Value::Object(object)
+        }
+        RawTextComponent::Score { name, objective } => {
+            json!({ "score": { "name": name, "objective": objective } })
+        }
+        RawTextComponent::Selector(selector) => json!({ "selector": selector }),
+        RawTextComponent::RawText(raw_text) => raw_text.to_json(),
+    }
+}
+
+fn component_from_json(value: &Value) -> Result<RawTextComponent, RawTextError> {
+    let format_error = || RawTextError::FormatError(format!("Invalid rawtext component: {value}"));
+    let string = |value: Option<&Value>| value.and_then(Value::as_str).map(String::from);
+
+    if let Some(text) = value.get("text") {
+        return Ok(RawTextComponent::Text(
+            string(Some(text)).ok_or_else(format_error)?,
+        ));
+    }
+
+    if let Some(key) = value.get("translate") {
+        let with = match value.get("with") {
+            None => None,
+            Some(Value::Array(with)) => Some(TranslateWith::Strings(
+                with.iter()
+                    .map(|v| string(Some(v)))
+                    .collect::<Option<_>>()
+                    .ok_or_else(format_error)?,
+            )),
+            Some(with) => Some(TranslateWith::RawText(RawText::from_json(with)?)),
+        };
+
+        return Ok(RawTextComponent::Translate {
+            key: string(Some(key)).ok_or_else(format_error)?,
+            with,
+        });
+    }
+
+    if let Some(score) = value.get("score") {
+        return Ok(RawTextComponent::Score {
+            name: string(score.get("name")).ok_or_else(format_error)?,
+            objective: string(score.get("objective")).ok_or_else(format_error)?,
+        });
+    }
+
+    if let Some(selector) = value.get("selector") {
+        return Ok(RawTextComponent::Selector(
+            string(Some(selector)).ok_or_else(format_error)?,
+        ));
+    }
+
+    if value.get("rawtext").is_some() {
+        return Ok(RawTextComponent::RawText(RawText::from_json(value)?));
+    }
+
+    Err(format_error())
+}
+
+impl Display for RawText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+impl From<&str> for RawText {
+    fn from(value: &str) -> Self {
+        RawText::new().text(value)
+    }
+}
+
+impl From<String> for RawText {
+    fn from(value: String) -> Self {
+        RawText::new().text(value)
+    }
+}
+
+// ProtoCodec
+impl ProtoCodec for RawText {
+    fn proto_serialize(&self, stream: &mut Vec<u8>) -> Result<(), ProtoCodecError> {
+        self.to_string().proto_serialize(stream)
+    }
+
+    fn proto_deserialize(stream: &mut Cursor<&[u8]>) -> Result<Self, ProtoCodecError> {
+        RawText::parse(&String::proto_deserialize(stream)?).map_err(|e| match e {
+            RawTextError::JsonError(e) => ProtoCodecError::JsonError(e),
+            RawTextError::FormatError(e) => ProtoCodecError::FormatMismatch(e),
+        })
+    }
+}